@@ -0,0 +1,58 @@
+// matmul benchmark: blocked kernel vs the previous naive i-k-j loop
+// run with `cargo bench --bench matmul`
+
+use linalgae_rs::core::matrix::Matrix;
+use std::hint::black_box;
+use std::time::{Duration, Instant};
+
+// the pre-tiling Matrix::matmul loop, kept here as the baseline
+fn naive_matmul(lhs: &[f64], rhs: &[f64], n: usize) -> Vec<f64>
+{
+    let mut result = vec![0.0; n * n];
+
+    for i in 0..n
+    {
+        for k in 0..n
+        {
+            let lhs_val = lhs[i * n + k];
+
+            for j in 0..n
+            {
+                result[i * n + j] += lhs_val * rhs[k * n + j];
+            }
+        }
+    }
+
+    result
+}
+
+// best-of-<reps> wall time
+fn time(reps: usize, mut f: impl FnMut()) -> Duration
+{
+    (0..reps).map(|_|
+        {
+            let start = Instant::now();
+            f();
+            start.elapsed()
+        }).min().unwrap()
+}
+
+fn gflops(n: usize, t: Duration) -> f64
+{
+    2.0 * (n as f64).powi(3) / t.as_secs_f64() / 1e9
+}
+
+fn main()
+{
+    for &(n, reps) in &[(512, 5), (2048, 1)]
+    {
+        let a = Matrix::<f64>::rand_init(n, n);
+        let b = Matrix::<f64>::rand_init(n, n);
+
+        let naive = time(reps, || { black_box(naive_matmul(a.as_slice(), b.as_slice(), n)); });
+        let blocked = time(reps, || { black_box(a.matmul(&b)); });
+
+        println!("{n}x{n}: naive {:>8.1?} ({:>5.2} GFLOP/s) | blocked {:>8.1?} ({:>5.2} GFLOP/s) | speedup {:.2}x",
+            naive, gflops(n, naive), blocked, gflops(n, blocked), naive.as_secs_f64() / blocked.as_secs_f64());
+    }
+}
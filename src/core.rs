@@ -1 +1,3 @@
-pub mod matrix;
\ No newline at end of file
+pub mod error;
+pub mod matrix;
+pub mod view;
\ No newline at end of file
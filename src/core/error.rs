@@ -0,0 +1,120 @@
+use std::error::Error;
+use std::fmt;
+
+// defining the crate-wide error type
+// shapes are reported as (rows, cols)
+// non_exhaustive, new failure modes get added as the crate grows
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum MatrixError
+{
+    // a row or col dim of zero was requested
+    ZeroDimension { rows: usize, cols: usize },
+
+    // the backing data does not hold (rows x cols) elements
+    LengthMismatch { rows: usize, cols: usize, len: usize },
+
+    // element-wise ops, both operands must have the same shape
+    ShapeMismatch { op: &'static str, lhs: (usize, usize), rhs: (usize, usize) },
+
+    // products, lhs_cols must equal rhs_rows
+    InnerDimMismatch { op: &'static str, lhs: (usize, usize), rhs: (usize, usize) },
+
+    // requested (start, end) row / col ranges fall outside the shape
+    SliceOutOfBounds { rows: (usize, usize), cols: (usize, usize), shape: (usize, usize) },
+
+    // op is only defined for square matrices
+    NotSquare { op: &'static str, rows: usize, cols: usize },
+
+    // op needs at least as many rows as cols
+    Underdetermined { op: &'static str, rows: usize, cols: usize },
+
+    // scalar divisor is zero
+    DivisionByZero { op: &'static str },
+
+    // matrix has no inverse (or is numerically singular)
+    Singular { op: &'static str },
+
+    // an intermediate value does not fit the integer type
+    Overflow { op: &'static str },
+
+    // op needs A == A^T
+    NotSymmetric { op: &'static str },
+
+    // op needs a symmetric positive definite matrix
+    NotPositiveDefinite { op: &'static str },
+
+    // an iterative method hit its iteration cap
+    NotConverged { op: &'static str, iterations: usize },
+
+    // a single (row, col) index falls outside the shape
+    IndexOutOfBounds { op: &'static str, index: (usize, usize), shape: (usize, usize) },
+
+    // sparse index arrays are inconsistent, reason says which invariant broke
+    InvalidStructure { op: &'static str, reason: &'static str },
+
+    // a scalar parameter is outside its valid range, reason gives the range
+    InvalidParameter { op: &'static str, reason: &'static str },
+}
+
+impl fmt::Display for MatrixError
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result
+    {
+        match self
+        {
+            MatrixError::ZeroDimension { rows, cols } =>
+            {
+                if *rows == 0 { write!(f, "Row dim should be positive! (got {} x {})", rows, cols) }
+                else { write!(f, "Col dim should be positive! (got {} x {})", rows, cols) }
+            }
+
+            MatrixError::LengthMismatch { rows, cols, len } =>
+                write!(f, "Input vector does not match dimensions! (expected {} x {} = {}, got {})", rows, cols, rows * cols, len),
+
+            MatrixError::ShapeMismatch { op, lhs, rhs } =>
+                write!(f, "Matrix::{}: Dimension mismatch! ( ({}, {}) vs ({}, {}) )", op, lhs.0, lhs.1, rhs.0, rhs.1),
+
+            MatrixError::InnerDimMismatch { op, lhs, rhs } =>
+                write!(f, "Matrix::{}: lhs_cols ({}) != rhs_rows ({}) ( ({}, {}) vs ({}, {}) )", op, lhs.1, rhs.0, lhs.0, lhs.1, rhs.0, rhs.1),
+
+            MatrixError::SliceOutOfBounds { rows, cols, shape } =>
+                write!(f, "Matrix::slice: Range [{}..{}, {}..{}] out of bounds for shape ({}, {})", rows.0, rows.1, cols.0, cols.1, shape.0, shape.1),
+
+            MatrixError::NotSquare { op, rows, cols } =>
+                write!(f, "Matrix::{}: Expected a square matrix, got ({}, {})!", op, rows, cols),
+
+            MatrixError::Underdetermined { op, rows, cols } =>
+                write!(f, "Matrix::{}: Expected rows >= cols, got ({}, {})!", op, rows, cols),
+
+            MatrixError::DivisionByZero { op } =>
+                write!(f, "Matrix::{}: Cannot divide by zero!", op),
+
+            MatrixError::Singular { op } =>
+                write!(f, "Matrix::{}: Matrix is singular!", op),
+
+            MatrixError::Overflow { op } =>
+                write!(f, "Matrix::{}: Integer overflow in intermediate value!", op),
+
+            MatrixError::NotSymmetric { op } =>
+                write!(f, "Matrix::{}: Matrix is not symmetric!", op),
+
+            MatrixError::NotPositiveDefinite { op } =>
+                write!(f, "Matrix::{}: Matrix is not positive definite!", op),
+
+            MatrixError::NotConverged { op, iterations } =>
+                write!(f, "Matrix::{}: Failed to converge after {} iterations!", op, iterations),
+
+            MatrixError::IndexOutOfBounds { op, index, shape } =>
+                write!(f, "Matrix::{}: Index ({}, {}) out of bounds for shape ({}, {})!", op, index.0, index.1, shape.0, shape.1),
+
+            MatrixError::InvalidStructure { op, reason } =>
+                write!(f, "Matrix::{}: Invalid sparse structure, {}!", op, reason),
+
+            MatrixError::InvalidParameter { op, reason } =>
+                write!(f, "Matrix::{}: Invalid parameter, {}!", op, reason),
+        }
+    }
+}
+
+impl Error for MatrixError {}
@@ -3,6 +3,12 @@ use std::ops::{self, AddAssign, Fn};
 use rand::prelude::*;
 use rand_distr::{StandardNormal, Distribution};
 use num_traits::Num;
+use crate::core::error::MatrixError;
+use crate::core::view::AsView;
+#[cfg(feature = "parallel")]
+use crate::ops::parallel;
+#[cfg(feature = "simd")]
+use crate::ops::simd;
 
 // defining a matrix struct (generic type)
 #[derive(Debug, Clone, PartialEq)]
@@ -18,50 +24,46 @@ impl<T> Matrix<T>
 where T: Num
 {
     // "default" constructor
-    pub fn new(rows: usize, cols: usize, data: Vec<T>) -> Result<Self, &'static str>
+    pub fn new(rows: usize, cols: usize, data: Vec<T>) -> Result<Self, MatrixError>
     {
         // check rows and cols
-        if rows == 0 { return Err("Row dim should be positive!"); }
-
-        if cols == 0 { return Err("Col dim should be positive!"); }
+        if rows == 0 || cols == 0 { return Err(MatrixError::ZeroDimension { rows, cols }); }
 
         // check if number of elements equal to (rows x cols)
-        if (rows * cols) != data.len() { return Err("Input vector does not match dimensions!"); }
+        if (rows * cols) != data.len() { return Err(MatrixError::LengthMismatch { rows, cols, len: data.len() }); }
         
         Ok(Matrix { rows, cols, data })
     }
 
     // "full" constructor, returns a Matrix filled with <fill_value>
-    pub fn full(rows: usize, cols: usize, fill_value: T) -> Result<Self, &'static str>
+    pub fn full(rows: usize, cols: usize, fill_value: T) -> Result<Self, MatrixError>
     where T: Clone
     {
         // check rows and cols
-        if rows == 0 { return Err("Row dim should be positive!"); }
-
-        if cols == 0 { return Err("Col dim should be positive!"); }
+        if rows == 0 || cols == 0 { return Err(MatrixError::ZeroDimension { rows, cols }); }
 
         Ok(Matrix { rows, cols, data: vec![fill_value; rows * cols] })
     }
 
     // "zeros" constructor, returns a zeroed out Matrix
-    pub fn zeroes(rows: usize, cols: usize) -> Result<Self, &'static str> 
+    pub fn zeroes(rows: usize, cols: usize) -> Result<Self, MatrixError> 
     where T: Num + Clone + Copy
     {
         Self::full(rows, cols, T::zero())
     }
 
     // "ones" constructor, returns a Matrix filled with ones
-    pub fn ones(rows: usize, cols: usize) -> Result<Self, &'static str> 
+    pub fn ones(rows: usize, cols: usize) -> Result<Self, MatrixError> 
     where T: Clone + num_traits::One
     {
         Self::full(rows, cols, T::one()) 
     }
 
     // "identity" constructor, returns an identity Matrix
-    pub fn identity(n: usize) -> Result<Self, &'static str>
+    pub fn identity(n: usize) -> Result<Self, MatrixError>
     where T: Num + Clone + Copy
     {
-        if n == 0 { return Err("Dimension should be positive!"); }
+        if n == 0 { return Err(MatrixError::ZeroDimension { rows: n, cols: n }); }
 
         let mut mat = Self::zeroes(n, n)?;
         let data = mat.as_mut_slice();
@@ -77,30 +79,102 @@ where T: Num
     // dim getters
     pub fn rows(&self) -> usize { self.rows }
     pub fn cols(&self) -> usize { self.cols }
+    pub fn shape(&self) -> (usize, usize) { (self.rows, self.cols) }
 
     // data accessors implemented as slicers
     pub fn as_slice(&self) -> &[T] { &self.data }
     pub fn as_mut_slice(&mut self) -> &mut[T] { &mut self.data }
 
-    // transpose function
+    // checked element accessors, None if (i, j) is out of range
+    pub fn get(&self, i: usize, j: usize) -> Option<&T>
+    {
+        if i >= self.rows || j >= self.cols { return None; }
+
+        self.data.get(i * self.cols + j)
+    }
+
+    pub fn get_mut(&mut self, i: usize, j: usize) -> Option<&mut T>
+    {
+        if i >= self.rows || j >= self.cols { return None; }
+
+        self.data.get_mut(i * self.cols + j)
+    }
+
+    // unchecked element accessors
+
+    /// # Safety
+    /// Caller must guarantee `i < rows` and `j < cols`.
+    pub unsafe fn get_unchecked(&self, i: usize, j: usize) -> &T
+    {
+        unsafe { self.data.get_unchecked(i * self.cols + j) }
+    }
+
+    /// # Safety
+    /// Caller must guarantee `i < rows` and `j < cols`.
+    pub unsafe fn get_unchecked_mut(&mut self, i: usize, j: usize) -> &mut T
+    {
+        unsafe { self.data.get_unchecked_mut(i * self.cols + j) }
+    }
+
+    // bounds check used by Index / IndexMut, names the offending row or col
+    pub(crate) fn check_index(&self, i: usize, j: usize, op: &'static str)
+    {
+        if i >= self.rows
+        {
+            panic!("Matrix::{}: row index {} out of range for matrix of shape ({}, {})", op, i, self.rows, self.cols);
+        }
+
+        if j >= self.cols
+        {
+            panic!("Matrix::{}: col index {} out of range for matrix of shape ({}, {})", op, j, self.rows, self.cols);
+        }
+    }
+
+    // transpose function, allocates; see t_view() for the zero-copy version
     pub fn t(&self) -> Matrix<T>
     where T: Default + Clone + Copy,
     {
-        let rows = self.rows();
-        let cols = self.cols();
-        let mut result = vec![T::zero(); rows * cols];
-        let slice = self.as_slice();
-
-        for i in 0..rows 
-        { 
-            for j in 0..cols 
-            { 
-                result[j*rows +i] = slice[i*cols + j]; 
-            } 
+        self.t_view().to_owned()
+    }
+
+    // inplace transpose for square matrices, swaps in cache-sized tiles
+    pub fn transpose_inplace(&mut self)
+    {
+        self.try_transpose_inplace().unwrap_or_else(|e| panic!("{}", e))
+    }
+
+    pub fn try_transpose_inplace(&mut self) -> Result<(), MatrixError>
+    {
+        const TILE: usize = 32;
+
+        let n = self.rows;
+
+        if n != self.cols
+        {
+            return Err(MatrixError::NotSquare { op: "transpose_inplace", rows: self.rows, cols: self.cols });
         }
 
-        Matrix::new(cols, rows, result).unwrap()
+        let data = self.as_mut_slice();
+
+        // walk tiles on and above the diagonal, swapping each with its mirror
+        for bi in (0..n).step_by(TILE)
+        {
+            for bj in (bi..n).step_by(TILE)
+            {
+                for i in bi..(bi + TILE).min(n)
+                {
+                    // on diagonal tiles only the strict upper half is swapped
+                    let j_start = if bi == bj { i + 1 } else { bj };
 
+                    for j in j_start..(bj + TILE).min(n)
+                    {
+                        data.swap(i * n + j, j * n + i);
+                    }
+                }
+            }
+        }
+
+        Ok(())
     }
 
     // apply function -> applies a closure / function to all values of the matrix
@@ -113,6 +187,26 @@ where T: Num
         Matrix::new(self.rows, self.cols, result).unwrap()
     }
 
+    // apply into a caller-provided buffer of the same shape, no allocs
+    pub fn apply_into(&self, func: impl Fn(T) -> T, out: &mut Matrix<T>)
+    where T: Copy
+    {
+        self.try_apply_into(func, out).unwrap_or_else(|e| panic!("{}", e))
+    }
+
+    pub fn try_apply_into(&self, func: impl Fn(T) -> T, out: &mut Matrix<T>) -> Result<(), MatrixError>
+    where T: Copy
+    {
+        out.check_out_shape(self.shape(), "apply_into")?;
+
+        for (o, x) in out.as_mut_slice().iter_mut().zip(self.as_slice().iter())
+        {
+            *o = func(*x);
+        }
+
+        Ok(())
+    }
+
     // inplace apply, reduces allocs
     pub fn apply_inplace(mut self, func: impl Fn(T) -> T) -> Self
     where T: Copy
@@ -125,110 +219,123 @@ where T: Num
         self
     }
 
-    // matmul function
-    pub fn matmul(&self, other: &Matrix<T>) -> Matrix<T>
+    // matmul function, rhs can be a Matrix or any view (e.g. a lazy transpose)
+    pub fn matmul(&self, other: &impl AsView<T>) -> Matrix<T>
     where T: Num + Copy + AddAssign
     {
-        let lhs_cols = self.cols();
-        let rhs_rows = other.rows();
-        
-        // check size
-        if lhs_cols != rhs_rows
-        {
-            panic!("Matrix::matmul: lhs_cols ({}) != rhs_rows ({})", lhs_cols, rhs_rows);
-        }
+        self.try_matmul(other).unwrap_or_else(|e| panic!("{}", e))
+    }
 
-        let lhs_rows = self.rows();
-        let rhs_cols = other.cols();
+    // fallible matmul, errors on lhs_cols != rhs_rows
+    pub fn try_matmul(&self, other: &impl AsView<T>) -> Result<Matrix<T>, MatrixError>
+    where T: Num + Copy + AddAssign
+    {
+        self.view().try_matmul(other)
+    }
 
-        let lhs_slice = self.as_slice();
-        let rhs_slice = other.as_slice();
+    // Hadamard multiplication
+    pub fn component_mul(&self, other: &Matrix<T>) -> Matrix<T>
+    where T: Num + Copy
+    {
+        self.try_component_mul(other).unwrap_or_else(|e| panic!("{}", e))
+    }
 
-        let mut result = vec![T::zero(); lhs_rows * rhs_cols];
+    // fallible Hadamard multiplication
+    pub fn try_component_mul(&self, other: &Matrix<T>) -> Result<Matrix<T>, MatrixError>
+    where T: Num + Copy
+    {
+        self.check_same_shape(other, "component_mul")?;
 
-        // optimised version of matmul, should be much faster
-        for i in 0..lhs_rows 
+        #[cfg(feature = "parallel")]
+        if parallel::enabled::<T>(self.data.len())
         {
-            for k in 0..lhs_cols 
-            {
-                let lhs_val = lhs_slice[i * lhs_cols + k];
-                
-                for j in 0..rhs_cols 
-                {
-                    let rhs_val = rhs_slice[k * rhs_cols + j];
-                    let res_idx = i * rhs_cols + j;
-                    result[res_idx] += lhs_val * rhs_val;
-                }
-            }
+            let mut out = vec![T::zero(); self.data.len()];
+            parallel::zip(self.as_slice(), other.as_slice(), &mut out, self.cols, |a, b| a * b);
+            return Matrix::new(self.rows, self.cols, out);
         }
 
-        Matrix::new(lhs_rows, rhs_cols, result).expect("Matrix::matmul: Result has incorrect dimensions!")
+        #[cfg(feature = "simd")]
+        if simd::enabled::<T>()
+        {
+            let mut out = vec![T::zero(); self.data.len()];
+            simd::binary(simd::Binary::Mul, self.as_slice(), other.as_slice(), &mut out);
+            return Matrix::new(self.rows, self.cols, out);
+        }
+
+        let result = self.data.iter().zip(other.data.iter()).map(|(&a, &b)| a * b).collect();
+
+        Matrix::new(self.rows, self.cols, result)
     }
 
-    // Hadamard multiplication
-    pub fn component_mul(&self, other: &Matrix<T>) -> Matrix<T>
+    // Hadamard multiplication into a caller-provided buffer
+    pub fn component_mul_into(&self, other: &Matrix<T>, out: &mut Matrix<T>)
     where T: Num + Copy
     {
-        let rows = self.rows();
-        let cols = self.cols();
-        let other_rows = other.rows();
-        let other_cols = other.cols();
+        self.try_component_mul_into(other, out).unwrap_or_else(|e| panic!("{}", e))
+    }
 
-        // check size
-        if rows != other_rows || cols != other_cols
+    pub fn try_component_mul_into(&self, other: &Matrix<T>, out: &mut Matrix<T>) -> Result<(), MatrixError>
+    where T: Num + Copy
+    {
+        self.check_same_shape(other, "component_mul_into")?;
+        out.check_out_shape(self.shape(), "component_mul_into")?;
+
+        for (o, (&a, &b)) in out.as_mut_slice().iter_mut().zip(self.data.iter().zip(other.data.iter()))
         {
-            panic!("Matrix::component_mul: Dimension mismatch!")
+            *o = a * b;
         }
 
-        let result = self.data.iter().zip(other.data.iter()).map(|(&a, &b)| a * b).collect();
-
-        Matrix::new(rows, cols, result).expect("Matrix::component_mul: Error!")
-        
+        Ok(())
     }
 
     // inplace hadamard
     pub fn component_mul_inplace(mut self, other: &Matrix<T>) -> Self
     where T: Copy + ops::MulAssign
     {
-        let rows = self.rows();
-        let cols = self.cols();
-        let other_rows = other.rows();
-        let other_cols = other.cols();
+        self.try_component_mul_inplace(other).unwrap_or_else(|e| panic!("{}", e));
+        
+        self
+    }
 
-        // check size
-        if rows != other_rows || cols != other_cols
-        {
-            panic!("Matrix::component_mul: Dimension mismatch!")
-        } 
+    // fallible inplace hadamard, takes &mut so self survives an error
+    pub fn try_component_mul_inplace(&mut self, other: &Matrix<T>) -> Result<(), MatrixError>
+    where T: Copy + ops::MulAssign
+    {
+        self.check_same_shape(other, "component_mul_inplace")?;
 
         for (a, b) in self.as_mut_slice().iter_mut().zip(other.as_slice().iter())
         {
             *a *= *b;
         }
-        
-        self
+
+        Ok(())
     }
 
     // sum func
     pub fn sum(&self) -> T
     where T: Copy + std::iter::Sum<T>
     {
+        #[cfg(feature = "parallel")]
+        if parallel::enabled::<T>(self.data.len())
+        {
+            return parallel::sum(self.as_slice(), self.cols);
+        }
+
         self.data.iter().copied().sum()
     }
 
     pub fn sub_assign_scaled(&mut self, other: &Matrix<T>, scalar: T)
     where T: ops::SubAssign + ops::Mul<Output = T> + Copy
     {
-        // check size
-        let lhs_rows = self.rows();
-        let lhs_cols = self.cols();
-        let other_rows = other.rows();
-        let other_cols = other.cols();
+        self.try_sub_assign_scaled(other, scalar).unwrap_or_else(|e| panic!("{}", e))
+    }
 
-        if lhs_rows != other_rows || lhs_cols != other_cols 
-        {
-            panic!("Matrix::sub_assign_scaled: Dimension mismatch!");
-        }
+    // fallible self -= other * scalar
+    pub fn try_sub_assign_scaled(&mut self, other: &Matrix<T>, scalar: T) -> Result<(), MatrixError>
+    where T: ops::SubAssign + ops::Mul<Output = T> + Copy
+    {
+        // check size
+        self.check_same_shape(other, "sub_assign_scaled")?;
 
         let self_mut_slice = self.as_mut_slice();
         let other_mut_slice = other.as_slice();
@@ -237,6 +344,41 @@ where T: Num
         {
             *a -= *b * scalar;
         }
+
+        Ok(())
+    }
+
+    // output-buffer guard for the *_into ops, lhs of the error is the expected shape
+    pub(crate) fn check_out_shape(&self, expected: (usize, usize), op: &'static str) -> Result<(), MatrixError>
+    {
+        if self.shape() != expected
+        {
+            return Err(MatrixError::ShapeMismatch { op, lhs: expected, rhs: self.shape() });
+        }
+
+        Ok(())
+    }
+
+    // shape guard shared by all element-wise ops
+    pub(crate) fn check_same_shape(&self, other: &Matrix<T>, op: &'static str) -> Result<(), MatrixError>
+    {
+        if self.rows != other.rows || self.cols != other.cols
+        {
+            return Err(MatrixError::ShapeMismatch { op, lhs: self.shape(), rhs: other.shape() });
+        }
+
+        Ok(())
+    }
+
+    // guard for ops only defined on square matrices
+    pub(crate) fn check_square(&self, op: &'static str) -> Result<(), MatrixError>
+    {
+        if self.rows != self.cols
+        {
+            return Err(MatrixError::NotSquare { op, rows: self.rows, cols: self.cols });
+        }
+
+        Ok(())
     }
 }
 
@@ -246,17 +388,20 @@ StandardNormal: Distribution<T>
 {
     pub fn rand_init(rows: usize, cols: usize) -> Matrix<T>
     {
-        // check rows and cols
-        if rows == 0 { panic!("Row dim should be positive!"); }
+        Self::try_rand_init(rows, cols).unwrap_or_else(|e| panic!("{}", e))
+    }
 
-        if cols == 0 { panic!("Col dim should be positive!"); }
+    // fallible rand_init, errors on zero dims
+    pub fn try_rand_init(rows: usize, cols: usize) -> Result<Matrix<T>, MatrixError>
+    {
+        // check rows and cols
+        if rows == 0 || cols == 0 { return Err(MatrixError::ZeroDimension { rows, cols }); }
 
         let mut rng = rand::rng();
 
-        let data = vec![T::zero(); rows * cols].iter().map(|_| rng.sample(StandardNormal)).collect();
-
-        Matrix::new(rows, cols, data).expect("Martix::rand_init: Error!")
+        let data = (0..rows * cols).map(|_| rng.sample(StandardNormal)).collect();
 
+        Matrix::new(rows, cols, data)
     }
 }
 
@@ -267,33 +412,39 @@ where T: Num + Display
 {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result 
     {
-        write!(f, "\n[")?;
-
-        for row in 0..self.rows
-        {
-            write!(f, "[")?;
+        fmt_grid(f, self.rows, self.cols, |row, col| &self.data[row * self.cols + col])
+    }
+}
 
-            for col in 0..self.cols
-            {
-                let idx = row * self.cols + col;
-                write!(f, "{:.2}", self.data[idx])?;
+// shared grid printer, used by Matrix and the view types
+pub(crate) fn fmt_grid<'a, T>(f: &mut fmt::Formatter<'_>, rows: usize, cols: usize, at: impl Fn(usize, usize) -> &'a T) -> fmt::Result
+where T: Display + 'a
+{
+    write!(f, "\n[")?;
 
-                if col != self.cols - 1
-                {
-                    write!(f, ", ")?;
-                }
-            }
+    for row in 0..rows
+    {
+        write!(f, "[")?;
 
-            write!(f, "]")?;
+        for col in 0..cols
+        {
+            write!(f, "{:.2}", at(row, col))?;
 
-            if row != self.rows - 1
+            if col != cols - 1
             {
-                write!(f, ",\n")?;
+                write!(f, ", ")?;
             }
         }
 
         write!(f, "]")?;
 
-        Ok(())
+        if row != rows - 1
+        {
+            writeln!(f, ",")?;
+        }
     }
-}
\ No newline at end of file
+
+    write!(f, "]")?;
+
+    Ok(())
+}
@@ -0,0 +1,573 @@
+use std::fmt::{self, Display};
+use std::ops::{AddAssign, Fn, Range};
+use num_traits::Num;
+use crate::core::error::MatrixError;
+use crate::core::matrix::{self, Matrix};
+use crate::ops::gemm;
+
+// strided layout shared by both view types
+// element (i, j) lives at data[offset + i * row_stride + j * col_stride]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+struct Layout
+{
+    offset: usize,
+    rows: usize,
+    cols: usize,
+    row_stride: usize,
+    col_stride: usize
+}
+
+impl Layout
+{
+    // layout of a whole row-major Matrix
+    fn dense(rows: usize, cols: usize) -> Self
+    {
+        Layout { offset: 0, rows, cols, row_stride: cols, col_stride: 1 }
+    }
+
+    fn idx(&self, i: usize, j: usize) -> usize
+    {
+        self.offset + i * self.row_stride + j * self.col_stride
+    }
+
+    // sub-block of this layout, ranges are relative to the current view
+    fn sub(&self, rows: Range<usize>, cols: Range<usize>) -> Result<Self, MatrixError>
+    {
+        if rows.start > rows.end || cols.start > cols.end || rows.end > self.rows || cols.end > self.cols
+        {
+            return Err(MatrixError::SliceOutOfBounds { rows: (rows.start, rows.end), cols: (cols.start, cols.end), shape: (self.rows, self.cols) });
+        }
+
+        // views follow Matrix and never have a zero dim
+        if rows.is_empty() || cols.is_empty()
+        {
+            return Err(MatrixError::ZeroDimension { rows: rows.len(), cols: cols.len() });
+        }
+
+        Ok(Layout
+        {
+            offset: self.idx(rows.start, cols.start),
+            rows: rows.len(),
+            cols: cols.len(),
+            row_stride: self.row_stride,
+            col_stride: self.col_stride
+        })
+    }
+
+    fn check_index(&self, i: usize, j: usize, op: &'static str)
+    {
+        if i >= self.rows
+        {
+            panic!("MatrixView::{}: row index {} out of range for view of shape ({}, {})", op, i, self.rows, self.cols);
+        }
+
+        if j >= self.cols
+        {
+            panic!("MatrixView::{}: col index {} out of range for view of shape ({}, {})", op, j, self.rows, self.cols);
+        }
+    }
+}
+
+// X----------X
+// anything readable as a strided view, lets Matrix and both view types
+// be passed interchangeably to the view-based ops
+pub trait AsView<T>
+where T: Num
+{
+    fn as_view(&self) -> MatrixView<'_, T>;
+}
+
+impl<T> AsView<T> for Matrix<T>
+where T: Num
+{
+    fn as_view(&self) -> MatrixView<'_, T> { self.view() }
+}
+
+impl<T> AsView<T> for MatrixView<'_, T>
+where T: Num
+{
+    fn as_view(&self) -> MatrixView<'_, T> { *self }
+}
+
+impl<T> AsView<T> for MatrixViewMut<'_, T>
+where T: Num
+{
+    fn as_view(&self) -> MatrixView<'_, T>
+    {
+        MatrixView { data: self.data, layout: self.layout }
+    }
+}
+
+// X----------X
+// read-only borrowed view into a Matrix
+#[derive(Debug)]
+pub struct MatrixView<'a, T>
+where T: Num
+{
+    data: &'a [T],
+    layout: Layout
+}
+
+// manual impls, derive would require T: Clone
+impl<T> Clone for MatrixView<'_, T>
+where T: Num
+{
+    fn clone(&self) -> Self { *self }
+}
+
+impl<T> Copy for MatrixView<'_, T>
+where T: Num
+{}
+
+impl<'a, T> MatrixView<'a, T>
+where T: Num
+{
+    // dim getters
+    pub fn rows(&self) -> usize { self.layout.rows }
+    pub fn cols(&self) -> usize { self.layout.cols }
+    pub fn shape(&self) -> (usize, usize) { (self.layout.rows, self.layout.cols) }
+
+    // stride getters, in elements of the parent buffer
+    pub fn row_stride(&self) -> usize { self.layout.row_stride }
+    pub fn col_stride(&self) -> usize { self.layout.col_stride }
+
+    // checked element accessor, None if (i, j) is out of range
+    pub fn get(&self, i: usize, j: usize) -> Option<&'a T>
+    {
+        if i >= self.layout.rows || j >= self.layout.cols { return None; }
+
+        self.data.get(self.layout.idx(i, j))
+    }
+
+    /// # Safety
+    /// Caller must guarantee `i < rows` and `j < cols`.
+    pub unsafe fn get_unchecked(&self, i: usize, j: usize) -> &'a T
+    {
+        unsafe { self.data.get_unchecked(self.layout.idx(i, j)) }
+    }
+
+    // bounds check used by Index, names the offending row or col
+    pub(crate) fn check_index(&self, i: usize, j: usize, op: &'static str)
+    {
+        self.layout.check_index(i, j, op);
+    }
+
+    // row i as a contiguous slice, None unless the columns are unit-stride
+    #[cfg(feature = "simd")]
+    pub(crate) fn row_slice(&self, i: usize) -> Option<&'a [T]>
+    {
+        if self.layout.col_stride != 1 { return None; }
+
+        let start = self.layout.idx(i, 0);
+
+        Some(&self.data[start..start + self.layout.cols])
+    }
+
+    // row-major iterator over the viewed elements
+    pub fn iter(&self) -> impl Iterator<Item = &'a T> + 'a
+    {
+        let data = self.data;
+        let layout = self.layout;
+
+        (0..layout.rows).flat_map(move |i| (0..layout.cols).map(move |j| &data[layout.idx(i, j)]))
+    }
+
+    // sub-view, ranges are relative to this view
+    pub fn try_slice(&self, rows: Range<usize>, cols: Range<usize>) -> Result<MatrixView<'a, T>, MatrixError>
+    {
+        Ok(MatrixView { data: self.data, layout: self.layout.sub(rows, cols)? })
+    }
+
+    pub fn slice(&self, rows: Range<usize>, cols: Range<usize>) -> MatrixView<'a, T>
+    {
+        self.try_slice(rows, cols).unwrap_or_else(|e| panic!("{}", e))
+    }
+
+    // single row / col as a (1 x cols) / (rows x 1) view
+    pub fn row(&self, i: usize) -> MatrixView<'a, T>
+    {
+        self.layout.check_index(i, 0, "row");
+        self.slice(i..i + 1, 0..self.layout.cols)
+    }
+
+    pub fn col(&self, j: usize) -> MatrixView<'a, T>
+    {
+        self.layout.check_index(0, j, "col");
+        self.slice(0..self.layout.rows, j..j + 1)
+    }
+
+    // lazy transpose, swaps dims and strides, no copy
+    pub fn t(&self) -> MatrixView<'a, T>
+    {
+        let l = self.layout;
+
+        MatrixView
+        {
+            data: self.data,
+            layout: Layout { offset: l.offset, rows: l.cols, cols: l.rows, row_stride: l.col_stride, col_stride: l.row_stride }
+        }
+    }
+
+    // copies the viewed elements into a new row-major Matrix
+    pub fn to_owned(&self) -> Matrix<T>
+    where T: Copy
+    {
+        let data = self.iter().copied().collect();
+
+        Matrix::new(self.rows(), self.cols(), data).expect("MatrixView::to_owned: Error!")
+    }
+
+    // sum func
+    pub fn sum(&self) -> T
+    where T: Copy + std::iter::Sum<T>
+    {
+        self.iter().copied().sum()
+    }
+
+    // apply function -> returns an owned Matrix, the view is untouched
+    pub fn apply(&self, func: impl Fn(T) -> T) -> Matrix<T>
+    where T: Copy
+    {
+        let result = self.iter().map(|x| func(*x)).collect();
+
+        Matrix::new(self.rows(), self.cols(), result).expect("MatrixView::apply: Error!")
+    }
+
+    // matmul function
+    pub fn matmul(&self, other: &impl AsView<T>) -> Matrix<T>
+    where T: Copy + AddAssign
+    {
+        self.try_matmul(other).unwrap_or_else(|e| panic!("{}", e))
+    }
+
+    // fallible matmul over arbitrary strides, runs the shared gemm kernel
+    pub fn try_matmul(&self, other: &impl AsView<T>) -> Result<Matrix<T>, MatrixError>
+    where T: Copy + AddAssign
+    {
+        let other = other.as_view();
+        let (lhs_rows, lhs_cols) = self.shape();
+        let (rhs_rows, rhs_cols) = other.shape();
+
+        // check size
+        if lhs_cols != rhs_rows
+        {
+            return Err(MatrixError::InnerDimMismatch { op: "matmul", lhs: self.shape(), rhs: other.shape() });
+        }
+
+        let mut result = vec![T::zero(); lhs_rows * rhs_cols];
+
+        gemm::gemm_kernel(T::one(), self, &other, &mut result);
+
+        Matrix::new(lhs_rows, rhs_cols, result)
+    }
+
+    // Hadamard multiplication
+    pub fn component_mul(&self, other: &impl AsView<T>) -> Matrix<T>
+    where T: Copy
+    {
+        self.try_component_mul(other).unwrap_or_else(|e| panic!("{}", e))
+    }
+
+    pub fn try_component_mul(&self, other: &impl AsView<T>) -> Result<Matrix<T>, MatrixError>
+    where T: Copy
+    {
+        self.zip_with(&other.as_view(), "component_mul", |a, b| a * b)
+    }
+
+    // fallible element-wise add / sub
+    pub fn try_add(&self, other: &impl AsView<T>) -> Result<Matrix<T>, MatrixError>
+    where T: Copy
+    {
+        self.zip_with(&other.as_view(), "Add", |a, b| a + b)
+    }
+
+    pub fn try_sub(&self, other: &impl AsView<T>) -> Result<Matrix<T>, MatrixError>
+    where T: Copy
+    {
+        self.zip_with(&other.as_view(), "Sub", |a, b| a - b)
+    }
+
+    // element-wise combinator shared by the binary ops above
+    fn zip_with(&self, other: &MatrixView<'_, T>, op: &'static str, func: impl Fn(T, T) -> T) -> Result<Matrix<T>, MatrixError>
+    where T: Copy
+    {
+        if self.shape() != other.shape()
+        {
+            return Err(MatrixError::ShapeMismatch { op, lhs: self.shape(), rhs: other.shape() });
+        }
+
+        let result = self.iter().zip(other.iter()).map(|(&a, &b)| func(a, b)).collect();
+
+        Matrix::new(self.rows(), self.cols(), result)
+    }
+}
+
+// X----------X
+// mutable borrowed view into a Matrix
+#[derive(Debug)]
+pub struct MatrixViewMut<'a, T>
+where T: Num
+{
+    data: &'a mut [T],
+    layout: Layout
+}
+
+impl<'a, T> MatrixViewMut<'a, T>
+where T: Num
+{
+    // dim getters
+    pub fn rows(&self) -> usize { self.layout.rows }
+    pub fn cols(&self) -> usize { self.layout.cols }
+    pub fn shape(&self) -> (usize, usize) { (self.layout.rows, self.layout.cols) }
+
+    // stride getters, in elements of the parent buffer
+    pub fn row_stride(&self) -> usize { self.layout.row_stride }
+    pub fn col_stride(&self) -> usize { self.layout.col_stride }
+
+    // checked element accessors, None if (i, j) is out of range
+    pub fn get(&self, i: usize, j: usize) -> Option<&T>
+    {
+        if i >= self.layout.rows || j >= self.layout.cols { return None; }
+
+        self.data.get(self.layout.idx(i, j))
+    }
+
+    pub fn get_mut(&mut self, i: usize, j: usize) -> Option<&mut T>
+    {
+        if i >= self.layout.rows || j >= self.layout.cols { return None; }
+
+        self.data.get_mut(self.layout.idx(i, j))
+    }
+
+    /// # Safety
+    /// Caller must guarantee `i < rows` and `j < cols`.
+    pub unsafe fn get_unchecked(&self, i: usize, j: usize) -> &T
+    {
+        unsafe { self.data.get_unchecked(self.layout.idx(i, j)) }
+    }
+
+    /// # Safety
+    /// Caller must guarantee `i < rows` and `j < cols`.
+    pub unsafe fn get_unchecked_mut(&mut self, i: usize, j: usize) -> &mut T
+    {
+        unsafe { self.data.get_unchecked_mut(self.layout.idx(i, j)) }
+    }
+
+    // bounds check used by Index / IndexMut, names the offending row or col
+    pub(crate) fn check_index(&self, i: usize, j: usize, op: &'static str)
+    {
+        self.layout.check_index(i, j, op);
+    }
+
+    // mutable sub-view, ranges are relative to this view
+    pub fn try_slice_mut(&mut self, rows: Range<usize>, cols: Range<usize>) -> Result<MatrixViewMut<'_, T>, MatrixError>
+    {
+        let layout = self.layout.sub(rows, cols)?;
+
+        Ok(MatrixViewMut { data: self.data, layout })
+    }
+
+    pub fn slice_mut(&mut self, rows: Range<usize>, cols: Range<usize>) -> MatrixViewMut<'_, T>
+    {
+        self.try_slice_mut(rows, cols).unwrap_or_else(|e| panic!("{}", e))
+    }
+
+    pub fn row_mut(&mut self, i: usize) -> MatrixViewMut<'_, T>
+    {
+        self.layout.check_index(i, 0, "row_mut");
+        let cols = self.layout.cols;
+        self.slice_mut(i..i + 1, 0..cols)
+    }
+
+    pub fn col_mut(&mut self, j: usize) -> MatrixViewMut<'_, T>
+    {
+        self.layout.check_index(0, j, "col_mut");
+        let rows = self.layout.rows;
+        self.slice_mut(0..rows, j..j + 1)
+    }
+
+    // sets every viewed element to <value>
+    pub fn fill(&mut self, value: T)
+    where T: Copy
+    {
+        self.apply_inplace(|_| value);
+    }
+
+    // inplace apply over the viewed elements only
+    pub fn apply_inplace(&mut self, func: impl Fn(T) -> T)
+    where T: Copy
+    {
+        for i in 0..self.layout.rows
+        {
+            for j in 0..self.layout.cols
+            {
+                let idx = self.layout.idx(i, j);
+                self.data[idx] = func(self.data[idx]);
+            }
+        }
+    }
+
+    // copies <src> into the viewed elements
+    pub fn copy_from(&mut self, src: &impl AsView<T>)
+    where T: Copy
+    {
+        self.try_copy_from(src).unwrap_or_else(|e| panic!("{}", e))
+    }
+
+    pub fn try_copy_from(&mut self, src: &impl AsView<T>) -> Result<(), MatrixError>
+    where T: Copy
+    {
+        self.zip_assign(&src.as_view(), "copy_from", |_, b| b)
+    }
+
+    // fallible inplace add / sub of another view
+    pub fn try_add_assign(&mut self, rhs: &impl AsView<T>) -> Result<(), MatrixError>
+    where T: Copy
+    {
+        self.zip_assign(&rhs.as_view(), "AddAssign", |a, b| a + b)
+    }
+
+    pub fn try_sub_assign(&mut self, rhs: &impl AsView<T>) -> Result<(), MatrixError>
+    where T: Copy
+    {
+        self.zip_assign(&rhs.as_view(), "SubAssign", |a, b| a - b)
+    }
+
+    fn zip_assign(&mut self, rhs: &MatrixView<'_, T>, op: &'static str, func: impl Fn(T, T) -> T) -> Result<(), MatrixError>
+    where T: Copy
+    {
+        if self.shape() != rhs.shape()
+        {
+            return Err(MatrixError::ShapeMismatch { op, lhs: self.shape(), rhs: rhs.shape() });
+        }
+
+        for i in 0..self.layout.rows
+        {
+            for j in 0..self.layout.cols
+            {
+                let idx = self.layout.idx(i, j);
+                self.data[idx] = func(self.data[idx], rhs.data[rhs.layout.idx(i, j)]);
+            }
+        }
+
+        Ok(())
+    }
+
+    // read ops, forwarded to the read-only view
+    pub fn to_owned(&self) -> Matrix<T>
+    where T: Copy
+    {
+        self.as_view().to_owned()
+    }
+
+    pub fn sum(&self) -> T
+    where T: Copy + std::iter::Sum<T>
+    {
+        self.as_view().sum()
+    }
+
+    pub fn apply(&self, func: impl Fn(T) -> T) -> Matrix<T>
+    where T: Copy
+    {
+        self.as_view().apply(func)
+    }
+
+    pub fn matmul(&self, other: &impl AsView<T>) -> Matrix<T>
+    where T: Copy + AddAssign
+    {
+        self.as_view().matmul(other)
+    }
+}
+
+// X----------X
+// view constructors on Matrix
+impl<T> Matrix<T>
+where T: Num
+{
+    // whole-matrix views
+    pub fn view(&self) -> MatrixView<'_, T>
+    {
+        MatrixView { data: self.as_slice(), layout: Layout::dense(self.rows(), self.cols()) }
+    }
+
+    pub fn view_mut(&mut self) -> MatrixViewMut<'_, T>
+    {
+        let layout = Layout::dense(self.rows(), self.cols());
+
+        MatrixViewMut { data: self.as_mut_slice(), layout }
+    }
+
+    // lazy transpose of the whole matrix, see MatrixView::t
+    pub fn t_view(&self) -> MatrixView<'_, T>
+    {
+        self.view().t()
+    }
+
+    // block views, errors if the ranges are empty or out of bounds
+    pub fn try_slice(&self, rows: Range<usize>, cols: Range<usize>) -> Result<MatrixView<'_, T>, MatrixError>
+    {
+        self.view().try_slice(rows, cols)
+    }
+
+    pub fn slice(&self, rows: Range<usize>, cols: Range<usize>) -> MatrixView<'_, T>
+    {
+        self.view().slice(rows, cols)
+    }
+
+    pub fn try_slice_mut(&mut self, rows: Range<usize>, cols: Range<usize>) -> Result<MatrixViewMut<'_, T>, MatrixError>
+    {
+        let layout = Layout::dense(self.rows(), self.cols()).sub(rows, cols)?;
+
+        Ok(MatrixViewMut { data: self.as_mut_slice(), layout })
+    }
+
+    pub fn slice_mut(&mut self, rows: Range<usize>, cols: Range<usize>) -> MatrixViewMut<'_, T>
+    {
+        self.try_slice_mut(rows, cols).unwrap_or_else(|e| panic!("{}", e))
+    }
+
+    // single row / col views
+    pub fn row(&self, i: usize) -> MatrixView<'_, T>
+    {
+        self.view().row(i)
+    }
+
+    pub fn col(&self, j: usize) -> MatrixView<'_, T>
+    {
+        self.view().col(j)
+    }
+
+    pub fn row_mut(&mut self, i: usize) -> MatrixViewMut<'_, T>
+    {
+        Layout::dense(self.rows(), self.cols()).check_index(i, 0, "row_mut");
+        let cols = self.cols();
+        self.slice_mut(i..i + 1, 0..cols)
+    }
+
+    pub fn col_mut(&mut self, j: usize) -> MatrixViewMut<'_, T>
+    {
+        Layout::dense(self.rows(), self.cols()).check_index(0, j, "col_mut");
+        let rows = self.rows();
+        self.slice_mut(0..rows, j..j + 1)
+    }
+}
+
+// X----------X
+// impl fmt::Display trait for the views, mirrors Matrix
+impl<T> fmt::Display for MatrixView<'_, T>
+where T: Num + Display
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result
+    {
+        matrix::fmt_grid(f, self.layout.rows, self.layout.cols, |i, j| &self.data[self.layout.idx(i, j)])
+    }
+}
+
+impl<T> fmt::Display for MatrixViewMut<'_, T>
+where T: Num + Display
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result
+    {
+        self.as_view().fmt(f)
+    }
+}
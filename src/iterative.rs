@@ -0,0 +1,6 @@
+pub mod bicgstab;
+pub mod cg;
+pub mod gmres;
+pub mod operator;
+pub mod preconditioner;
+pub mod solver;
\ No newline at end of file
@@ -0,0 +1,77 @@
+use num_traits::Float;
+use crate::core::{error::MatrixError, matrix::Matrix};
+use crate::iterative::operator::LinearOperator;
+use crate::iterative::solver::{self, IterativeOptions, IterativeResult, Status};
+
+//----------BiCGSTAB----------//
+
+// A * x = b for general square A (van der Vorst), two A * v per iteration and no A^T
+// a preconditioner is applied on the right, A * M^-1 * u = b with x = M^-1 * u
+// ends in Breakdown when rho = r_hat^T * r or omega vanishes
+pub fn bicgstab<T>(a: &impl LinearOperator<T>, b: &Matrix<T>, options: IterativeOptions<'_, T>) -> IterativeResult<T>
+where T: Float
+{
+    try_bicgstab(a, b, options).unwrap_or_else(|e| panic!("{}", e))
+}
+
+pub fn try_bicgstab<T>(a: &impl LinearOperator<T>, b: &Matrix<T>, options: IterativeOptions<'_, T>) -> Result<IterativeResult<T>, MatrixError>
+where T: Float
+{
+    let (mut x, mut monitor) = solver::setup(a, b, options, "bicgstab")?;
+    let mut r = solver::residual(a, b, &x);
+
+    if let Some(status) = monitor.start(solver::norm(&r)) { return Ok(monitor.finish(x, 0, status)); }
+
+    // shadow residual stays fixed at r0
+    let r_hat = r.clone();
+    let mut p = r.clone();
+    let mut v = Matrix::zeroes(r.rows(), 1)?;
+    let (mut rho, mut alpha, mut omega) = (T::one(), T::one(), T::one());
+
+    let mut k = 0;
+    loop
+    {
+        k += 1;
+
+        let rho_next = solver::dot(&r_hat, &r);
+        if rho_next == T::zero() || rho_next.is_nan() { return Ok(monitor.finish(x, k - 1, Status::Breakdown)); }
+
+        // p = r + beta * (p - omega * v), p = r0 on the first pass
+        if k > 1
+        {
+            solver::axpy(&mut p, -omega, &v);
+            solver::xpby(&r, (rho_next / rho) * (alpha / omega), &mut p);
+        }
+        rho = rho_next;
+
+        let p_hat = monitor.precondition(&p);
+        v = a.apply(&p_hat);
+        let rv = solver::dot(&r_hat, &v);
+        if rv == T::zero() || rv.is_nan() { return Ok(monitor.finish(x, k - 1, Status::Breakdown)); }
+
+        // half step, r becomes s = r - alpha * v
+        alpha = rho / rv;
+        solver::axpy(&mut x, alpha, &p_hat);
+        solver::axpy(&mut r, -alpha, &v);
+
+        let s_norm = solver::norm(&r);
+        if monitor.reached(s_norm)
+        {
+            let status = monitor.step(k, s_norm).unwrap();
+            return Ok(monitor.finish(x, k, status));
+        }
+
+        // stabilising step, omega minimises ||s - omega * t||
+        let s_hat = monitor.precondition(&r);
+        let t = a.apply(&s_hat);
+        let tt = solver::dot(&t, &t);
+        if tt == T::zero() || tt.is_nan() { return Ok(monitor.finish(x, k, Status::Breakdown)); }
+
+        omega = solver::dot(&t, &r) / tt;
+        solver::axpy(&mut x, omega, &s_hat);
+        solver::axpy(&mut r, -omega, &t);
+
+        if let Some(status) = monitor.step(k, solver::norm(&r)) { return Ok(monitor.finish(x, k, status)); }
+        if omega == T::zero() { return Ok(monitor.finish(x, k, Status::Breakdown)); }
+    }
+}
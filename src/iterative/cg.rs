@@ -0,0 +1,51 @@
+use num_traits::Float;
+use crate::core::{error::MatrixError, matrix::Matrix};
+use crate::iterative::operator::LinearOperator;
+use crate::iterative::solver::{self, IterativeOptions, IterativeResult, Status};
+
+//----------Conjugate Gradient----------//
+
+// A * x = b for symmetric positive definite A, one A * p per iteration
+// with a preconditioner this is PCG, which needs M symmetric positive definite as well
+// ends in Breakdown if p^T * A * p <= 0, i.e. A is not positive definite
+pub fn cg<T>(a: &impl LinearOperator<T>, b: &Matrix<T>, options: IterativeOptions<'_, T>) -> IterativeResult<T>
+where T: Float
+{
+    try_cg(a, b, options).unwrap_or_else(|e| panic!("{}", e))
+}
+
+pub fn try_cg<T>(a: &impl LinearOperator<T>, b: &Matrix<T>, options: IterativeOptions<'_, T>) -> Result<IterativeResult<T>, MatrixError>
+where T: Float
+{
+    let (mut x, mut monitor) = solver::setup(a, b, options, "cg")?;
+    let mut r = solver::residual(a, b, &x);
+
+    if let Some(status) = monitor.start(solver::norm(&r)) { return Ok(monitor.finish(x, 0, status)); }
+
+    let mut z = monitor.precondition(&r);
+    let mut p = z.clone();
+    let mut rz = solver::dot(&r, &z);
+
+    let mut k = 0;
+    loop
+    {
+        k += 1;
+
+        let ap = a.apply(&p);
+        let pap = solver::dot(&p, &ap);
+
+        if pap.is_nan() || pap <= T::zero() { return Ok(monitor.finish(x, k - 1, Status::Breakdown)); }
+
+        let alpha = rz / pap;
+        solver::axpy(&mut x, alpha, &p);
+        solver::axpy(&mut r, -alpha, &ap);
+
+        if let Some(status) = monitor.step(k, solver::norm(&r)) { return Ok(monitor.finish(x, k, status)); }
+
+        // p = z + beta * p with z = M^-1 * r
+        z = monitor.precondition(&r);
+        let rz_next = solver::dot(&r, &z);
+        solver::xpby(&z, rz_next / rz, &mut p);
+        rz = rz_next;
+    }
+}
@@ -0,0 +1,111 @@
+use num_traits::Float;
+use crate::core::{error::MatrixError, matrix::Matrix};
+use crate::iterative::operator::LinearOperator;
+use crate::iterative::solver::{self, IterativeOptions, IterativeResult, Status};
+
+//----------Restarted GMRES----------//
+
+// A * x = b for general square A, minimises ||b - A * x|| over a Krylov space of at most `restart` vectors,
+// then restarts from the true residual; one A * v per iteration, the residual history inside a cycle
+// is the Givens estimate |g_(j + 1)| rather than a recomputed norm
+// a preconditioner is applied on the right, so that estimate is still ||b - A * x||
+pub fn gmres<T>(a: &impl LinearOperator<T>, b: &Matrix<T>, options: IterativeOptions<'_, T>) -> IterativeResult<T>
+where T: Float
+{
+    try_gmres(a, b, options).unwrap_or_else(|e| panic!("{}", e))
+}
+
+pub fn try_gmres<T>(a: &impl LinearOperator<T>, b: &Matrix<T>, options: IterativeOptions<'_, T>) -> Result<IterativeResult<T>, MatrixError>
+where T: Float
+{
+    // the Krylov space of an (n x n) A has at most n dimensions
+    let restart = options.restart.min(a.shape().0).max(1);
+    let (mut x, mut monitor) = solver::setup(a, b, options, "gmres")?;
+    let mut r = solver::residual(a, b, &x);
+
+    if let Some(status) = monitor.start(solver::norm(&r)) { return Ok(monitor.finish(x, 0, status)); }
+
+    let mut k = 0;
+    loop
+    {
+        let beta = solver::norm(&r);
+
+        // orthonormal Krylov basis, H in rotated (upper triangular) form column by column, rhs g
+        let mut basis = vec![r.apply(|ri| ri / beta)];
+        let mut h: Vec<Vec<T>> = Vec::with_capacity(restart);
+        let mut rotations: Vec<(T, T)> = Vec::with_capacity(restart);
+        let mut g = vec![T::zero(); restart + 1];
+        g[0] = beta;
+
+        let mut stop = None;
+
+        for j in 0..restart
+        {
+            k += 1;
+
+            // Arnoldi step with modified Gram-Schmidt
+            let mut w = a.apply(&monitor.precondition(&basis[j]));
+            let mut col = Vec::with_capacity(j + 2);
+
+            for v in basis.iter()
+            {
+                let hij = solver::dot(&w, v);
+                solver::axpy(&mut w, -hij, v);
+                col.push(hij);
+            }
+
+            let h_next = solver::norm(&w);
+            col.push(h_next);
+
+            // earlier rotations, then a new one that zeroes the subdiagonal entry
+            for (i, &(c, s)) in rotations.iter().enumerate()
+            {
+                let (u, v) = (col[i], col[i + 1]);
+                col[i] = c * u + s * v;
+                col[i + 1] = c * v - s * u;
+            }
+
+            let denom = col[j].hypot(col[j + 1]);
+            if denom == T::zero() || denom.is_nan()
+            {
+                stop = Some(Status::Breakdown);
+                break;
+            }
+
+            let (c, s) = (col[j] / denom, col[j + 1] / denom);
+            col[j] = denom;
+            col[j + 1] = T::zero();
+            rotations.push((c, s));
+            h.push(col);
+
+            g[j + 1] = -s * g[j];
+            g[j] = c * g[j];
+
+            stop = monitor.step(k, g[j + 1].abs());
+
+            // happy breakdown, the Krylov space is invariant so the update below is exact
+            if stop.is_none() && h_next == T::zero() { stop = Some(Status::Converged); }
+            if stop.is_some() { break; }
+
+            basis.push(w.apply(|wi| wi / h_next));
+        }
+
+        // x += M^-1 * V * y with H * y = g, back substitution on the rotated columns
+        let m = h.len();
+        let mut y = vec![T::zero(); m];
+
+        for i in (0..m).rev()
+        {
+            let dot = (i + 1..m).fold(T::zero(), |acc, j| acc + h[j][i] * y[j]);
+            y[i] = (g[i] - dot) / h[i][i];
+        }
+
+        let mut dx = Matrix::zeroes(x.rows(), 1)?;
+        for (v, &yi) in basis.iter().zip(y.iter()) { solver::axpy(&mut dx, yi, v); }
+        solver::axpy(&mut x, T::one(), &monitor.precondition(&dx));
+
+        if let Some(status) = stop { return Ok(monitor.finish(x, k, status)); }
+
+        r = solver::residual(a, b, &x);
+    }
+}
@@ -0,0 +1,42 @@
+use std::ops::AddAssign;
+use num_traits::Num;
+use crate::core::matrix::Matrix;
+use crate::sparse::{csc::CscMatrix, csr::CsrMatrix};
+
+//----------Linear Operators----------//
+
+// everything the Krylov solvers need from A is the product A * x,
+// so A can be dense, sparse, or matrix-free
+pub trait LinearOperator<T>
+where T: Num + Copy
+{
+    // (rows, cols) of A
+    fn shape(&self) -> (usize, usize);
+
+    // A * x for a (cols x 1) x, returns (rows x 1)
+    fn apply(&self, x: &Matrix<T>) -> Matrix<T>;
+}
+
+impl<T> LinearOperator<T> for Matrix<T>
+where T: Num + Copy + AddAssign
+{
+    fn shape(&self) -> (usize, usize) { Matrix::shape(self) }
+
+    fn apply(&self, x: &Matrix<T>) -> Matrix<T> { self.matmul(x) }
+}
+
+impl<T> LinearOperator<T> for CsrMatrix<T>
+where T: Num + Copy
+{
+    fn shape(&self) -> (usize, usize) { CsrMatrix::shape(self) }
+
+    fn apply(&self, x: &Matrix<T>) -> Matrix<T> { self.matmul(x) }
+}
+
+impl<T> LinearOperator<T> for CscMatrix<T>
+where T: Num + Copy
+{
+    fn shape(&self) -> (usize, usize) { CscMatrix::shape(self) }
+
+    fn apply(&self, x: &Matrix<T>) -> Matrix<T> { self.matmul(x) }
+}
@@ -0,0 +1,379 @@
+use num_traits::{Float, Num};
+use crate::core::{error::MatrixError, matrix::Matrix};
+use crate::sparse::{compressed::Compressed, csc::CscMatrix, csr::CsrMatrix};
+
+//----------Preconditioners----------//
+
+// M ~ A that is cheap to invert, the Krylov solvers then work on M^-1 * A instead of A
+pub trait Preconditioner<T>
+where T: Float
+{
+    // n of the (n x n) M
+    fn size(&self) -> usize;
+
+    // z = M^-1 * r for an (n x 1) r
+    fn apply(&self, r: &Matrix<T>) -> Matrix<T>;
+}
+
+// matrices a preconditioner can be built from, everything below reads A row by row
+pub trait ToCsr<T>
+where T: Num + Copy
+{
+    fn to_csr(&self) -> CsrMatrix<T>;
+}
+
+impl<T> ToCsr<T> for Matrix<T>
+where T: Num + Copy
+{
+    // the nonzeros of a dense matrix are its pattern, so IC(0) / ILU(0) of a full matrix are the exact factors
+    fn to_csr(&self) -> CsrMatrix<T> { CsrMatrix::from_dense(self) }
+}
+
+impl<T> ToCsr<T> for CsrMatrix<T>
+where T: Num + Copy
+{
+    fn to_csr(&self) -> CsrMatrix<T> { self.clone() }
+}
+
+impl<T> ToCsr<T> for CscMatrix<T>
+where T: Num + Copy
+{
+    fn to_csr(&self) -> CsrMatrix<T> { CscMatrix::to_csr(self) }
+}
+
+fn square_csr<T>(a: &impl ToCsr<T>, op: &'static str) -> Result<CsrMatrix<T>, MatrixError>
+where T: Num + Copy
+{
+    let a = a.to_csr();
+    if a.rows() != a.cols() { return Err(MatrixError::NotSquare { op, rows: a.rows(), cols: a.cols() }); }
+    Ok(a)
+}
+
+// X----------X
+// Jacobi
+
+// M = diag(A)
+#[derive(Debug, Clone, PartialEq)]
+pub struct Jacobi<T>
+where T: Float
+{
+    inv_diag: Vec<T>,
+}
+
+impl<T> Jacobi<T>
+where T: Float
+{
+    pub fn new(a: &impl ToCsr<T>) -> Result<Self, MatrixError>
+    {
+        const OP: &str = "jacobi";
+
+        let a = square_csr(a, OP)?;
+        let inv_diag = (0..a.rows())
+            .map(|i| match a.get(i, i).unwrap()
+            {
+                d if d == T::zero() => Err(MatrixError::Singular { op: OP }),
+                d => Ok(d.recip()),
+            })
+            .collect::<Result<Vec<T>, MatrixError>>()?;
+
+        Ok(Jacobi { inv_diag })
+    }
+}
+
+impl<T> Preconditioner<T> for Jacobi<T>
+where T: Float
+{
+    fn size(&self) -> usize { self.inv_diag.len() }
+
+    fn apply(&self, r: &Matrix<T>) -> Matrix<T>
+    {
+        let mut z = r.clone();
+        for (zi, &di) in z.as_mut_slice().iter_mut().zip(self.inv_diag.iter()) { *zi = *zi * di; }
+        z
+    }
+}
+
+// X----------X
+// SSOR
+
+// M = w / (2 - w) * (D / w + L) * (D / w)^-1 * (D / w + U) with A = L + D + U, symmetric when A is
+// w = 1 is symmetric Gauss-Seidel
+#[derive(Debug, Clone, PartialEq)]
+pub struct Ssor<T>
+where T: Float
+{
+    a: CsrMatrix<T>,
+    diag: Vec<T>,
+    omega: T,
+}
+
+impl<T> Ssor<T>
+where T: Float
+{
+    pub fn new(a: &impl ToCsr<T>, omega: T) -> Result<Self, MatrixError>
+    {
+        const OP: &str = "ssor";
+
+        let two = T::one() + T::one();
+        if omega.is_nan() || omega <= T::zero() || omega >= two
+        {
+            return Err(MatrixError::InvalidParameter { op: OP, reason: "omega must lie in (0, 2)" });
+        }
+
+        let a = square_csr(a, OP)?;
+        let diag: Vec<T> = (0..a.rows()).map(|i| a.get(i, i).unwrap()).collect();
+        if diag.iter().any(|&d| d == T::zero()) { return Err(MatrixError::Singular { op: OP }); }
+
+        Ok(Ssor { a, diag, omega })
+    }
+
+    pub fn omega(&self) -> T { self.omega }
+}
+
+impl<T> Preconditioner<T> for Ssor<T>
+where T: Float
+{
+    fn size(&self) -> usize { self.diag.len() }
+
+    fn apply(&self, r: &Matrix<T>) -> Matrix<T>
+    {
+        let n = self.size();
+        let w = self.omega;
+        let mut z = r.clone();
+        let y = z.as_mut_slice();
+
+        // (D / w + L) * y = r
+        for i in 0..n
+        {
+            let s = self.a.row(i).take_while(|&(j, _)| j < i).fold(T::zero(), |acc, (j, aij)| acc + aij * y[j]);
+            y[i] = (y[i] - s) * w / self.diag[i];
+        }
+
+        // y <- (2 - w) / w * (D / w) * y, the outer scale folded in
+        let two = T::one() + T::one();
+        for (yi, &di) in y.iter_mut().zip(self.diag.iter()) { *yi = *yi * di * (two - w) / (w * w); }
+
+        // (D / w + U) * z = y
+        for i in (0..n).rev()
+        {
+            let s = self.a.row(i).filter(|&(j, _)| j > i).fold(T::zero(), |acc, (j, aij)| acc + aij * y[j]);
+            y[i] = (y[i] - s) * w / self.diag[i];
+        }
+
+        z
+    }
+}
+
+// X----------X
+// incomplete Cholesky
+
+// IC(0), M = L * L^T with L restricted to the pattern of the lower triangle of A
+// only the lower triangle is read, A should be symmetric positive definite
+#[derive(Debug, Clone, PartialEq)]
+pub struct IncompleteCholesky<T>
+where T: Float
+{
+    // diagonal is the last entry of every row
+    l: CsrMatrix<T>,
+}
+
+impl<T> IncompleteCholesky<T>
+where T: Float
+{
+    pub fn new(a: &impl ToCsr<T>) -> Result<Self, MatrixError>
+    {
+        const OP: &str = "ic0";
+
+        let a = square_csr(a, OP)?;
+        let n = a.rows();
+
+        // lower triangle including the diagonal, which has to be stored
+        let mut indptr = vec![0; n + 1];
+        let mut indices = Vec::new();
+        let mut values = Vec::new();
+
+        for i in 0..n
+        {
+            for (j, x) in a.row(i).take_while(|&(j, _)| j <= i)
+            {
+                indices.push(j);
+                values.push(x);
+            }
+            if indices.last() != Some(&i) { return Err(MatrixError::NotPositiveDefinite { op: OP }); }
+            indptr[i + 1] = indices.len();
+        }
+
+        // row by row, l_ik = (a_ik - sum_{j < k} l_ij * l_kj) / l_kk on the kept pattern only
+        for i in 0..n
+        {
+            for q in indptr[i]..indptr[i + 1]
+            {
+                let k = indices[q];
+                let dot = sparse_dot(&indices[indptr[i]..q], &values[indptr[i]..q], &indices[indptr[k]..indptr[k + 1] - 1], &values[indptr[k]..indptr[k + 1] - 1]);
+
+                if k < i
+                {
+                    values[q] = (values[q] - dot) / values[indptr[k + 1] - 1];
+                }
+                else
+                {
+                    // also catches NaN
+                    let d = values[q] - dot;
+                    if d.is_nan() || d <= T::zero() { return Err(MatrixError::NotPositiveDefinite { op: OP }); }
+                    values[q] = d.sqrt();
+                }
+            }
+        }
+
+        Ok(IncompleteCholesky { l: CsrMatrix::from_compressed(Compressed { major: n, minor: n, indptr, indices, values }) })
+    }
+
+    // the incomplete factor L in CSR
+    pub fn l(&self) -> &CsrMatrix<T> { &self.l }
+}
+
+// sum of x_a[p] * x_b[q] over matching indices of two sorted index lists
+fn sparse_dot<T>(ia: &[usize], xa: &[T], ib: &[usize], xb: &[T]) -> T
+where T: Float
+{
+    let (mut p, mut q) = (0, 0);
+    let mut acc = T::zero();
+
+    while p < ia.len() && q < ib.len()
+    {
+        if ia[p] < ib[q] { p += 1; }
+        else if ib[q] < ia[p] { q += 1; }
+        else
+        {
+            acc = acc + xa[p] * xb[q];
+            p += 1;
+            q += 1;
+        }
+    }
+
+    acc
+}
+
+impl<T> Preconditioner<T> for IncompleteCholesky<T>
+where T: Float
+{
+    fn size(&self) -> usize { self.l.rows() }
+
+    fn apply(&self, r: &Matrix<T>) -> Matrix<T>
+    {
+        let n = self.size();
+        let (lp, li, lx) = (self.l.indptr(), self.l.indices(), self.l.values());
+        let mut z = r.clone();
+        let y = z.as_mut_slice();
+
+        // L * y = r
+        for i in 0..n
+        {
+            let diag = lp[i + 1] - 1;
+            let s = (lp[i]..diag).fold(T::zero(), |acc, q| acc + lx[q] * y[li[q]]);
+            y[i] = (y[i] - s) / lx[diag];
+        }
+
+        // L^T * z = y, row i of L is column i of L^T
+        for i in (0..n).rev()
+        {
+            let diag = lp[i + 1] - 1;
+            y[i] = y[i] / lx[diag];
+            for q in lp[i]..diag { y[li[q]] = y[li[q]] - lx[q] * y[i]; }
+        }
+
+        z
+    }
+}
+
+// X----------X
+// incomplete LU
+
+// ILU(0), M = L * U with both factors restricted to the pattern of A, no pivoting
+#[derive(Debug, Clone, PartialEq)]
+pub struct IncompleteLU<T>
+where T: Float
+{
+    // L strictly below the diagonal (unit diagonal implied), U on and above it
+    lu: CsrMatrix<T>,
+    diag: Vec<usize>,
+}
+
+impl<T> IncompleteLU<T>
+where T: Float
+{
+    pub fn new(a: &impl ToCsr<T>) -> Result<Self, MatrixError>
+    {
+        const OP: &str = "ilu0";
+
+        let a = square_csr(a, OP)?;
+        let n = a.rows();
+        let (indptr, indices) = (a.indptr().to_vec(), a.indices().to_vec());
+        let mut values = a.values().to_vec();
+
+        // position of every diagonal entry, a missing one is a zero pivot
+        let diag = (0..n)
+            .map(|i| indices[indptr[i]..indptr[i + 1]].binary_search(&i).map(|q| indptr[i] + q).map_err(|_| MatrixError::Singular { op: OP }))
+            .collect::<Result<Vec<usize>, MatrixError>>()?;
+
+        // IKJ variant: row i is eliminated against every earlier row k it touches, fill outside the pattern is dropped
+        let mut pos: Vec<Option<usize>> = vec![None; n];
+
+        for i in 0..n
+        {
+            for q in indptr[i]..indptr[i + 1] { pos[indices[q]] = Some(q); }
+
+            for q in indptr[i]..diag[i]
+            {
+                let k = indices[q];
+                values[q] = values[q] / values[diag[k]];
+                let lik = values[q];
+
+                for r in diag[k] + 1..indptr[k + 1]
+                {
+                    if let Some(p) = pos[indices[r]] { values[p] = values[p] - lik * values[r]; }
+                }
+            }
+
+            for q in indptr[i]..indptr[i + 1] { pos[indices[q]] = None; }
+
+            let pivot = values[diag[i]];
+            if pivot == T::zero() || pivot.is_nan() { return Err(MatrixError::Singular { op: OP }); }
+        }
+
+        Ok(IncompleteLU { lu: CsrMatrix::from_compressed(Compressed { major: n, minor: n, indptr, indices, values }), diag })
+    }
+
+    // L and U packed in one CSR matrix, the unit diagonal of L is not stored
+    pub fn lu(&self) -> &CsrMatrix<T> { &self.lu }
+}
+
+impl<T> Preconditioner<T> for IncompleteLU<T>
+where T: Float
+{
+    fn size(&self) -> usize { self.diag.len() }
+
+    fn apply(&self, r: &Matrix<T>) -> Matrix<T>
+    {
+        let n = self.size();
+        let (lp, li, lx) = (self.lu.indptr(), self.lu.indices(), self.lu.values());
+        let mut z = r.clone();
+        let y = z.as_mut_slice();
+
+        // L * y = r, unit diagonal
+        for i in 0..n
+        {
+            let s = (lp[i]..self.diag[i]).fold(T::zero(), |acc, q| acc + lx[q] * y[li[q]]);
+            y[i] = y[i] - s;
+        }
+
+        // U * z = y
+        for i in (0..n).rev()
+        {
+            let s = (self.diag[i] + 1..lp[i + 1]).fold(T::zero(), |acc, q| acc + lx[q] * y[li[q]]);
+            y[i] = (y[i] - s) / lx[self.diag[i]];
+        }
+
+        z
+    }
+}
@@ -0,0 +1,255 @@
+use num_traits::Float;
+use crate::core::{error::MatrixError, matrix::Matrix};
+use crate::iterative::{operator::LinearOperator, preconditioner::Preconditioner};
+
+//----------Shared Pieces of the Krylov Solvers----------//
+
+// why an iterative solve stopped
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Status
+{
+    // relative residual reached the tolerance
+    Converged,
+    // ran out of iterations first
+    MaxIterations,
+    // the recurrence hit a zero (or NaN) denominator, the solution is the last iterate
+    Breakdown,
+    // the callback asked to stop
+    Stopped,
+}
+
+// builder-style settings, every solver reads the ones that apply to it
+pub struct IterativeOptions<'a, T>
+where T: Float
+{
+    pub(crate) tol: T,
+    pub(crate) max_iter: Option<usize>,
+    pub(crate) restart: usize,
+    pub(crate) x0: Option<Matrix<T>>,
+    pub(crate) callback: Option<Box<dyn FnMut(usize, T) -> bool + 'a>>,
+    pub(crate) preconditioner: Option<&'a dyn Preconditioner<T>>,
+}
+
+impl<T> Default for IterativeOptions<'_, T>
+where T: Float
+{
+    fn default() -> Self { Self::new() }
+}
+
+impl<'a, T> IterativeOptions<'a, T>
+where T: Float
+{
+    // tol = sqrt(eps), max_iter = 10 * n, restart = 30, x0 = 0, no callback, no preconditioner
+    pub fn new() -> Self
+    {
+        IterativeOptions { tol: T::epsilon().sqrt(), max_iter: None, restart: 30, x0: None, callback: None, preconditioner: None }
+    }
+
+    // stop once ||b - A * x|| <= tol * ||b||
+    pub fn tol(mut self, tol: T) -> Self
+    {
+        self.tol = tol;
+        self
+    }
+
+    pub fn max_iter(mut self, max_iter: usize) -> Self
+    {
+        self.max_iter = Some(max_iter);
+        self
+    }
+
+    // GMRES only, size of the Krylov basis before a restart (at least 1)
+    pub fn restart(mut self, restart: usize) -> Self
+    {
+        self.restart = restart;
+        self
+    }
+
+    // starting guess (n x 1)
+    pub fn x0(mut self, x0: Matrix<T>) -> Self
+    {
+        self.x0 = Some(x0);
+        self
+    }
+
+    // called after every iteration with (iteration, relative residual), returning false stops the solve
+    pub fn callback(mut self, callback: impl FnMut(usize, T) -> bool + 'a) -> Self
+    {
+        self.callback = Some(Box::new(callback));
+        self
+    }
+
+    // M ~ A, applied on the left by CG and on the right by BiCGSTAB and GMRES,
+    // so the residuals reported are always those of the original system
+    pub fn preconditioner(mut self, preconditioner: &'a dyn Preconditioner<T>) -> Self
+    {
+        self.preconditioner = Some(preconditioner);
+        self
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct IterativeResult<T>
+where T: Float
+{
+    solution: Matrix<T>,
+    iterations: usize,
+    // relative residual of the starting guess, then one entry per iteration
+    residuals: Vec<T>,
+    status: Status,
+}
+
+impl<T> IterativeResult<T>
+where T: Float
+{
+    pub fn solution(&self) -> &Matrix<T> { &self.solution }
+    pub fn into_solution(self) -> Matrix<T> { self.solution }
+
+    pub fn iterations(&self) -> usize { self.iterations }
+    pub fn residuals(&self) -> &[T] { &self.residuals }
+
+    // last relative residual, ||b - A * x|| / ||b||
+    pub fn residual(&self) -> T { *self.residuals.last().unwrap() }
+
+    pub fn status(&self) -> Status { self.status }
+    pub fn converged(&self) -> bool { self.status == Status::Converged }
+}
+
+// X----------X
+// driver state shared by the solvers
+
+// residual bookkeeping, tolerance test, callback and preconditioner in one place
+pub(crate) struct Monitor<'a, T>
+where T: Float
+{
+    tol: T,
+    // ||b||, or 1 for b = 0 so the test falls back to an absolute one
+    scale: T,
+    residuals: Vec<T>,
+    callback: Option<Box<dyn FnMut(usize, T) -> bool + 'a>>,
+    preconditioner: Option<&'a dyn Preconditioner<T>>,
+    max_iter: usize,
+}
+
+impl<T> Monitor<'_, T>
+where T: Float
+{
+    pub(crate) fn reached(&self, rnorm: T) -> bool { rnorm <= self.tol * self.scale }
+
+    // M^-1 * r, a copy of r without a preconditioner
+    pub(crate) fn precondition(&self, r: &Matrix<T>) -> Matrix<T>
+    {
+        match self.preconditioner
+        {
+            Some(m) => m.apply(r),
+            None => r.clone(),
+        }
+    }
+
+    // records the residual of the starting guess, Some(status) if no iteration should run
+    pub(crate) fn start(&mut self, rnorm: T) -> Option<Status>
+    {
+        self.residuals.push(rnorm / self.scale);
+
+        if self.reached(rnorm) { return Some(Status::Converged); }
+        if self.max_iter == 0 { return Some(Status::MaxIterations); }
+
+        None
+    }
+
+    // records the residual after iteration k, Some(status) once the solve should end
+    pub(crate) fn step(&mut self, k: usize, rnorm: T) -> Option<Status>
+    {
+        let rel = rnorm / self.scale;
+        self.residuals.push(rel);
+
+        let go_on = self.callback.as_mut().is_none_or(|f| f(k, rel));
+
+        if self.reached(rnorm) { return Some(Status::Converged); }
+        if rel.is_nan() { return Some(Status::Breakdown); }
+        if !go_on { return Some(Status::Stopped); }
+        if k >= self.max_iter { return Some(Status::MaxIterations); }
+
+        None
+    }
+
+    pub(crate) fn finish(self, solution: Matrix<T>, iterations: usize, status: Status) -> IterativeResult<T>
+    {
+        IterativeResult { solution, iterations, residuals: self.residuals, status }
+    }
+}
+
+// checks shapes, returns x0 and the monitor for this solve
+pub(crate) fn setup<'a, T>(a: &impl LinearOperator<T>, b: &Matrix<T>, options: IterativeOptions<'a, T>, op: &'static str) -> Result<(Matrix<T>, Monitor<'a, T>), MatrixError>
+where T: Float
+{
+    let (rows, cols) = a.shape();
+    if rows != cols { return Err(MatrixError::NotSquare { op, rows, cols }); }
+
+    let n = rows;
+    if b.shape() != (n, 1) { return Err(MatrixError::ShapeMismatch { op, lhs: (n, 1), rhs: b.shape() }); }
+
+    let x = match options.x0
+    {
+        Some(x0) if x0.shape() != (n, 1) => return Err(MatrixError::ShapeMismatch { op, lhs: (n, 1), rhs: x0.shape() }),
+        Some(x0) => x0,
+        None => Matrix::zeroes(n, 1)?,
+    };
+
+    if let Some(m) = options.preconditioner.filter(|m| m.size() != n)
+    {
+        return Err(MatrixError::ShapeMismatch { op, lhs: (n, n), rhs: (m.size(), m.size()) });
+    }
+
+    let bnorm = norm(b);
+    let scale = if bnorm == T::zero() { T::one() } else { bnorm };
+    let monitor = Monitor
+    {
+        tol: options.tol,
+        scale,
+        residuals: Vec::new(),
+        callback: options.callback,
+        preconditioner: options.preconditioner,
+        max_iter: options.max_iter.unwrap_or(10 * n),
+    };
+
+    Ok((x, monitor))
+}
+
+// X----------X
+// vector kernels on (n x 1) matrices
+
+// b - A * x
+pub(crate) fn residual<T>(a: &impl LinearOperator<T>, b: &Matrix<T>, x: &Matrix<T>) -> Matrix<T>
+where T: Float
+{
+    let mut r = a.apply(x);
+    for (ri, &bi) in r.as_mut_slice().iter_mut().zip(b.as_slice().iter()) { *ri = bi - *ri; }
+    r
+}
+
+pub(crate) fn dot<T>(x: &Matrix<T>, y: &Matrix<T>) -> T
+where T: Float
+{
+    x.as_slice().iter().zip(y.as_slice().iter()).fold(T::zero(), |acc, (&a, &b)| acc + a * b)
+}
+
+pub(crate) fn norm<T>(x: &Matrix<T>) -> T
+where T: Float
+{
+    dot(x, x).sqrt()
+}
+
+// y += alpha * x
+pub(crate) fn axpy<T>(y: &mut Matrix<T>, alpha: T, x: &Matrix<T>)
+where T: Float
+{
+    for (yi, &xi) in y.as_mut_slice().iter_mut().zip(x.as_slice().iter()) { *yi = *yi + alpha * xi; }
+}
+
+// y = x + beta * y
+pub(crate) fn xpby<T>(x: &Matrix<T>, beta: T, y: &mut Matrix<T>)
+where T: Float
+{
+    for (yi, &xi) in y.as_mut_slice().iter_mut().zip(x.as_slice().iter()) { *yi = xi + beta * *yi; }
+}
@@ -1,5 +1,8 @@
 pub mod core;
+pub mod iterative;
+pub mod linalg;
 pub mod ops;
+pub mod sparse;
 
 // defining the user-facing macro
 #[macro_export]
@@ -0,0 +1,24 @@
+pub mod cholesky;
+pub mod eigen;
+pub mod exact;
+pub mod ldlt;
+pub mod lu;
+pub mod pinv;
+pub mod qr;
+pub mod qr_pivoted;
+pub mod solve;
+pub mod svd;
+pub mod symmetric_eigen;
+pub mod triangular;
+
+use std::cmp::Ordering;
+use num_traits::Float;
+
+//----------Shared Helpers----------//
+
+// total order for sorting, NaN compares above every number so a sort can never panic
+// (num_traits::Float has no total_cmp)
+pub(crate) fn total_cmp<T: Float>(a: &T, b: &T) -> Ordering
+{
+    a.partial_cmp(b).unwrap_or_else(|| a.is_nan().cmp(&b.is_nan()))
+}
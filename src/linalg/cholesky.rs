@@ -0,0 +1,230 @@
+use num_traits::Float;
+use crate::core::{error::MatrixError, matrix::Matrix};
+
+//----------Cholesky Decomposition----------//
+
+// A = L * L^T for symmetric positive definite A, L lower triangular with a positive diagonal
+#[derive(Debug, Clone, PartialEq)]
+pub struct Cholesky<T>
+where T: Float
+{
+    // strict upper part is kept at zero
+    l: Matrix<T>,
+}
+
+impl<T> Matrix<T>
+where T: Float
+{
+    pub fn cholesky(&self) -> Cholesky<T>
+    {
+        self.try_cholesky().unwrap_or_else(|e| panic!("{}", e))
+    }
+
+    pub fn try_cholesky(&self) -> Result<Cholesky<T>, MatrixError>
+    {
+        const OP: &str = "cholesky";
+
+        self.check_symmetric(OP)?;
+
+        let n = self.rows();
+        let mut l = Matrix::zeroes(n, n)?;
+        let a = self.as_slice();
+        let out = l.as_mut_slice();
+
+        // row by row (Cholesky-Banachiewicz), each entry only needs finished rows
+        for i in 0..n
+        {
+            for j in 0..=i
+            {
+                let dot = (0..j).fold(T::zero(), |acc, k| acc + out[i * n + k] * out[j * n + k]);
+                let s = a[i * n + j] - dot;
+
+                if i == j
+                {
+                    // also catches NaN
+                    if s.is_nan() || s <= T::zero() { return Err(MatrixError::NotPositiveDefinite { op: OP }); }
+                    out[i * n + i] = s.sqrt();
+                }
+                else
+                {
+                    out[i * n + j] = s / out[j * n + j];
+                }
+            }
+        }
+
+        Ok(Cholesky { l })
+    }
+
+    // symmetric up to rounding, |a_ij - a_ji| <= n * eps * max|a|
+    pub(crate) fn check_symmetric(&self, op: &'static str) -> Result<(), MatrixError>
+    {
+        self.check_square(op)?;
+
+        let n = self.rows();
+        let a = self.as_slice();
+        let scale = a.iter().fold(T::zero(), |acc, x| acc.max(x.abs()));
+        let tol = T::from(n).unwrap() * T::epsilon() * scale;
+
+        for i in 0..n
+        {
+            for j in 0..i
+            {
+                if (a[i * n + j] - a[j * n + i]).abs() > tol { return Err(MatrixError::NotSymmetric { op }); }
+            }
+        }
+
+        Ok(())
+    }
+}
+
+impl<T> Cholesky<T>
+where T: Float
+{
+    pub fn size(&self) -> usize { self.l.rows() }
+
+    pub fn l(&self) -> &Matrix<T> { &self.l }
+
+    // log(det(A)) = 2 * sum(log(l_ii)), stays finite where det() would overflow
+    pub fn log_det(&self) -> T
+    {
+        let n = self.size();
+        let two = T::one() + T::one();
+        (0..n).fold(T::zero(), |acc, i| acc + self.l.as_slice()[i * n + i].ln()) * two
+    }
+
+    pub fn det(&self) -> T
+    {
+        let n = self.size();
+        let prod = (0..n).fold(T::one(), |acc, i| acc * self.l.as_slice()[i * n + i]);
+        prod * prod
+    }
+
+    // solves A * X = B for every column of B
+    pub fn solve(&self, b: &Matrix<T>) -> Matrix<T>
+    {
+        self.try_solve(b).unwrap_or_else(|e| panic!("{}", e))
+    }
+
+    pub fn try_solve(&self, b: &Matrix<T>) -> Result<Matrix<T>, MatrixError>
+    {
+        let mut x = b.clone();
+        self.try_solve_in_place(&mut x)?;
+        Ok(x)
+    }
+
+    pub fn solve_in_place(&self, b: &mut Matrix<T>)
+    {
+        self.try_solve_in_place(b).unwrap_or_else(|e| panic!("{}", e))
+    }
+
+    pub fn try_solve_in_place(&self, b: &mut Matrix<T>) -> Result<(), MatrixError>
+    {
+        let n = self.size();
+
+        if b.rows() != n
+        {
+            return Err(MatrixError::InnerDimMismatch { op: "solve", lhs: (n, n), rhs: b.shape() });
+        }
+
+        let w = b.cols();
+        let l = self.l.as_slice();
+        let x = b.as_mut_slice();
+
+        // L * y = b
+        for i in 0..n
+        {
+            let (done, rest) = x.split_at_mut(i * w);
+            let row = &mut rest[..w];
+
+            for (k, done_row) in done.chunks_exact(w).enumerate()
+            {
+                let lik = l[i * n + k];
+                for (r, d) in row.iter_mut().zip(done_row.iter()) { *r = *r - lik * *d; }
+            }
+
+            let diag = l[i * n + i];
+            for r in row.iter_mut() { *r = *r / diag; }
+        }
+
+        // L^T * x = y, column i of L is row i of L^T
+        for i in (0..n).rev()
+        {
+            let (head, done) = x.split_at_mut((i + 1) * w);
+            let row = &mut head[i * w..];
+
+            for (k, done_row) in done.chunks_exact(w).enumerate()
+            {
+                let lki = l[(i + 1 + k) * n + i];
+                for (r, d) in row.iter_mut().zip(done_row.iter()) { *r = *r - lki * *d; }
+            }
+
+            let diag = l[i * n + i];
+            for r in row.iter_mut() { *r = *r / diag; }
+        }
+
+        Ok(())
+    }
+
+    // X----------X
+    // rank-1 modifications, O(n^2) instead of refactorising
+
+    // L * L^T <- L * L^T + x * x^T, x is (n x 1)
+    pub fn rank_one_update(&mut self, x: &Matrix<T>)
+    {
+        self.try_rank_one_update(x).unwrap_or_else(|e| panic!("{}", e))
+    }
+
+    pub fn try_rank_one_update(&mut self, x: &Matrix<T>) -> Result<(), MatrixError>
+    {
+        self.rank_one(x, T::one(), "rank_one_update")
+    }
+
+    // L * L^T <- L * L^T - x * x^T, fails (leaving the factor untouched) if the result is not SPD
+    pub fn rank_one_downdate(&mut self, x: &Matrix<T>)
+    {
+        self.try_rank_one_downdate(x).unwrap_or_else(|e| panic!("{}", e))
+    }
+
+    pub fn try_rank_one_downdate(&mut self, x: &Matrix<T>) -> Result<(), MatrixError>
+    {
+        self.rank_one(x, -T::one(), "rank_one_downdate")
+    }
+
+    // hyperbolic / plain Givens sweep down the diagonal, sign = +1 update, -1 downdate
+    fn rank_one(&mut self, x: &Matrix<T>, sign: T, op: &'static str) -> Result<(), MatrixError>
+    {
+        let n = self.size();
+
+        if x.shape() != (n, 1)
+        {
+            return Err(MatrixError::ShapeMismatch { op, lhs: (n, 1), rhs: x.shape() });
+        }
+
+        let mut l = self.l.clone();
+        let mut w = x.as_slice().to_vec();
+        let a = l.as_mut_slice();
+
+        for k in 0..n
+        {
+            let lkk = a[k * n + k];
+            let r2 = lkk * lkk + sign * w[k] * w[k];
+
+            if r2.is_nan() || r2 <= T::zero() { return Err(MatrixError::NotPositiveDefinite { op }); }
+
+            let r = r2.sqrt();
+            let c = r / lkk;
+            let s = w[k] / lkk;
+            a[k * n + k] = r;
+
+            for i in (k + 1)..n
+            {
+                let lik = (a[i * n + k] + sign * s * w[i]) / c;
+                a[i * n + k] = lik;
+                w[i] = c * w[i] - s * lik;
+            }
+        }
+
+        self.l = l;
+        Ok(())
+    }
+}
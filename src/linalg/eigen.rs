@@ -0,0 +1,665 @@
+use num_complex::Complex64;
+use crate::core::{error::MatrixError, matrix::Matrix};
+
+//----------General Eigendecomposition (Hessenberg + Francis double-shift QR)----------//
+
+// total QR sweeps allowed are this times max(n, 10), same budget as LAPACK's dlahqr
+const MAX_ITER_FACTOR: usize = 30;
+
+// A = Q * H * Q^T, H upper Hessenberg (zero below the first subdiagonal), Q orthogonal
+#[derive(Debug, Clone, PartialEq)]
+pub struct Hessenberg
+{
+    h: Matrix<f64>,
+    q: Matrix<f64>,
+}
+
+// real Schur form A = Z * T * Z^T, T quasi upper triangular with a 2x2 diagonal block
+// for every complex conjugate pair, Z orthogonal
+#[derive(Debug, Clone, PartialEq)]
+pub struct Schur
+{
+    t: Matrix<f64>,
+    z: Matrix<f64>,
+    values: Vec<Complex64>,
+}
+
+// A * V = V * diag(values), columns of V are unit 2-norm eigenvectors
+// eigenvalues come in the order they appear on the diagonal of T, conjugate pairs adjacent
+// with the positive imaginary part first
+#[derive(Debug, Clone, PartialEq)]
+pub struct Eigen
+{
+    values: Vec<Complex64>,
+    vectors: Matrix<Complex64>,
+}
+
+impl Matrix<f64>
+{
+    pub fn hessenberg(&self) -> Hessenberg
+    {
+        self.try_hessenberg().unwrap_or_else(|e| panic!("{}", e))
+    }
+
+    pub fn try_hessenberg(&self) -> Result<Hessenberg, MatrixError>
+    {
+        self.check_square("hessenberg")?;
+
+        let n = self.rows();
+        let (h, q) = reduce_hessenberg(self.as_slice(), n);
+
+        Ok(Hessenberg { h: Matrix::new(n, n, h)?, q: Matrix::new(n, n, q)? })
+    }
+
+    pub fn schur(&self) -> Schur
+    {
+        self.try_schur().unwrap_or_else(|e| panic!("{}", e))
+    }
+
+    pub fn try_schur(&self) -> Result<Schur, MatrixError>
+    {
+        const OP: &str = "schur";
+
+        self.check_square(OP)?;
+
+        let n = self.rows();
+        let (mut h, mut v) = reduce_hessenberg(self.as_slice(), n);
+        let (d, e) = francis_qr(&mut h, &mut v, n, OP)?;
+        let t = quasi_triangular(&h, &e, n);
+
+        Ok(Schur { t: Matrix::new(n, n, t)?, z: Matrix::new(n, n, v)?, values: complex_values(&d, &e) })
+    }
+
+    // values only, skips the eigenvector back substitution
+    pub fn eigenvalues(&self) -> Vec<Complex64>
+    {
+        self.try_eigenvalues().unwrap_or_else(|e| panic!("{}", e))
+    }
+
+    pub fn try_eigenvalues(&self) -> Result<Vec<Complex64>, MatrixError>
+    {
+        const OP: &str = "eigenvalues";
+
+        self.check_square(OP)?;
+
+        let n = self.rows();
+        let (mut h, mut v) = reduce_hessenberg(self.as_slice(), n);
+        let (d, e) = francis_qr(&mut h, &mut v, n, OP)?;
+
+        Ok(complex_values(&d, &e))
+    }
+
+    pub fn eigen(&self) -> Eigen
+    {
+        self.try_eigen().unwrap_or_else(|e| panic!("{}", e))
+    }
+
+    pub fn try_eigen(&self) -> Result<Eigen, MatrixError>
+    {
+        const OP: &str = "eigen";
+
+        self.check_square(OP)?;
+
+        let n = self.rows();
+        let (mut h, mut v) = reduce_hessenberg(self.as_slice(), n);
+        let (d, e) = francis_qr(&mut h, &mut v, n, OP)?;
+        schur_vectors(&mut h, &mut v, &d, &e, n);
+
+        // column pairs (u, w) of a complex pair hold u + iw and its conjugate u - iw
+        let mut data = vec![Complex64::new(0.0, 0.0); n * n];
+        let mut j = 0;
+        while j < n
+        {
+            if e[j] > 0.0
+            {
+                for i in 0..n
+                {
+                    let (u, w) = (v[i * n + j], v[i * n + j + 1]);
+                    data[i * n + j] = Complex64::new(u, w);
+                    data[i * n + j + 1] = Complex64::new(u, -w);
+                }
+                j += 2;
+            }
+            else
+            {
+                for i in 0..n { data[i * n + j] = Complex64::new(v[i * n + j], 0.0); }
+                j += 1;
+            }
+        }
+
+        // unit 2-norm columns
+        for j in 0..n
+        {
+            let norm = (0..n).fold(0.0_f64, |acc, i| acc.hypot(data[i * n + j].norm()));
+            if norm != 0.0
+            {
+                for i in 0..n { data[i * n + j] /= norm; }
+            }
+        }
+
+        Ok(Eigen { values: complex_values(&d, &e), vectors: Matrix::new(n, n, data)? })
+    }
+}
+
+impl Hessenberg
+{
+    pub fn h(&self) -> &Matrix<f64> { &self.h }
+
+    pub fn q(&self) -> &Matrix<f64> { &self.q }
+}
+
+impl Schur
+{
+    pub fn t(&self) -> &Matrix<f64> { &self.t }
+
+    pub fn z(&self) -> &Matrix<f64> { &self.z }
+
+    // read off the diagonal blocks of T
+    pub fn eigenvalues(&self) -> &[Complex64] { &self.values }
+}
+
+impl Eigen
+{
+    pub fn values(&self) -> &[Complex64] { &self.values }
+
+    pub fn vectors(&self) -> &Matrix<Complex64> { &self.vectors }
+
+    // largest |lambda|, governs the growth of x_{k+1} = A * x_k
+    pub fn spectral_radius(&self) -> f64
+    {
+        self.values.iter().fold(0.0, |acc, x| acc.max(x.norm()))
+    }
+}
+
+// X----------X
+// kernels
+
+fn complex_values(d: &[f64], e: &[f64]) -> Vec<Complex64>
+{
+    d.iter().zip(e.iter()).map(|(&re, &im)| Complex64::new(re, im)).collect()
+}
+
+// Householder reduction to upper Hessenberg form (EISPACK orthes + ortran), returns (H, Q)
+fn reduce_hessenberg(a: &[f64], n: usize) -> (Vec<f64>, Vec<f64>)
+{
+    let mut h = a.to_vec();
+    let mut ort = vec![0.0; n];
+
+    for m in 1..n.saturating_sub(1)
+    {
+        let scale = (m..n).fold(0.0, |acc, i| acc + h[i * n + m - 1].abs());
+        if scale == 0.0 { continue; }
+
+        // reflector from the scaled column below the subdiagonal
+        let mut hh = 0.0;
+        for i in (m..n).rev()
+        {
+            ort[i] = h[i * n + m - 1] / scale;
+            hh += ort[i] * ort[i];
+        }
+
+        let g = if ort[m] > 0.0 { -hh.sqrt() } else { hh.sqrt() };
+        hh -= ort[m] * g;
+        ort[m] -= g;
+
+        // H <- (I - u u^T / hh) * H * (I - u u^T / hh)
+        for j in m..n
+        {
+            let f = (m..n).rev().fold(0.0, |acc, i| acc + ort[i] * h[i * n + j]) / hh;
+            for i in m..n { h[i * n + j] -= f * ort[i]; }
+        }
+
+        for i in 0..n
+        {
+            let f = (m..n).rev().fold(0.0, |acc, j| acc + ort[j] * h[i * n + j]) / hh;
+            for j in m..n { h[i * n + j] -= f * ort[j]; }
+        }
+
+        ort[m] *= scale;
+        h[m * n + m - 1] = scale * g;
+    }
+
+    // accumulate Q from the stored reflectors
+    let mut q = vec![0.0; n * n];
+    for i in 0..n { q[i * n + i] = 1.0; }
+
+    for m in (1..n.saturating_sub(1)).rev()
+    {
+        if h[m * n + m - 1] == 0.0 { continue; }
+
+        for i in (m + 1)..n { ort[i] = h[i * n + m - 1]; }
+
+        for j in m..n
+        {
+            let g = (m..n).fold(0.0, |acc, i| acc + ort[i] * q[i * n + j]);
+
+            // double division avoids underflow
+            let g = (g / ort[m]) / h[m * n + m - 1];
+            for i in m..n { q[i * n + j] += g * ort[i]; }
+        }
+    }
+
+    // the reflectors were left below the subdiagonal
+    for i in 2..n
+    {
+        for j in 0..(i - 1) { h[i * n + j] = 0.0; }
+    }
+
+    (h, q)
+}
+
+// Francis double-shift QR on the Hessenberg <h> (EISPACK hqr2, iteration phase), rotations
+// accumulate into <v>; returns the real and imaginary parts of the eigenvalues
+// on exit h is in real Schur form up to negligible subdiagonal entries
+fn francis_qr(h: &mut [f64], v: &mut [f64], nn: usize, op: &'static str) -> Result<(Vec<f64>, Vec<f64>), MatrixError>
+{
+    let eps = f64::EPSILON;
+    let max_iter = MAX_ITER_FACTOR * nn.max(10);
+
+    let mut d = vec![0.0; nn];
+    let mut e = vec![0.0; nn];
+
+    let norm = (0..nn).fold(0.0, |acc, i| acc + h[i * nn + i.saturating_sub(1)..(i + 1) * nn].iter().map(|x| x.abs()).sum::<f64>());
+
+    let mut exshift = 0.0;
+    let (mut iter, mut total) = (0, 0);
+    let mut n = nn as isize - 1;
+
+    while n >= 0
+    {
+        let nu = n as usize;
+        let at = |h: &[f64], i: usize, j: usize| h[i * nn + j];
+
+        // look for a single small subdiagonal element
+        let mut l = nu;
+        while l > 0
+        {
+            let mut s = at(h, l - 1, l - 1).abs() + at(h, l, l).abs();
+            if s == 0.0 { s = norm; }
+            if at(h, l, l - 1).abs() <= eps * s { break; }
+            l -= 1;
+        }
+
+        if l == nu
+        {
+            // one root found
+            h[nu * nn + nu] += exshift;
+            d[nu] = h[nu * nn + nu];
+            e[nu] = 0.0;
+            n -= 1;
+            iter = 0;
+        }
+        else if l + 1 == nu
+        {
+            // two roots found
+            let w = at(h, nu, nu - 1) * at(h, nu - 1, nu);
+            let p = (at(h, nu - 1, nu - 1) - at(h, nu, nu)) / 2.0;
+            let q = p * p + w;
+            let mut z = q.abs().sqrt();
+            h[nu * nn + nu] += exshift;
+            h[(nu - 1) * nn + nu - 1] += exshift;
+            let x = at(h, nu, nu);
+
+            if q >= 0.0
+            {
+                // real pair, rotate the block to upper triangular
+                z = if p >= 0.0 { p + z } else { p - z };
+                d[nu - 1] = x + z;
+                d[nu] = if z != 0.0 { x - w / z } else { d[nu - 1] };
+                e[nu - 1] = 0.0;
+                e[nu] = 0.0;
+
+                let x = at(h, nu, nu - 1);
+                let s = x.abs() + z.abs();
+                let (p, q) = (x / s, z / s);
+                let r = p.hypot(q);
+                let (p, q) = (p / r, q / r);
+
+                for j in (nu - 1)..nn
+                {
+                    let z = at(h, nu - 1, j);
+                    h[(nu - 1) * nn + j] = q * z + p * at(h, nu, j);
+                    h[nu * nn + j] = q * at(h, nu, j) - p * z;
+                }
+
+                for i in 0..=nu
+                {
+                    let z = at(h, i, nu - 1);
+                    h[i * nn + nu - 1] = q * z + p * at(h, i, nu);
+                    h[i * nn + nu] = q * at(h, i, nu) - p * z;
+                }
+
+                for i in 0..nn
+                {
+                    let z = v[i * nn + nu - 1];
+                    v[i * nn + nu - 1] = q * z + p * v[i * nn + nu];
+                    v[i * nn + nu] = q * v[i * nn + nu] - p * z;
+                }
+
+                h[nu * nn + nu - 1] = 0.0;
+            }
+            else
+            {
+                // complex pair
+                d[nu - 1] = x + p;
+                d[nu] = x + p;
+                e[nu - 1] = z;
+                e[nu] = -z;
+            }
+
+            n -= 2;
+            iter = 0;
+        }
+        else
+        {
+            total += 1;
+            if total > max_iter { return Err(MatrixError::NotConverged { op, iterations: max_iter }); }
+
+            // shift from the trailing 2x2 block
+            let mut x = at(h, nu, nu);
+            let mut y = at(h, nu - 1, nu - 1);
+            let mut w = at(h, nu, nu - 1) * at(h, nu - 1, nu);
+
+            // Wilkinson's exceptional shift
+            if iter == 10
+            {
+                exshift += x;
+                for i in 0..=nu { h[i * nn + i] -= x; }
+                let s = at(h, nu, nu - 1).abs() + at(h, nu - 1, nu - 2).abs();
+                x = 0.75 * s;
+                y = x;
+                w = -0.4375 * s * s;
+            }
+
+            // second exceptional shift, from MATLAB
+            if iter == 30
+            {
+                let s = (y - x) / 2.0;
+                let s = s * s + w;
+                if s > 0.0
+                {
+                    let s = if y < x { -s.sqrt() } else { s.sqrt() };
+                    let s = x - w / ((y - x) / 2.0 + s);
+                    for i in 0..=nu { h[i * nn + i] -= s; }
+                    exshift += s;
+                    x = 0.964;
+                    y = x;
+                    w = x;
+                }
+            }
+
+            iter += 1;
+
+            // look for two consecutive small subdiagonal elements
+            let (mut p, mut q, mut r);
+            let mut m = nu - 2;
+            loop
+            {
+                let z = at(h, m, m);
+                let (rr, ss) = (x - z, y - z);
+                p = (rr * ss - w) / at(h, m + 1, m) + at(h, m, m + 1);
+                q = at(h, m + 1, m + 1) - z - rr - ss;
+                r = at(h, m + 2, m + 1);
+                let s = p.abs() + q.abs() + r.abs();
+                p /= s;
+                q /= s;
+                r /= s;
+
+                if m == l { break; }
+
+                let lhs = at(h, m, m - 1).abs() * (q.abs() + r.abs());
+                let rhs = eps * (p.abs() * (at(h, m - 1, m - 1).abs() + z.abs() + at(h, m + 1, m + 1).abs()));
+                if lhs < rhs { break; }
+
+                m -= 1;
+            }
+
+            for i in (m + 2)..=nu
+            {
+                h[i * nn + i - 2] = 0.0;
+                if i > m + 2 { h[i * nn + i - 3] = 0.0; }
+            }
+
+            // double QR step on rows l..=n and columns m..=n
+            for k in m..nu
+            {
+                let notlast = k != nu - 1;
+                let mut x = 0.0;
+
+                if k != m
+                {
+                    p = at(h, k, k - 1);
+                    q = at(h, k + 1, k - 1);
+                    r = if notlast { at(h, k + 2, k - 1) } else { 0.0 };
+                    x = p.abs() + q.abs() + r.abs();
+                    if x == 0.0 { continue; }
+                    p /= x;
+                    q /= x;
+                    r /= x;
+                }
+
+                let s = if p < 0.0 { -(p * p + q * q + r * r).sqrt() } else { (p * p + q * q + r * r).sqrt() };
+                if s == 0.0 { continue; }
+
+                if k != m { h[k * nn + k - 1] = -s * x; }
+                else if l != m { h[k * nn + k - 1] = -at(h, k, k - 1); }
+
+                p += s;
+                let (x, y, z) = (p / s, q / s, r / s);
+                q /= p;
+                r /= p;
+
+                // row modification
+                for j in k..nn
+                {
+                    let mut p = at(h, k, j) + q * at(h, k + 1, j);
+                    if notlast
+                    {
+                        p += r * at(h, k + 2, j);
+                        h[(k + 2) * nn + j] -= p * z;
+                    }
+                    h[k * nn + j] -= p * x;
+                    h[(k + 1) * nn + j] -= p * y;
+                }
+
+                // column modification
+                for i in 0..=nu.min(k + 3)
+                {
+                    let mut p = x * at(h, i, k) + y * at(h, i, k + 1);
+                    if notlast
+                    {
+                        p += z * at(h, i, k + 2);
+                        h[i * nn + k + 2] -= p * r;
+                    }
+                    h[i * nn + k] -= p;
+                    h[i * nn + k + 1] -= p * q;
+                }
+
+                // accumulate transformations
+                for i in 0..nn
+                {
+                    let mut p = x * v[i * nn + k] + y * v[i * nn + k + 1];
+                    if notlast
+                    {
+                        p += z * v[i * nn + k + 2];
+                        v[i * nn + k + 2] -= p * r;
+                    }
+                    v[i * nn + k] -= p;
+                    v[i * nn + k + 1] -= p * q;
+                }
+            }
+        }
+    }
+
+    Ok((d, e))
+}
+
+// copy of <h> with everything below the subdiagonal, and every subdiagonal entry outside a
+// complex 2x2 block, set to zero
+fn quasi_triangular(h: &[f64], e: &[f64], n: usize) -> Vec<f64>
+{
+    let mut t = h.to_vec();
+
+    for i in 1..n
+    {
+        let in_block = e[i - 1] > 0.0;
+        for j in 0..i
+        {
+            if j + 1 < i || !in_block { t[i * n + j] = 0.0; }
+        }
+    }
+
+    t
+}
+
+// eigenvectors of the quasi triangular <h> by back substitution, then mapped back through <v>
+// (EISPACK hqr2, second phase); on exit the columns of v hold the real eigenvectors and, for a
+// complex pair at (j, j + 1), the real and imaginary parts of the vector for d[j] + i e[j]
+fn schur_vectors(h: &mut [f64], v: &mut [f64], d: &[f64], e: &[f64], nn: usize)
+{
+    let eps = f64::EPSILON;
+    let norm = (0..nn).fold(0.0, |acc, i| acc + h[i * nn + i.saturating_sub(1)..(i + 1) * nn].iter().map(|x| x.abs()).sum::<f64>());
+
+    if norm == 0.0 { return; }
+
+    let cdiv = |xr: f64, xi: f64, yr: f64, yi: f64| Complex64::new(xr, xi) / Complex64::new(yr, yi);
+
+    for n in (0..nn).rev()
+    {
+        let (p, q) = (d[n], e[n]);
+
+        if q == 0.0
+        {
+            // real vector
+            let mut l = n;
+            h[n * nn + n] = 1.0;
+            let (mut z, mut s) = (0.0, 0.0);
+
+            for i in (0..n).rev()
+            {
+                let w = h[i * nn + i] - p;
+                let r = (l..=n).fold(0.0, |acc, j| acc + h[i * nn + j] * h[j * nn + n]);
+
+                if e[i] < 0.0
+                {
+                    z = w;
+                    s = r;
+                    continue;
+                }
+
+                l = i;
+                if e[i] == 0.0
+                {
+                    h[i * nn + n] = if w != 0.0 { -r / w } else { -r / (eps * norm) };
+                }
+                else
+                {
+                    // real 2x2 system against the complex block above
+                    let (x, y) = (h[i * nn + i + 1], h[(i + 1) * nn + i]);
+                    let q = (d[i] - p) * (d[i] - p) + e[i] * e[i];
+                    let t = (x * s - z * r) / q;
+                    h[i * nn + n] = t;
+                    h[(i + 1) * nn + n] = if x.abs() > z.abs() { (-r - w * t) / x } else { (-s - y * t) / z };
+                }
+
+                // overflow control
+                let t = h[i * nn + n].abs();
+                if (eps * t) * t > 1.0
+                {
+                    for j in i..=n { h[j * nn + n] /= t; }
+                }
+            }
+        }
+        else if q < 0.0
+        {
+            // complex vector, stored in columns n - 1 (real) and n (imaginary)
+            let mut l = n - 1;
+
+            if h[n * nn + n - 1].abs() > h[(n - 1) * nn + n].abs()
+            {
+                h[(n - 1) * nn + n - 1] = q / h[n * nn + n - 1];
+                h[(n - 1) * nn + n] = -(h[n * nn + n] - p) / h[n * nn + n - 1];
+            }
+            else
+            {
+                let c = cdiv(0.0, -h[(n - 1) * nn + n], h[(n - 1) * nn + n - 1] - p, q);
+                h[(n - 1) * nn + n - 1] = c.re;
+                h[(n - 1) * nn + n] = c.im;
+            }
+
+            h[n * nn + n - 1] = 0.0;
+            h[n * nn + n] = 1.0;
+            let (mut z, mut r, mut s) = (0.0, 0.0, 0.0);
+
+            for i in (0..n.saturating_sub(1)).rev()
+            {
+                let ra = (l..=n).fold(0.0, |acc, j| acc + h[i * nn + j] * h[j * nn + n - 1]);
+                let sa = (l..=n).fold(0.0, |acc, j| acc + h[i * nn + j] * h[j * nn + n]);
+                let w = h[i * nn + i] - p;
+
+                if e[i] < 0.0
+                {
+                    z = w;
+                    r = ra;
+                    s = sa;
+                    continue;
+                }
+
+                l = i;
+                if e[i] == 0.0
+                {
+                    let c = cdiv(-ra, -sa, w, q);
+                    h[i * nn + n - 1] = c.re;
+                    h[i * nn + n] = c.im;
+                }
+                else
+                {
+                    // complex 2x2 system against the complex block above
+                    let (x, y) = (h[i * nn + i + 1], h[(i + 1) * nn + i]);
+                    let mut vr = (d[i] - p) * (d[i] - p) + e[i] * e[i] - q * q;
+                    let vi = (d[i] - p) * 2.0 * q;
+                    if vr == 0.0 && vi == 0.0
+                    {
+                        vr = eps * norm * (w.abs() + q.abs() + x.abs() + y.abs() + z.abs());
+                    }
+
+                    let c = cdiv(x * r - z * ra + q * sa, x * s - z * sa - q * ra, vr, vi);
+                    h[i * nn + n - 1] = c.re;
+                    h[i * nn + n] = c.im;
+
+                    if x.abs() > z.abs() + q.abs()
+                    {
+                        h[(i + 1) * nn + n - 1] = (-ra - w * h[i * nn + n - 1] + q * h[i * nn + n]) / x;
+                        h[(i + 1) * nn + n] = (-sa - w * h[i * nn + n] - q * h[i * nn + n - 1]) / x;
+                    }
+                    else
+                    {
+                        let c = cdiv(-r - y * h[i * nn + n - 1], -s - y * h[i * nn + n], z, q);
+                        h[(i + 1) * nn + n - 1] = c.re;
+                        h[(i + 1) * nn + n] = c.im;
+                    }
+                }
+
+                // overflow control
+                let t = h[i * nn + n - 1].abs().max(h[i * nn + n].abs());
+                if (eps * t) * t > 1.0
+                {
+                    for j in i..=n
+                    {
+                        h[j * nn + n - 1] /= t;
+                        h[j * nn + n] /= t;
+                    }
+                }
+            }
+        }
+    }
+
+    // back transformation, V <- V * (upper triangle of h)
+    for j in (0..nn).rev()
+    {
+        for i in 0..nn
+        {
+            v[i * nn + j] = (0..=j).fold(0.0, |acc, k| acc + v[i * nn + k] * h[k * nn + j]);
+        }
+    }
+}
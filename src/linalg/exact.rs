@@ -0,0 +1,104 @@
+use num_traits::{PrimInt, Signed};
+use crate::core::{error::MatrixError, matrix::Matrix};
+
+//----------Exact Integer Elimination (Bareiss)----------//
+
+// result of a fraction-free elimination pass
+struct Echelon<T>
+{
+    data: Vec<T>,
+    rank: usize,
+
+    // true for an odd number of row swaps
+    negate: bool,
+}
+
+impl<T> Matrix<T>
+where T: PrimInt + Signed
+{
+    // exact determinant, panics if an intermediate value overflows T
+    pub fn det_exact(&self) -> T
+    {
+        self.try_det_exact().unwrap_or_else(|e| panic!("{}", e))
+    }
+
+    // overflow-checked determinant, every intermediate is a minor of A so
+    // the result is exact whenever it returns Ok
+    pub fn try_det_exact(&self) -> Result<T, MatrixError>
+    {
+        self.check_square("det_exact")?;
+
+        let n = self.rows();
+        let echelon = self.bareiss("det_exact")?;
+
+        if echelon.rank < n { return Ok(T::zero()); }
+
+        // the last pivot is the full determinant (up to row swaps)
+        let det = echelon.data[n * n - 1];
+
+        if echelon.negate { T::zero().checked_sub(&det).ok_or(MatrixError::Overflow { op: "det_exact" }) }
+        else { Ok(det) }
+    }
+
+    // exact rank, any shape
+    pub fn rank_exact(&self) -> usize
+    {
+        self.try_rank_exact().unwrap_or_else(|e| panic!("{}", e))
+    }
+
+    pub fn try_rank_exact(&self) -> Result<usize, MatrixError>
+    {
+        Ok(self.bareiss("rank_exact")?.rank)
+    }
+
+    // fraction-free row echelon form:
+    //   a[i][j] = (a[r][c] * a[i][j] - a[i][c] * a[r][j]) / prev_pivot
+    // the division is always exact, so integers stay integers
+    fn bareiss(&self, op: &'static str) -> Result<Echelon<T>, MatrixError>
+    {
+        let (m, n) = self.shape();
+        let mut a = self.as_slice().to_vec();
+        let mut prev = T::one();
+        let mut r = 0;
+        let mut negate = false;
+
+        let overflow = || MatrixError::Overflow { op };
+
+        for c in 0..n
+        {
+            if r == m { break; }
+
+            // any nonzero entry works as a pivot, no magnitude games needed
+            let Some(p) = (r..m).find(|&i| a[i * n + c] != T::zero()) else { continue; };
+
+            if p != r
+            {
+                for j in 0..n { a.swap(r * n + j, p * n + j); }
+                negate = !negate;
+            }
+
+            let pivot = a[r * n + c];
+
+            for i in (r + 1)..m
+            {
+                let lead = a[i * n + c];
+
+                for j in (c + 1)..n
+                {
+                    let lhs = pivot.checked_mul(&a[i * n + j]).ok_or_else(overflow)?;
+                    let rhs = lead.checked_mul(&a[r * n + j]).ok_or_else(overflow)?;
+                    let num = lhs.checked_sub(&rhs).ok_or_else(overflow)?;
+
+                    a[i * n + j] = num.checked_div(&prev).ok_or_else(overflow)?;
+                }
+
+                a[i * n + c] = T::zero();
+            }
+
+            prev = pivot;
+            r += 1;
+        }
+
+        Ok(Echelon { data: a, rank: r, negate })
+    }
+}
@@ -0,0 +1,351 @@
+use num_traits::Float;
+use crate::core::{error::MatrixError, matrix::Matrix};
+
+//----------Pivoted LDL^T (Bunch-Kaufman)----------//
+
+// P * A * P^T = L * D * L^T for symmetric (possibly indefinite) A
+// L is unit lower triangular, D is block diagonal with 1x1 and 2x2 blocks
+#[derive(Debug, Clone, PartialEq)]
+pub struct LDLT<T>
+where T: Float
+{
+    l: Matrix<T>,
+
+    // diagonal of D and its first subdiagonal (nonzero only inside 2x2 blocks)
+    diag: Vec<T>,
+    sub: Vec<T>,
+
+    // (start, size) of every diagonal block
+    blocks: Vec<(usize, usize)>,
+
+    // row i of P * A * P^T comes from row perm[i] of A
+    perm: Vec<usize>,
+
+    // max|a_ij| of the input, the reference for the relative singularity test
+    scale: T,
+}
+
+impl<T> Matrix<T>
+where T: Float
+{
+    pub fn ldlt(&self) -> LDLT<T>
+    {
+        self.try_ldlt().unwrap_or_else(|e| panic!("{}", e))
+    }
+
+    // never fails on singular input, a (numerically) zero pivot is stored in D and reported by solve()
+    pub fn try_ldlt(&self) -> Result<LDLT<T>, MatrixError>
+    {
+        self.check_symmetric("ldlt")?;
+
+        let n = self.rows();
+
+        // Bunch-Kaufman growth constant, (1 + sqrt(17)) / 8
+        let alpha = (T::one() + T::from(17).unwrap().sqrt()) / T::from(8).unwrap();
+
+        // working copy, only the trailing block s[k.., k..] is live
+        let mut s = self.as_slice().to_vec();
+        let mut l = Matrix::identity(n)?;
+        let mut diag = vec![T::zero(); n];
+        let mut sub = vec![T::zero(); n.saturating_sub(1)];
+        let mut blocks = Vec::new();
+        let mut perm: Vec<usize> = (0..n).collect();
+        let scale = self.as_slice().iter().fold(T::zero(), |acc, x| acc.max(x.abs()));
+
+        let at = |s: &[T], i: usize, j: usize| s[i * n + j];
+
+        let mut k = 0;
+        while k < n
+        {
+            let akk = at(&s, k, k).abs();
+            let (imax, colmax) = ((k + 1)..n).fold((k, T::zero()), |best, i|
+                {
+                    let v = at(&s, i, k).abs();
+                    if v > best.1 { (i, v) } else { best }
+                });
+
+            // choose between a 1x1 pivot at k, a 1x1 pivot at imax, or a 2x2 pivot on (k, imax)
+            let (kp, step) = if akk.max(colmax) == T::zero() || akk >= alpha * colmax
+            {
+                (k, 1)
+            }
+            else
+            {
+                let rowmax = (k..n).filter(|&j| j != imax).fold(T::zero(), |acc, j| acc.max(at(&s, imax, j).abs()));
+
+                if akk * rowmax >= alpha * colmax * colmax { (k, 1) }
+                else if at(&s, imax, imax).abs() >= alpha * rowmax { (imax, 1) }
+                else { (imax, 2) }
+            };
+
+            // symmetric swap so the pivot lands at kk
+            let kk = k + step - 1;
+            if kp != kk
+            {
+                swap_sym(&mut s, n, kk, kp);
+                perm.swap(kk, kp);
+
+                // finished columns of L follow their rows
+                let lm = l.as_mut_slice();
+                for j in 0..k { lm.swap(kk * n + j, kp * n + j); }
+            }
+
+            if step == 1
+            {
+                let d = at(&s, k, k);
+                diag[k] = d;
+                blocks.push((k, 1));
+
+                if d != T::zero()
+                {
+                    let col: Vec<T> = ((k + 1)..n).map(|i| at(&s, i, k)).collect();
+
+                    for (a, &ci) in col.iter().enumerate()
+                    {
+                        let i = k + 1 + a;
+                        let li = ci / d;
+                        l.as_mut_slice()[i * n + k] = li;
+
+                        // trailing update, both triangles kept in sync
+                        for (b, &cj) in col.iter().enumerate()
+                        {
+                            s[i * n + k + 1 + b] = s[i * n + k + 1 + b] - li * cj;
+                        }
+                    }
+                }
+            }
+            else
+            {
+                let (d11, d21, d22) = (at(&s, k, k), at(&s, k + 1, k), at(&s, k + 1, k + 1));
+                diag[k] = d11;
+                diag[k + 1] = d22;
+                sub[k] = d21;
+                blocks.push((k, 2));
+
+                // D^-1 of the 2x2 block, det < 0 by construction so it is invertible
+                let det = d11 * d22 - d21 * d21;
+                let (i11, i21, i22) = (d22 / det, -d21 / det, d11 / det);
+
+                let c0: Vec<T> = ((k + 2)..n).map(|i| at(&s, i, k)).collect();
+                let c1: Vec<T> = ((k + 2)..n).map(|i| at(&s, i, k + 1)).collect();
+
+                for a in 0..c0.len()
+                {
+                    let i = k + 2 + a;
+                    let li0 = c0[a] * i11 + c1[a] * i21;
+                    let li1 = c0[a] * i21 + c1[a] * i22;
+                    l.as_mut_slice()[i * n + k] = li0;
+                    l.as_mut_slice()[i * n + k + 1] = li1;
+
+                    for b in 0..c0.len()
+                    {
+                        let j = k + 2 + b;
+                        s[i * n + j] = s[i * n + j] - (li0 * c0[b] + li1 * c1[b]);
+                    }
+                }
+            }
+
+            k += step;
+        }
+
+        Ok(LDLT { l, diag, sub, blocks, perm, scale })
+    }
+}
+
+impl<T> LDLT<T>
+where T: Float
+{
+    // X----------X
+    // accessors
+
+    pub fn size(&self) -> usize { self.l.rows() }
+
+    pub fn l(&self) -> &Matrix<T> { &self.l }
+
+    // block diagonal D as a dense matrix
+    pub fn d(&self) -> Matrix<T>
+    {
+        let n = self.size();
+        let mut d = Matrix::zeroes(n, n).unwrap();
+        let out = d.as_mut_slice();
+
+        for i in 0..n { out[i * n + i] = self.diag[i]; }
+
+        for &(start, size) in &self.blocks
+        {
+            if size == 2
+            {
+                out[(start + 1) * n + start] = self.sub[start];
+                out[start * n + start + 1] = self.sub[start];
+            }
+        }
+
+        d
+    }
+
+    // sizes of the diagonal blocks in order, each 1 or 2
+    pub fn block_sizes(&self) -> Vec<usize>
+    {
+        self.blocks.iter().map(|&(_, size)| size).collect()
+    }
+
+    pub fn permutation(&self) -> &[usize] { &self.perm }
+
+    // permutation matrix P, with P * A * P^T = L * D * L^T
+    pub fn p(&self) -> Matrix<T>
+    {
+        let n = self.size();
+        let mut p = Matrix::zeroes(n, n).unwrap();
+
+        for (i, &j) in self.perm.iter().enumerate()
+        {
+            p.as_mut_slice()[i * n + j] = T::one();
+        }
+
+        p
+    }
+
+    // X----------X
+    // derived quantities
+
+    pub fn det(&self) -> T
+    {
+        self.blocks.iter().fold(T::one(), |acc, &(k, size)| acc * self.block_det(k, size))
+    }
+
+    // (positive, negative, zero) eigenvalue counts of A, by Sylvester's law of inertia
+    // every 2x2 block has det < 0, so it holds one eigenvalue of each sign
+    // 1x1 pivots within the singularity tolerance count as zero
+    pub fn inertia(&self) -> (usize, usize, usize)
+    {
+        let mut counts = (0, 0, 0);
+
+        for &(k, size) in &self.blocks
+        {
+            if size == 2
+            {
+                counts.0 += 1;
+                counts.1 += 1;
+            }
+            else if self.is_zero_block(k, size) { counts.2 += 1; }
+            else if self.diag[k] > T::zero() { counts.0 += 1; }
+            else { counts.1 += 1; }
+        }
+
+        counts
+    }
+
+    // relative test, any 1x1 pivot with |d| <= n * eps * max|a_ij| or 2x2 block with
+    // |det| <= n * eps * max|a_ij|^2, the same default Matrix::try_solve uses for LU
+    pub fn is_singular(&self) -> bool
+    {
+        self.blocks.iter().any(|&(k, size)| self.is_zero_block(k, size))
+    }
+
+    pub fn solve(&self, b: &Matrix<T>) -> Matrix<T>
+    {
+        self.try_solve(b).unwrap_or_else(|e| panic!("{}", e))
+    }
+
+    // x = P^T * L^-T * D^-1 * L^-1 * P * b
+    pub fn try_solve(&self, b: &Matrix<T>) -> Result<Matrix<T>, MatrixError>
+    {
+        let n = self.size();
+
+        if b.rows() != n
+        {
+            return Err(MatrixError::InnerDimMismatch { op: "solve", lhs: (n, n), rhs: b.shape() });
+        }
+
+        if self.is_singular()
+        {
+            return Err(MatrixError::Singular { op: "solve" });
+        }
+
+        let w = b.cols();
+        let l = self.l.as_slice();
+        let src = b.as_slice();
+
+        // y = P * b
+        let mut y: Vec<T> = self.perm.iter().flat_map(|&p| src[p * w..(p + 1) * w].iter().copied()).collect();
+
+        // L * z = y, unit diagonal
+        for i in 1..n
+        {
+            let (done, rest) = y.split_at_mut(i * w);
+            let row = &mut rest[..w];
+
+            for (k, done_row) in done.chunks_exact(w).enumerate()
+            {
+                let lik = l[i * n + k];
+                if lik == T::zero() { continue; }
+                for (r, d) in row.iter_mut().zip(done_row.iter()) { *r = *r - lik * *d; }
+            }
+        }
+
+        // D blocks
+        for &(k, size) in &self.blocks
+        {
+            if size == 1
+            {
+                for r in y[k * w..(k + 1) * w].iter_mut() { *r = *r / self.diag[k]; }
+            }
+            else
+            {
+                let (d11, d21, d22) = (self.diag[k], self.sub[k], self.diag[k + 1]);
+                let det = d11 * d22 - d21 * d21;
+
+                for c in 0..w
+                {
+                    let (y0, y1) = (y[k * w + c], y[(k + 1) * w + c]);
+                    y[k * w + c] = (d22 * y0 - d21 * y1) / det;
+                    y[(k + 1) * w + c] = (d11 * y1 - d21 * y0) / det;
+                }
+            }
+        }
+
+        // L^T * x = z, unit diagonal
+        for i in (0..n.saturating_sub(1)).rev()
+        {
+            let (head, done) = y.split_at_mut((i + 1) * w);
+            let row = &mut head[i * w..];
+
+            for (k, done_row) in done.chunks_exact(w).enumerate()
+            {
+                let lki = l[(i + 1 + k) * n + i];
+                if lki == T::zero() { continue; }
+                for (r, d) in row.iter_mut().zip(done_row.iter()) { *r = *r - lki * *d; }
+            }
+        }
+
+        // x = P^T * z
+        let mut x = vec![T::zero(); n * w];
+        for (i, &p) in self.perm.iter().enumerate()
+        {
+            x[p * w..(p + 1) * w].copy_from_slice(&y[i * w..(i + 1) * w]);
+        }
+
+        Matrix::new(n, w, x)
+    }
+
+    fn block_det(&self, k: usize, size: usize) -> T
+    {
+        if size == 1 { self.diag[k] }
+        else { self.diag[k] * self.diag[k + 1] - self.sub[k] * self.sub[k] }
+    }
+
+    // a 2x2 determinant carries two factors of the entry scale, so its tolerance does too
+    fn is_zero_block(&self, k: usize, size: usize) -> bool
+    {
+        let tol = T::from(self.size()).unwrap() * T::epsilon() * self.scale;
+        let tol = if size == 1 { tol } else { tol * self.scale };
+        self.block_det(k, size).abs() <= tol
+    }
+}
+
+// swaps rows and columns i, j of a dense symmetric (n x n) buffer
+fn swap_sym<T: Copy>(s: &mut [T], n: usize, i: usize, j: usize)
+{
+    for c in 0..n { s.swap(i * n + c, j * n + c); }
+    for r in 0..n { s.swap(r * n + i, r * n + j); }
+}
@@ -0,0 +1,308 @@
+use num_traits::Float;
+use crate::core::{error::MatrixError, matrix::Matrix};
+
+//----------LU Decomposition----------//
+
+// P * A = L * U with partial (row) pivoting
+// L (unit diagonal, strictly lower part) and U (upper part, with diagonal) are packed in one matrix
+#[derive(Debug, Clone, PartialEq)]
+pub struct LU<T>
+where T: Float
+{
+    lu: Matrix<T>,
+
+    // LAPACK-style pivots, row k was swapped with row pivots[k] at step k
+    pivots: Vec<usize>,
+
+    // +1 / -1, parity of the row swaps
+    sign: T,
+
+    // max|a_ij| of the input, the reference for the relative singularity test
+    scale: T,
+}
+
+impl<T> Matrix<T>
+where T: Float
+{
+    pub fn lu(&self) -> LU<T>
+    {
+        self.try_lu().unwrap_or_else(|e| panic!("{}", e))
+    }
+
+    // factorisation always succeeds for square input, an exactly zero pivot column is
+    // left in place so det() still reports 0; solve / inverse return Singular whenever
+    // is_singular() holds
+    pub fn try_lu(&self) -> Result<LU<T>, MatrixError>
+    {
+        self.check_square("lu")?;
+
+        let n = self.rows();
+        let mut lu = self.clone();
+        let mut pivots = Vec::with_capacity(n);
+        let mut sign = T::one();
+        let scale = self.as_slice().iter().fold(T::zero(), |acc, x| acc.max(x.abs()));
+        let a = lu.as_mut_slice();
+
+        for k in 0..n
+        {
+            // pick the largest magnitude in column k
+            let mut p = k;
+            for i in (k + 1)..n
+            {
+                if a[i * n + k].abs() > a[p * n + k].abs() { p = i; }
+            }
+
+            pivots.push(p);
+
+            if p != k
+            {
+                swap_rows(a, n, k, p);
+                sign = -sign;
+            }
+
+            let pivot = a[k * n + k];
+            if pivot == T::zero() { continue; }
+
+            // eliminate below the pivot, row k stays read-only
+            let (top, bottom) = a.split_at_mut((k + 1) * n);
+            let pivot_row = &top[k * n..];
+
+            for row in bottom.chunks_exact_mut(n)
+            {
+                let l = row[k] / pivot;
+                row[k] = l;
+
+                if l == T::zero() { continue; }
+
+                for (r, u) in row[(k + 1)..].iter_mut().zip(pivot_row[(k + 1)..].iter())
+                {
+                    *r = *r - l * *u;
+                }
+            }
+        }
+
+        Ok(LU { lu, pivots, sign, scale })
+    }
+}
+
+impl<T> LU<T>
+where T: Float
+{
+    // X----------X
+    // accessors
+
+    pub fn size(&self) -> usize { self.lu.rows() }
+
+    // packed factors, strict lower part is L (unit diagonal implied), upper part is U
+    pub fn packed(&self) -> &Matrix<T> { &self.lu }
+
+    pub fn pivots(&self) -> &[usize] { &self.pivots }
+
+    // +1 for an even number of row swaps, -1 for odd
+    pub fn sign(&self) -> T { self.sign }
+
+    // row i of P * A is row permutation()[i] of A
+    pub fn permutation(&self) -> Vec<usize>
+    {
+        let mut perm: Vec<usize> = (0..self.size()).collect();
+
+        for (k, &p) in self.pivots.iter().enumerate()
+        {
+            perm.swap(k, p);
+        }
+
+        perm
+    }
+
+    // permutation matrix P, with P * A = L * U
+    pub fn p(&self) -> Matrix<T>
+    {
+        let n = self.size();
+        let mut p = Matrix::zeroes(n, n).unwrap();
+
+        for (i, &j) in self.permutation().iter().enumerate()
+        {
+            p.as_mut_slice()[i * n + j] = T::one();
+        }
+
+        p
+    }
+
+    pub fn l(&self) -> Matrix<T>
+    {
+        let n = self.size();
+        let mut l = Matrix::identity(n).unwrap();
+
+        for i in 0..n
+        {
+            l.as_mut_slice()[i * n..i * n + i].copy_from_slice(&self.lu.as_slice()[i * n..i * n + i]);
+        }
+
+        l
+    }
+
+    pub fn u(&self) -> Matrix<T>
+    {
+        let n = self.size();
+        let mut u = Matrix::zeroes(n, n).unwrap();
+
+        for i in 0..n
+        {
+            u.as_mut_slice()[i * n + i..(i + 1) * n].copy_from_slice(&self.lu.as_slice()[i * n + i..(i + 1) * n]);
+        }
+
+        u
+    }
+
+    // X----------X
+    // derived quantities
+
+    // sign * prod(diag(U)), 0 for singular input
+    pub fn det(&self) -> T
+    {
+        let n = self.size();
+        (0..n).fold(self.sign, |acc, i| acc * self.lu.as_slice()[i * n + i])
+    }
+
+    // relative test, any pivot with |u_ii| <= n * eps * max|a_ij|, the same default
+    // Matrix::try_solve uses, so rounded-off zero pivots count as singular too
+    pub fn is_singular(&self) -> bool
+    {
+        let n = self.size();
+        self.is_singular_tol(T::from(n).unwrap() * T::epsilon() * self.scale)
+    }
+
+    // numerical test, any pivot with |u_ii| <= tol (absolute)
+    pub fn is_singular_tol(&self, tol: T) -> bool
+    {
+        let n = self.size();
+        (0..n).any(|i| self.lu.as_slice()[i * n + i].abs() <= tol)
+    }
+
+    // solves A * X = B for every column of B
+    pub fn solve(&self, b: &Matrix<T>) -> Matrix<T>
+    {
+        self.try_solve(b).unwrap_or_else(|e| panic!("{}", e))
+    }
+
+    pub fn try_solve(&self, b: &Matrix<T>) -> Result<Matrix<T>, MatrixError>
+    {
+        let mut x = b.clone();
+        self.try_solve_in_place(&mut x)?;
+        Ok(x)
+    }
+
+    // overwrites B with the solution X, no allocation
+    pub fn solve_in_place(&self, b: &mut Matrix<T>)
+    {
+        self.try_solve_in_place(b).unwrap_or_else(|e| panic!("{}", e))
+    }
+
+    pub fn try_solve_in_place(&self, b: &mut Matrix<T>) -> Result<(), MatrixError>
+    {
+        self.check_rhs(b, "solve")?;
+
+        if self.is_singular()
+        {
+            return Err(MatrixError::Singular { op: "solve" });
+        }
+
+        self.substitute(b);
+        Ok(())
+    }
+
+    pub fn inverse(&self) -> Matrix<T>
+    {
+        self.try_inverse().unwrap_or_else(|e| panic!("{}", e))
+    }
+
+    pub fn try_inverse(&self) -> Result<Matrix<T>, MatrixError>
+    {
+        if self.is_singular()
+        {
+            return Err(MatrixError::Singular { op: "inverse" });
+        }
+
+        let mut inv = Matrix::identity(self.size())?;
+        self.substitute(&mut inv);
+        Ok(inv)
+    }
+
+    // X----------X
+    // helpers
+
+    pub(crate) fn check_rhs(&self, b: &Matrix<T>, op: &'static str) -> Result<(), MatrixError>
+    {
+        if b.rows() != self.size()
+        {
+            return Err(MatrixError::InnerDimMismatch { op, lhs: self.lu.shape(), rhs: b.shape() });
+        }
+
+        Ok(())
+    }
+
+    // applies P, then L^-1, then U^-1 to the rows of b (all rhs columns at once)
+    // caller checks shape and singularity
+    pub(crate) fn substitute(&self, b: &mut Matrix<T>)
+    {
+        let n = self.size();
+        let m = b.cols();
+        let lu = self.lu.as_slice();
+        let x = b.as_mut_slice();
+
+        for (k, &p) in self.pivots.iter().enumerate()
+        {
+            if p != k { swap_rows(x, m, k, p); }
+        }
+
+        // forward, unit diagonal
+        for i in 1..n
+        {
+            let (done, rest) = x.split_at_mut(i * m);
+            let row = &mut rest[..m];
+
+            for (k, done_row) in done.chunks_exact(m).enumerate()
+            {
+                let l = lu[i * n + k];
+                if l == T::zero() { continue; }
+
+                for (r, d) in row.iter_mut().zip(done_row.iter())
+                {
+                    *r = *r - l * *d;
+                }
+            }
+        }
+
+        // backward
+        for i in (0..n).rev()
+        {
+            let (head, done) = x.split_at_mut((i + 1) * m);
+            let row = &mut head[i * m..];
+
+            for (k, done_row) in done.chunks_exact(m).enumerate()
+            {
+                let u = lu[i * n + i + 1 + k];
+                if u == T::zero() { continue; }
+
+                for (r, d) in row.iter_mut().zip(done_row.iter())
+                {
+                    *r = *r - u * *d;
+                }
+            }
+
+            let diag = lu[i * n + i];
+            for r in row.iter_mut()
+            {
+                *r = *r / diag;
+            }
+        }
+    }
+}
+
+// swaps rows i and j of a row-major buffer with <cols> columns
+fn swap_rows<T>(data: &mut [T], cols: usize, i: usize, j: usize)
+{
+    let (lo, hi) = if i < j { (i, j) } else { (j, i) };
+    let (top, bottom) = data.split_at_mut(hi * cols);
+    top[lo * cols..(lo + 1) * cols].swap_with_slice(&mut bottom[..cols]);
+}
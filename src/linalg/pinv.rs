@@ -0,0 +1,129 @@
+use num_traits::Float;
+use crate::core::{error::MatrixError, matrix::Matrix};
+use crate::linalg::svd::SVD;
+
+//----------Pseudo-inverse and Minimum-Norm Least Squares----------//
+
+// result of lstsq, mirrors numpy.linalg.lstsq
+#[derive(Debug, Clone, PartialEq)]
+pub struct LeastSquares<T>
+where T: Float
+{
+    solution: Matrix<T>,
+    residuals: Vec<T>,
+    rank: usize,
+    singular_values: Vec<T>,
+}
+
+impl<T> Matrix<T>
+where T: Float
+{
+    // Moore-Penrose pseudo-inverse (n x m), singular values <= rcond * sigma_max count as zero
+    pub fn pinv(&self, rcond: T) -> Matrix<T>
+    {
+        self.try_pinv(rcond).unwrap_or_else(|e| panic!("{}", e))
+    }
+
+    pub fn try_pinv(&self, rcond: T) -> Result<Matrix<T>, MatrixError>
+    {
+        let svd = self.try_svd_thin()?;
+        let rank = svd.rank_with_tol(rcond);
+        apply_pinv(&svd, rank, &Matrix::identity(self.rows())?)
+    }
+
+    // minimum-norm X minimising ||A * X - B|| column by column, works for any shape and rank
+    pub fn lstsq(&self, b: &Matrix<T>) -> LeastSquares<T>
+    {
+        self.try_lstsq(b).unwrap_or_else(|e| panic!("{}", e))
+    }
+
+    pub fn try_lstsq(&self, b: &Matrix<T>) -> Result<LeastSquares<T>, MatrixError>
+    {
+        self.try_lstsq_with_rcond(b, self.default_rtol())
+    }
+
+    pub fn try_lstsq_with_rcond(&self, b: &Matrix<T>, rcond: T) -> Result<LeastSquares<T>, MatrixError>
+    {
+        let (m, n) = self.shape();
+
+        if b.rows() != m
+        {
+            return Err(MatrixError::ShapeMismatch { op: "lstsq", lhs: (m, n), rhs: b.shape() });
+        }
+
+        let svd = self.try_svd_thin()?;
+        let rank = svd.rank_with_tol(rcond);
+        let solution = apply_pinv(&svd, rank, b)?;
+
+        // squared residual norms per column, only for full column rank overdetermined systems (as numpy)
+        let residuals = if rank == n && m > n
+        {
+            let w = b.cols();
+            let (a, x, bx) = (self.as_slice(), solution.as_slice(), b.as_slice());
+
+            (0..w).map(|j| (0..m).fold(T::zero(), |acc, i|
+                {
+                    let ax = (0..n).fold(T::zero(), |dot, k| dot + a[i * n + k] * x[k * w + j]);
+                    let r = bx[i * w + j] - ax;
+                    acc + r * r
+                })).collect()
+        }
+        else
+        {
+            Vec::new()
+        };
+
+        Ok(LeastSquares { solution, residuals, rank, singular_values: svd.singular_values().to_vec() })
+    }
+}
+
+impl<T> LeastSquares<T>
+where T: Float
+{
+    pub fn solution(&self) -> &Matrix<T> { &self.solution }
+
+    // ||b_j - A * x_j||^2 for every column, empty when rank < n or m <= n
+    pub fn residuals(&self) -> &[T] { &self.residuals }
+
+    // effective rank of A under rcond
+    pub fn rank(&self) -> usize { self.rank }
+
+    // all min(m, n) singular values of A, descending
+    pub fn singular_values(&self) -> &[T] { &self.singular_values }
+
+    pub fn into_solution(self) -> Matrix<T> { self.solution }
+}
+
+// V * diag(1 / sigma) * U^T * b over the leading <rank> singular triplets
+fn apply_pinv<T: Float>(svd: &SVD<T>, rank: usize, b: &Matrix<T>) -> Result<Matrix<T>, MatrixError>
+{
+    let (u, vt, s) = (svd.u(), svd.vt(), svd.singular_values());
+    let (m, k, n, w) = (u.rows(), u.cols(), vt.cols(), b.cols());
+    let (u, vt, bs) = (u.as_slice(), vt.as_slice(), b.as_slice());
+
+    // c = diag(1 / sigma) * U^T * b, (rank x w)
+    let mut c = vec![T::zero(); rank * w];
+    for (p, row) in c.chunks_exact_mut(w).enumerate()
+    {
+        for i in 0..m
+        {
+            let uip = u[i * k + p];
+            for (cx, &bx) in row.iter_mut().zip(bs[i * w..(i + 1) * w].iter()) { *cx = *cx + uip * bx; }
+        }
+
+        for cx in row.iter_mut() { *cx = *cx / s[p]; }
+    }
+
+    // x = V * c, row p of V^T is column p of V
+    let mut x = Matrix::zeroes(n, w)?;
+    for (i, row) in x.as_mut_slice().chunks_exact_mut(w).enumerate()
+    {
+        for (p, crow) in c.chunks_exact(w).enumerate()
+        {
+            let vip = vt[p * n + i];
+            for (xo, &cx) in row.iter_mut().zip(crow.iter()) { *xo = *xo + vip * cx; }
+        }
+    }
+
+    Ok(x)
+}
@@ -0,0 +1,282 @@
+use num_traits::Float;
+use crate::core::{error::MatrixError, matrix::Matrix};
+
+//----------QR Decomposition (Householder)----------//
+
+// A = Q * R with Q = H_0 * H_1 * ... * H_{k-1}, k = min(rows, cols)
+// each H_j = I - tau_j * v_j * v_j^T with v_j[j] = 1 implied
+// R sits on and above the diagonal, v_j[j+1..] below it (LAPACK geqrf layout)
+#[derive(Debug, Clone, PartialEq)]
+pub struct QR<T>
+where T: Float
+{
+    qr: Matrix<T>,
+    tau: Vec<T>,
+}
+
+impl<T> Matrix<T>
+where T: Float
+{
+    // always succeeds, rank deficiency only shows up in solve_least_squares
+    pub fn qr(&self) -> QR<T>
+    {
+        let (m, n) = self.shape();
+        let k = m.min(n);
+        let mut qr = self.clone();
+        let mut tau = Vec::with_capacity(k);
+        let a = qr.as_mut_slice();
+
+        for j in 0..k
+        {
+            let t = make_reflector(a, m, n, j);
+            tau.push(t);
+
+            if t != T::zero()
+            {
+                let v = reflector(a, m, n, j);
+                reflect_rows(&v, t, a, n, j, j + 1);
+            }
+        }
+
+        QR { qr, tau }
+    }
+}
+
+impl<T> QR<T>
+where T: Float
+{
+    pub(crate) fn from_parts(qr: Matrix<T>, tau: Vec<T>) -> Self
+    {
+        QR { qr, tau }
+    }
+
+    // X----------X
+    // accessors
+
+    pub fn shape(&self) -> (usize, usize) { self.qr.shape() }
+
+    // packed R and Householder vectors
+    pub fn packed(&self) -> &Matrix<T> { &self.qr }
+
+    pub fn tau(&self) -> &[T] { &self.tau }
+
+    // thin factors, Q is (m x k), R is (k x n)
+    pub fn thin_q(&self) -> Matrix<T>
+    {
+        let (m, n) = self.shape();
+        self.build_q(m.min(n))
+    }
+
+    pub fn thin_r(&self) -> Matrix<T>
+    {
+        let (m, n) = self.shape();
+        self.build_r(m.min(n))
+    }
+
+    // full factors, Q is (m x m) orthogonal, R is (m x n)
+    pub fn full_q(&self) -> Matrix<T>
+    {
+        self.build_q(self.shape().0)
+    }
+
+    pub fn full_r(&self) -> Matrix<T>
+    {
+        self.build_r(self.shape().0)
+    }
+
+    // X----------X
+    // solvers
+
+    // minimises ||A * X - B||_2 column by column, needs rows >= cols and full column rank
+    pub fn solve_least_squares(&self, b: &Matrix<T>) -> Matrix<T>
+    {
+        self.try_solve_least_squares(b).unwrap_or_else(|e| panic!("{}", e))
+    }
+
+    pub fn try_solve_least_squares(&self, b: &Matrix<T>) -> Result<Matrix<T>, MatrixError>
+    {
+        const OP: &str = "solve_least_squares";
+
+        let (m, n) = self.shape();
+
+        if m < n
+        {
+            return Err(MatrixError::Underdetermined { op: OP, rows: m, cols: n });
+        }
+
+        if b.rows() != m
+        {
+            return Err(MatrixError::ShapeMismatch { op: OP, lhs: (m, n), rhs: b.shape() });
+        }
+
+        // rank check relative to the largest |r_jj|
+        let r = self.qr.as_slice();
+        let diag_max = (0..n).fold(T::zero(), |acc, j| acc.max(r[j * n + j].abs()));
+        let tol = T::from(m).unwrap() * T::epsilon() * diag_max;
+
+        if (0..n).any(|j| r[j * n + j].abs() <= tol)
+        {
+            return Err(MatrixError::Singular { op: OP });
+        }
+
+        let mut c = b.clone();
+        self.apply_qt(&mut c);
+
+        let w = b.cols();
+        let mut x = c.as_slice()[..n * w].to_vec();
+        back_substitute(r, n, n, &mut x, w);
+
+        Matrix::new(n, w, x)
+    }
+
+    // b <- Q^T * b, b must have self.rows() rows
+    pub(crate) fn apply_qt(&self, b: &mut Matrix<T>)
+    {
+        let (m, n) = self.shape();
+        let w = b.cols();
+
+        for (j, &t) in self.tau.iter().enumerate()
+        {
+            if t == T::zero() { continue; }
+
+            let v = reflector(self.qr.as_slice(), m, n, j);
+            reflect_rows(&v, t, b.as_mut_slice(), w, j, 0);
+        }
+    }
+
+    // X----------X
+    // helpers
+
+    // first <cols> columns of Q, reflectors applied back to front on the identity
+    fn build_q(&self, cols: usize) -> Matrix<T>
+    {
+        let (m, n) = self.shape();
+        let mut q = Matrix::zeroes(m, cols).unwrap();
+
+        for i in 0..cols
+        {
+            q.as_mut_slice()[i * cols + i] = T::one();
+        }
+
+        for (j, &t) in self.tau.iter().enumerate().rev()
+        {
+            if t == T::zero() { continue; }
+
+            // columns left of j are still unit vectors with zeros in rows j.., so H_j skips them
+            let v = reflector(self.qr.as_slice(), m, n, j);
+            reflect_rows(&v, t, q.as_mut_slice(), cols, j, j.min(cols));
+        }
+
+        q
+    }
+
+    // upper triangle of the packed matrix, first <rows> rows
+    fn build_r(&self, rows: usize) -> Matrix<T>
+    {
+        let n = self.shape().1;
+        let mut r = Matrix::zeroes(rows, n).unwrap();
+        let src = self.qr.as_slice();
+
+        for i in 0..rows.min(self.shape().0).min(n)
+        {
+            r.as_mut_slice()[i * n + i..(i + 1) * n].copy_from_slice(&src[i * n + i..(i + 1) * n]);
+        }
+
+        r
+    }
+}
+
+// X----------X
+// Householder kernels on row-major buffers, shared with the pivoted variant
+
+// turns a[j.., j] into beta * e_1 and stores v[1..] below the diagonal, returns tau
+// tau == 0 means the column is already reduced (H = I)
+pub(crate) fn make_reflector<T>(a: &mut [T], m: usize, n: usize, j: usize) -> T
+where T: Float
+{
+    let alpha = a[j * n + j];
+    let tail_norm = ((j + 1)..m).fold(T::zero(), |acc, i| acc.hypot(a[i * n + j]));
+
+    if tail_norm == T::zero() { return T::zero(); }
+
+    // beta takes the opposite sign of alpha to avoid cancellation
+    let norm = alpha.hypot(tail_norm);
+    let beta = if alpha >= T::zero() { -norm } else { norm };
+    let scale = T::one() / (alpha - beta);
+
+    for i in (j + 1)..m
+    {
+        a[i * n + j] = a[i * n + j] * scale;
+    }
+
+    a[j * n + j] = beta;
+
+    (beta - alpha) / beta
+}
+
+// copies out v_j = [1, a[j+1.., j]]
+pub(crate) fn reflector<T>(a: &[T], m: usize, n: usize, j: usize) -> Vec<T>
+where T: Float
+{
+    std::iter::once(T::one()).chain(((j + 1)..m).map(|i| a[i * n + j])).collect()
+}
+
+// applies I - tau * v * v^T to rows row0.. and columns col0.. of b (width w)
+pub(crate) fn reflect_rows<T>(v: &[T], tau: T, b: &mut [T], w: usize, row0: usize, col0: usize)
+where T: Float
+{
+    if col0 >= w { return; }
+
+    let rows = &mut b[row0 * w..(row0 + v.len()) * w];
+
+    // work = v^T * B, one pass over the rows
+    let mut work = vec![T::zero(); w - col0];
+
+    for (vi, row) in v.iter().zip(rows.chunks_exact(w))
+    {
+        for (acc, x) in work.iter_mut().zip(row[col0..].iter())
+        {
+            *acc = *acc + *vi * *x;
+        }
+    }
+
+    // B -= tau * v * work
+    for (vi, row) in v.iter().zip(rows.chunks_exact_mut(w))
+    {
+        let s = tau * *vi;
+        if s == T::zero() { continue; }
+
+        for (x, acc) in row[col0..].iter_mut().zip(work.iter())
+        {
+            *x = *x - s * *acc;
+        }
+    }
+}
+
+// solves R * X = B in place for the leading (k x k) block of r (row stride n), B is (k x w)
+pub(crate) fn back_substitute<T>(r: &[T], n: usize, k: usize, b: &mut [T], w: usize)
+where T: Float
+{
+    for i in (0..k).rev()
+    {
+        let (head, done) = b.split_at_mut((i + 1) * w);
+        let row = &mut head[i * w..];
+
+        for (p, done_row) in done.chunks_exact(w).take(k - i - 1).enumerate()
+        {
+            let u = r[i * n + i + 1 + p];
+            if u == T::zero() { continue; }
+
+            for (x, d) in row.iter_mut().zip(done_row.iter())
+            {
+                *x = *x - u * *d;
+            }
+        }
+
+        let diag = r[i * n + i];
+        for x in row.iter_mut()
+        {
+            *x = *x / diag;
+        }
+    }
+}
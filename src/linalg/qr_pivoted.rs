@@ -0,0 +1,193 @@
+use crate::core::matrix::Matrix;
+use crate::linalg::qr::{self, QR};
+
+//----------Column-Pivoted QR (rank revealing)----------//
+
+// A * P = Q * R, at each step the remaining column with the largest norm is moved to the front,
+// so |r_00| >= |r_11| >= ... and the numerical rank can be read off the diagonal of R
+#[derive(Debug, Clone, PartialEq)]
+pub struct PivotedQR
+{
+    qr: QR<f64>,
+
+    // column j of A * P is column perm[j] of A
+    perm: Vec<usize>,
+}
+
+impl Matrix<f64>
+{
+    pub fn qr_pivoted(&self) -> PivotedQR
+    {
+        let (m, n) = self.shape();
+        let k = m.min(n);
+        let mut packed = self.clone();
+        let mut tau = Vec::with_capacity(k);
+        let mut perm: Vec<usize> = (0..n).collect();
+        let a = packed.as_mut_slice();
+
+        // running (partial) column norms, plus the value at the last exact recompute
+        let mut norms: Vec<f64> = (0..n).map(|c| tail_norm(a, m, n, 0, c)).collect();
+        let mut exact = norms.clone();
+
+        for j in 0..k
+        {
+            // bring the heaviest remaining column to position j
+            let p = (j..n).fold(j, |best, c| if norms[c] > norms[best] { c } else { best });
+
+            if p != j
+            {
+                for row in a.chunks_exact_mut(n) { row.swap(j, p); }
+                perm.swap(j, p);
+                norms.swap(j, p);
+                exact.swap(j, p);
+            }
+
+            let t = qr::make_reflector(a, m, n, j);
+            tau.push(t);
+
+            if t != 0.0
+            {
+                let v = qr::reflector(a, m, n, j);
+                qr::reflect_rows(&v, t, a, n, j, j + 1);
+            }
+
+            // downdate the norms by the entry just moved into R (LAPACK xLAQP2),
+            // recomputing when cancellation has eaten most of the digits
+            for c in (j + 1)..n
+            {
+                if norms[c] == 0.0 { continue; }
+
+                let ratio = a[j * n + c].abs() / norms[c];
+                let shrink = (1.0 - ratio * ratio).max(0.0);
+
+                if shrink * (norms[c] / exact[c]).powi(2) <= f64::EPSILON.sqrt()
+                {
+                    norms[c] = tail_norm(a, m, n, j + 1, c);
+                    exact[c] = norms[c];
+                }
+                else
+                {
+                    norms[c] *= shrink.sqrt();
+                }
+            }
+        }
+
+        PivotedQR { qr: QR::from_parts(packed, tau), perm }
+    }
+}
+
+impl PivotedQR
+{
+    // X----------X
+    // accessors
+
+    pub fn shape(&self) -> (usize, usize) { self.qr.shape() }
+
+    // the underlying factorisation of A * P
+    pub fn factors(&self) -> &QR<f64> { &self.qr }
+
+    pub fn permutation(&self) -> &[usize] { &self.perm }
+
+    // permutation matrix P, with A * P = Q * R
+    pub fn p(&self) -> Matrix<f64>
+    {
+        let n = self.perm.len();
+        let mut p = Matrix::zeroes(n, n).unwrap();
+
+        for (j, &i) in self.perm.iter().enumerate()
+        {
+            p.as_mut_slice()[i * n + j] = 1.0;
+        }
+
+        p
+    }
+
+    pub fn thin_q(&self) -> Matrix<f64> { self.qr.thin_q() }
+    pub fn thin_r(&self) -> Matrix<f64> { self.qr.thin_r() }
+    pub fn full_q(&self) -> Matrix<f64> { self.qr.full_q() }
+    pub fn full_r(&self) -> Matrix<f64> { self.qr.full_r() }
+
+    // X----------X
+    // rank
+
+    // max(rows, cols) * eps, relative to |r_00|
+    pub fn default_rtol(&self) -> f64
+    {
+        let (m, n) = self.shape();
+        m.max(n) as f64 * f64::EPSILON
+    }
+
+    pub fn rank(&self) -> usize
+    {
+        self.rank_with_tol(self.default_rtol())
+    }
+
+    // number of diagonal entries with |r_jj| > rtol * |r_00|
+    pub fn rank_with_tol(&self, rtol: f64) -> usize
+    {
+        let (m, n) = self.shape();
+        let r = self.qr.packed().as_slice();
+        let cutoff = rtol * r[0].abs();
+
+        (0..m.min(n)).take_while(|&j| r[j * n + j].abs() > cutoff).count()
+    }
+
+    // X----------X
+    // bases
+
+    // orthonormal basis of range(A), (rows x rank); None for a zero matrix
+    pub fn column_space(&self) -> Option<Matrix<f64>>
+    {
+        self.column_space_with_tol(self.default_rtol())
+    }
+
+    pub fn column_space_with_tol(&self, rtol: f64) -> Option<Matrix<f64>>
+    {
+        let rank = self.rank_with_tol(rtol);
+        if rank == 0 { return None; }
+
+        let q = self.qr.thin_q();
+        Some(q.slice(0..q.rows(), 0..rank).to_owned())
+    }
+
+    // orthonormal basis of null(A), (cols x (cols - rank)); None for full column rank
+    pub fn null_space(&self) -> Option<Matrix<f64>>
+    {
+        self.null_space_with_tol(self.default_rtol())
+    }
+
+    pub fn null_space_with_tol(&self, rtol: f64) -> Option<Matrix<f64>>
+    {
+        let n = self.shape().1;
+        let rank = self.rank_with_tol(rtol);
+        let nullity = n - rank;
+
+        if nullity == 0 { return None; }
+
+        // A * P * [-R11^-1 * R12; I] = 0, with R11 the leading (rank x rank) block
+        let r = self.qr.packed().as_slice();
+        let mut top: Vec<f64> = (0..rank).flat_map(|i| r[i * n + rank..(i + 1) * n].iter().map(|x| -x)).collect();
+        qr::back_substitute(r, n, rank, &mut top, nullity);
+
+        // undo the permutation on the rows, row perm[i] of the basis is row i of the stacked block
+        let mut basis = Matrix::zeroes(n, nullity).unwrap();
+        let out = basis.as_mut_slice();
+
+        for (i, &row) in self.perm.iter().enumerate()
+        {
+            let dst = &mut out[row * nullity..(row + 1) * nullity];
+
+            if i < rank { dst.copy_from_slice(&top[i * nullity..(i + 1) * nullity]); }
+            else { dst[i - rank] = 1.0; }
+        }
+
+        // orthonormalise, the span is unchanged
+        Some(basis.qr().thin_q())
+    }
+}
+
+// 2-norm of a[row0.., c]
+fn tail_norm(a: &[f64], m: usize, n: usize, row0: usize, c: usize) -> f64
+{
+    (row0..m).fold(0.0, |acc: f64, i| acc.hypot(a[i * n + c]))
+}
@@ -0,0 +1,105 @@
+use num_traits::{Float, Num};
+use crate::core::{error::MatrixError, matrix::Matrix};
+use crate::linalg::lu::LU;
+
+//----------Dense Solvers----------//
+
+impl<T> Matrix<T>
+where T: Float
+{
+    // default relative tolerance for the singularity check, n * eps
+    pub fn default_rtol(&self) -> T
+    {
+        T::from(self.rows().max(self.cols())).unwrap() * T::epsilon()
+    }
+
+    // solves A * X = B (B may hold several right-hand sides), default tolerance
+    pub fn solve(&self, b: &Matrix<T>) -> Matrix<T>
+    {
+        self.try_solve(b).unwrap_or_else(|e| panic!("{}", e))
+    }
+
+    pub fn try_solve(&self, b: &Matrix<T>) -> Result<Matrix<T>, MatrixError>
+    {
+        self.try_solve_with_tol(b, self.default_rtol())
+    }
+
+    // A is treated as singular when a pivot satisfies |u_ii| <= rtol * max|a_ij|
+    pub fn try_solve_with_tol(&self, b: &Matrix<T>, rtol: T) -> Result<Matrix<T>, MatrixError>
+    {
+        let lu = self.checked_lu(rtol, "solve")?;
+        lu.check_rhs(b, "solve")?;
+
+        let mut x = b.clone();
+        lu.substitute(&mut x);
+        Ok(x)
+    }
+
+    pub fn inverse(&self) -> Matrix<T>
+    {
+        self.try_inverse().unwrap_or_else(|e| panic!("{}", e))
+    }
+
+    pub fn try_inverse(&self) -> Result<Matrix<T>, MatrixError>
+    {
+        self.try_inverse_with_tol(self.default_rtol())
+    }
+
+    pub fn try_inverse_with_tol(&self, rtol: T) -> Result<Matrix<T>, MatrixError>
+    {
+        let lu = self.checked_lu(rtol, "inverse")?;
+
+        let mut inv = Matrix::identity(self.rows())?;
+        lu.substitute(&mut inv);
+        Ok(inv)
+    }
+
+    // determinant via LU, singular matrices give 0 rather than an error
+    pub fn determinant(&self) -> T
+    {
+        self.try_determinant().unwrap_or_else(|e| panic!("{}", e))
+    }
+
+    pub fn try_determinant(&self) -> Result<T, MatrixError>
+    {
+        self.check_square("determinant")?;
+
+        Ok(self.try_lu()?.det())
+    }
+
+    // X----------X
+    // helpers
+
+    fn checked_lu(&self, rtol: T, op: &'static str) -> Result<LU<T>, MatrixError>
+    {
+        self.check_square(op)?;
+
+        let lu = self.try_lu()?;
+        let scale = self.as_slice().iter().fold(T::zero(), |acc, x| acc.max(x.abs()));
+
+        if lu.is_singular_tol(rtol * scale)
+        {
+            return Err(MatrixError::Singular { op });
+        }
+
+        Ok(lu)
+    }
+}
+
+impl<T> Matrix<T>
+where T: Num + Copy
+{
+    // sum of the diagonal
+    pub fn trace(&self) -> T
+    {
+        self.try_trace().unwrap_or_else(|e| panic!("{}", e))
+    }
+
+    pub fn try_trace(&self) -> Result<T, MatrixError>
+    {
+        self.check_square("trace")?;
+
+        let n = self.rows();
+        Ok((0..n).fold(T::zero(), |acc, i| acc + self.as_slice()[i * n + i]))
+    }
+}
@@ -0,0 +1,271 @@
+use num_traits::Float;
+use crate::core::{error::MatrixError, matrix::Matrix};
+use crate::linalg::total_cmp;
+
+//----------Singular Value Decomposition (one-sided Jacobi)----------//
+
+// sweep cap for the Jacobi iteration, convergence is quadratic so this is never close in practice
+const MAX_SWEEPS: usize = 60;
+
+// A = U * Sigma * V^T, singular values sorted descending
+// full: U is (m x m), V^T is (n x n); thin: U is (m x k), V^T is (k x n), k = min(m, n)
+#[derive(Debug, Clone, PartialEq)]
+pub struct SVD<T>
+where T: Float
+{
+    u: Matrix<T>,
+    s: Vec<T>,
+    vt: Matrix<T>,
+}
+
+impl<T> Matrix<T>
+where T: Float
+{
+    pub fn svd(&self) -> SVD<T>
+    {
+        self.try_svd().unwrap_or_else(|e| panic!("{}", e))
+    }
+
+    pub fn try_svd(&self) -> Result<SVD<T>, MatrixError>
+    {
+        self.svd_op(true)
+    }
+
+    pub fn svd_thin(&self) -> SVD<T>
+    {
+        self.try_svd_thin().unwrap_or_else(|e| panic!("{}", e))
+    }
+
+    pub fn try_svd_thin(&self) -> Result<SVD<T>, MatrixError>
+    {
+        self.svd_op(false)
+    }
+
+    // values only, skips forming U and V
+    pub fn singular_values(&self) -> Vec<T>
+    {
+        self.try_singular_values().unwrap_or_else(|e| panic!("{}", e))
+    }
+
+    pub fn try_singular_values(&self) -> Result<Vec<T>, MatrixError>
+    {
+        let (m, n) = self.shape();
+
+        // Jacobi works on the rows of A^T (the columns of A), so pick the orientation with fewer of them
+        let mut w = if m >= n { self.t_view().to_owned() } else { self.clone() };
+        let k = m.min(n);
+        jacobi(w.as_mut_slice(), k, m.max(n), None)?;
+
+        let mut s: Vec<T> = w.as_slice().chunks_exact(m.max(n)).map(norm).collect();
+        s.sort_by(|a, b| total_cmp(b, a));
+        Ok(s)
+    }
+
+    fn svd_op(&self, full: bool) -> Result<SVD<T>, MatrixError>
+    {
+        let (m, n) = self.shape();
+
+        // wide input, decompose A^T = U' S V'^T and swap the roles: A = V' S U'^T
+        if m < n
+        {
+            let svd = self.t_view().to_owned().svd_op(full)?;
+            return Ok(SVD { u: svd.vt.t_view().to_owned(), s: svd.s, vt: svd.u.t_view().to_owned() });
+        }
+
+        // rows of w are the columns of A, rows of vt accumulate the rotations (V^T is built directly)
+        let mut w = self.t_view().to_owned();
+        let mut vt = Matrix::identity(n)?;
+        jacobi(w.as_mut_slice(), n, m, Some(vt.as_mut_slice()))?;
+
+        // sigma_i = |w_i|, u_i = w_i / sigma_i; sort by sigma descending
+        let s_raw: Vec<T> = w.as_slice().chunks_exact(m).map(norm).collect();
+        let mut order: Vec<usize> = (0..n).collect();
+        order.sort_by(|&a, &b| total_cmp(&s_raw[b], &s_raw[a]));
+
+        let s: Vec<T> = order.iter().map(|&i| s_raw[i]).collect();
+        let vt_sorted: Vec<T> = order.iter().flat_map(|&i| vt.as_slice()[i * n..(i + 1) * n].iter().copied()).collect();
+
+        // columns of U, zero singular values leave a gap that gets filled below
+        let cutoff = s[0] * T::epsilon();
+        let mut u_cols: Vec<Vec<T>> = order.iter().zip(s.iter())
+            .filter(|&(_, &sigma)| sigma > cutoff)
+            .map(|(&i, &sigma)| w.as_slice()[i * m..(i + 1) * m].iter().map(|&x| x / sigma).collect())
+            .collect();
+
+        let cols = if full { m } else { n };
+        complete_basis(&mut u_cols, m, cols);
+
+        let mut u = Matrix::zeroes(m, cols)?;
+        for (j, col) in u_cols.iter().enumerate()
+        {
+            for (i, &x) in col.iter().enumerate() { u.as_mut_slice()[i * cols + j] = x; }
+        }
+
+        Ok(SVD { u, s, vt: Matrix::new(n, n, vt_sorted)? }.trim_vt(full))
+    }
+}
+
+impl<T> SVD<T>
+where T: Float
+{
+    // X----------X
+    // accessors
+
+    pub fn u(&self) -> &Matrix<T> { &self.u }
+
+    pub fn vt(&self) -> &Matrix<T> { &self.vt }
+
+    // min(m, n) values, descending
+    pub fn singular_values(&self) -> &[T] { &self.s }
+
+    // Sigma shaped to sit between u() and vt()
+    pub fn sigma(&self) -> Matrix<T>
+    {
+        let (rows, cols) = (self.u.cols(), self.vt.rows());
+        let mut sigma = Matrix::zeroes(rows, cols).unwrap();
+
+        for (i, &s) in self.s.iter().enumerate()
+        {
+            sigma.as_mut_slice()[i * cols + i] = s;
+        }
+
+        sigma
+    }
+
+    // X----------X
+    // derived quantities
+
+    // 2-norm condition number, sigma_max / sigma_min (inf when singular)
+    pub fn cond(&self) -> T
+    {
+        self.s[0] / self.s[self.s.len() - 1]
+    }
+
+    // number of singular values above rtol * sigma_max
+    pub fn rank_with_tol(&self, rtol: T) -> usize
+    {
+        let cutoff = rtol * self.s[0];
+        self.s.iter().filter(|&&s| s > cutoff).count()
+    }
+
+    // best rank-r approximation in the 2- and Frobenius norms (Eckart-Young)
+    pub fn low_rank(&self, r: usize) -> Matrix<T>
+    {
+        let (m, n) = (self.u.rows(), self.vt.cols());
+        let (uc, r) = (self.u.cols(), r.min(self.s.len()));
+        let (u, vt) = (self.u.as_slice(), self.vt.as_slice());
+        let mut out = Matrix::zeroes(m, n).unwrap();
+
+        for (i, row) in out.as_mut_slice().chunks_exact_mut(n).enumerate()
+        {
+            for p in 0..r
+            {
+                let scale = u[i * uc + p] * self.s[p];
+                for (o, &v) in row.iter_mut().zip(vt[p * n..(p + 1) * n].iter()) { *o = *o + scale * v; }
+            }
+        }
+
+        out
+    }
+
+    // thin output keeps only the first k rows of V^T
+    fn trim_vt(mut self, full: bool) -> Self
+    {
+        let k = self.s.len();
+
+        if !full && self.vt.rows() > k
+        {
+            let n = self.vt.cols();
+            self.vt = Matrix::new(k, n, self.vt.as_slice()[..k * n].to_vec()).unwrap();
+        }
+
+        self
+    }
+}
+
+// X----------X
+// kernels
+
+fn norm<T: Float>(x: &[T]) -> T
+{
+    x.iter().fold(T::zero(), |acc, &v| acc.hypot(v))
+}
+
+// Hestenes one-sided Jacobi on the <k> rows (length <len>) of w until they are mutually orthogonal,
+// the same rotations are applied to the rows of vt when given
+fn jacobi<T: Float>(w: &mut [T], k: usize, len: usize, mut vt: Option<&mut [T]>) -> Result<(), MatrixError>
+{
+    let two = T::one() + T::one();
+
+    for _ in 0..MAX_SWEEPS
+    {
+        let mut rotated = false;
+
+        for p in 0..k
+        {
+            for q in (p + 1)..k
+            {
+                let (head, tail) = w.split_at_mut(q * len);
+                let (wp, wq) = (&mut head[p * len..(p + 1) * len], &mut tail[..len]);
+
+                let alpha = wp.iter().fold(T::zero(), |acc, &x| acc + x * x);
+                let beta = wq.iter().fold(T::zero(), |acc, &x| acc + x * x);
+                let gamma = wp.iter().zip(wq.iter()).fold(T::zero(), |acc, (&x, &y)| acc + x * y);
+
+                if gamma == T::zero() || gamma.abs() <= T::epsilon() * (alpha * beta).sqrt() { continue; }
+
+                rotated = true;
+
+                // rotation that zeroes the (p, q) entry of the 2x2 Gram block
+                let zeta = (beta - alpha) / (two * gamma);
+                let t = zeta.signum() / (zeta.abs() + (T::one() + zeta * zeta).sqrt());
+                let c = T::one() / (T::one() + t * t).sqrt();
+                let s = c * t;
+
+                rotate(wp, wq, c, s);
+
+                if let Some(vt) = vt.as_deref_mut()
+                {
+                    let (head, tail) = vt.split_at_mut(q * k);
+                    rotate(&mut head[p * k..(p + 1) * k], &mut tail[..k], c, s);
+                }
+            }
+        }
+
+        if !rotated { return Ok(()); }
+    }
+
+    Err(MatrixError::NotConverged { op: "svd", iterations: MAX_SWEEPS })
+}
+
+fn rotate<T: Float>(x: &mut [T], y: &mut [T], c: T, s: T)
+{
+    for (a, b) in x.iter_mut().zip(y.iter_mut())
+    {
+        let (xa, yb) = (*a, *b);
+        *a = c * xa - s * yb;
+        *b = s * xa + c * yb;
+    }
+}
+
+// extends orthonormal vectors (length m) to <target> of them, the extra ones come from the
+// full Q of a Householder QR of the existing set, whose trailing columns span the complement
+fn complete_basis<T: Float>(cols: &mut Vec<Vec<T>>, m: usize, target: usize)
+{
+    let r = cols.len();
+    if r >= target { return; }
+
+    if r == 0
+    {
+        cols.extend((0..target).map(|e| (0..m).map(|i| if i == e { T::one() } else { T::zero() }).collect()));
+        return;
+    }
+
+    let data = (0..m).flat_map(|i| cols.iter().map(move |c| c[i])).collect();
+    let q = Matrix::new(m, r, data).unwrap().qr().full_q();
+
+    for j in r..target
+    {
+        cols.push((0..m).map(|i| q.as_slice()[i * m + j]).collect());
+    }
+}
@@ -0,0 +1,296 @@
+use num_traits::Float;
+use crate::core::{error::MatrixError, matrix::Matrix};
+use crate::linalg::total_cmp;
+
+//----------Symmetric Eigendecomposition (tridiagonal QL)----------//
+
+// QL sweeps allowed per eigenvalue before giving up, same budget as LAPACK's xSTEQR
+const MAX_ITER_PER_VALUE: usize = 30;
+
+// A = V * Lambda * V^T for symmetric A, eigenvalues ascending
+// column j of V is the unit eigenvector for values()[j]
+#[derive(Debug, Clone, PartialEq)]
+pub struct SymmetricEigen<T>
+where T: Float
+{
+    values: Vec<T>,
+    vectors: Matrix<T>,
+}
+
+impl<T> Matrix<T>
+where T: Float
+{
+    pub fn symmetric_eigen(&self) -> SymmetricEigen<T>
+    {
+        self.try_symmetric_eigen().unwrap_or_else(|e| panic!("{}", e))
+    }
+
+    pub fn try_symmetric_eigen(&self) -> Result<SymmetricEigen<T>, MatrixError>
+    {
+        const OP: &str = "symmetric_eigen";
+
+        self.check_symmetric(OP)?;
+
+        let n = self.rows();
+        let mut v = self.as_slice().to_vec();
+        let mut d = vec![T::zero(); n];
+        let mut e = vec![T::zero(); n];
+
+        tridiagonalize(&mut v, &mut d, &mut e, n);
+        tridiagonal_ql(&mut v, &mut d, &mut e, n, OP)?;
+
+        // ascending, columns of V follow their eigenvalue
+        // a NaN diagonal gets past the symmetry check and QL, the total order sorts it last instead of panicking
+        let mut order: Vec<usize> = (0..n).collect();
+        order.sort_by(|&a, &b| total_cmp(&d[a], &d[b]));
+
+        let values = order.iter().map(|&j| d[j]).collect();
+        let data = (0..n).flat_map(|i| order.iter().map(move |&j| (i, j))).map(|(i, j)| v[i * n + j]).collect();
+
+        Ok(SymmetricEigen { values, vectors: Matrix::new(n, n, data)? })
+    }
+}
+
+impl<T> SymmetricEigen<T>
+where T: Float
+{
+    // X----------X
+    // accessors
+
+    pub fn size(&self) -> usize { self.values.len() }
+
+    // ascending
+    pub fn values(&self) -> &[T] { &self.values }
+
+    // orthonormal eigenvectors as columns
+    pub fn vectors(&self) -> &Matrix<T> { &self.vectors }
+
+    // Lambda as a dense diagonal matrix
+    pub fn lambda(&self) -> Matrix<T>
+    {
+        let n = self.size();
+        let mut lambda = Matrix::zeroes(n, n).unwrap();
+
+        for (i, &x) in self.values.iter().enumerate()
+        {
+            lambda.as_mut_slice()[i * n + i] = x;
+        }
+
+        lambda
+    }
+
+    // X----------X
+    // derived quantities
+
+    // V * f(Lambda) * V^T, e.g. sqrt for the matrix square root or exp for the matrix exponential
+    pub fn map_values(&self, func: impl Fn(T) -> T) -> Matrix<T>
+    {
+        let n = self.size();
+        let fv: Vec<T> = self.values.iter().map(|&x| func(x)).collect();
+        let q = self.vectors.as_slice();
+        let mut out = Matrix::zeroes(n, n).unwrap();
+
+        for (i, row) in out.as_mut_slice().chunks_exact_mut(n).enumerate()
+        {
+            for (j, o) in row.iter_mut().enumerate()
+            {
+                *o = (0..n).fold(T::zero(), |acc, k| acc + q[i * n + k] * fv[k] * q[j * n + k]);
+            }
+        }
+
+        out
+    }
+}
+
+// X----------X
+// kernels
+
+// Householder reduction of the symmetric (n x n) buffer <v> to tridiagonal form (EISPACK tred2)
+// on exit d holds the diagonal, e[1..] the subdiagonal, and v the accumulated orthogonal transform
+fn tridiagonalize<T: Float>(v: &mut [T], d: &mut [T], e: &mut [T], n: usize)
+{
+    if n == 0 { return; }
+
+    d.copy_from_slice(&v[(n - 1) * n..]);
+
+    for i in (1..n).rev()
+    {
+        let scale = d[..i].iter().fold(T::zero(), |acc, x| acc + x.abs());
+        let mut h = T::zero();
+
+        if scale == T::zero()
+        {
+            e[i] = d[i - 1];
+
+            for j in 0..i
+            {
+                d[j] = v[(i - 1) * n + j];
+                v[i * n + j] = T::zero();
+                v[j * n + i] = T::zero();
+            }
+        }
+        else
+        {
+            // reflector from the scaled row i
+            for x in d[..i].iter_mut()
+            {
+                *x = *x / scale;
+                h = h + *x * *x;
+            }
+
+            let f = d[i - 1];
+            let g = if f > T::zero() { -h.sqrt() } else { h.sqrt() };
+            e[i] = scale * g;
+            h = h - f * g;
+            d[i - 1] = f - g;
+
+            for x in e[..i].iter_mut() { *x = T::zero(); }
+
+            // e = A * u over the leading block
+            for j in 0..i
+            {
+                let f = d[j];
+                v[j * n + i] = f;
+                let mut g = e[j] + v[j * n + j] * f;
+
+                for k in (j + 1)..i
+                {
+                    g = g + v[k * n + j] * d[k];
+                    e[k] = e[k] + v[k * n + j] * f;
+                }
+
+                e[j] = g;
+            }
+
+            let mut f = T::zero();
+            for j in 0..i
+            {
+                e[j] = e[j] / h;
+                f = f + e[j] * d[j];
+            }
+
+            let hh = f / (h + h);
+            for j in 0..i { e[j] = e[j] - hh * d[j]; }
+
+            // rank-2 update of the leading block
+            for j in 0..i
+            {
+                let (f, g) = (d[j], e[j]);
+                for k in j..i { v[k * n + j] = v[k * n + j] - (f * e[k] + g * d[k]); }
+                d[j] = v[(i - 1) * n + j];
+                v[i * n + j] = T::zero();
+            }
+        }
+
+        d[i] = h;
+    }
+
+    // accumulate the reflectors into v
+    for i in 0..(n - 1)
+    {
+        v[(n - 1) * n + i] = v[i * n + i];
+        v[i * n + i] = T::one();
+        let h = d[i + 1];
+
+        if h != T::zero()
+        {
+            for k in 0..=i { d[k] = v[k * n + i + 1] / h; }
+
+            for j in 0..=i
+            {
+                let g = (0..=i).fold(T::zero(), |acc, k| acc + v[k * n + i + 1] * v[k * n + j]);
+                for k in 0..=i { v[k * n + j] = v[k * n + j] - g * d[k]; }
+            }
+        }
+
+        for k in 0..=i { v[k * n + i + 1] = T::zero(); }
+    }
+
+    for j in 0..n
+    {
+        d[j] = v[(n - 1) * n + j];
+        v[(n - 1) * n + j] = T::zero();
+    }
+
+    v[(n - 1) * n + n - 1] = T::one();
+    e[0] = T::zero();
+}
+
+// implicit QL with Wilkinson-style shifts on the tridiagonal (d, e) (EISPACK tql2),
+// rotations are accumulated into the columns of v
+fn tridiagonal_ql<T: Float>(v: &mut [T], d: &mut [T], e: &mut [T], n: usize, op: &'static str) -> Result<(), MatrixError>
+{
+    if n == 0 { return Ok(()); }
+
+    let two = T::one() + T::one();
+
+    e.copy_within(1.., 0);
+    e[n - 1] = T::zero();
+
+    let mut f = T::zero();
+    let mut tst1 = T::zero();
+
+    for l in 0..n
+    {
+        // find a negligible subdiagonal entry, e[n - 1] = 0 stops the search
+        tst1 = tst1.max(d[l].abs() + e[l].abs());
+        let m = (l..n).find(|&m| e[m].abs() <= T::epsilon() * tst1).unwrap_or(n - 1);
+
+        let mut iter = 0;
+        while m > l && e[l].abs() > T::epsilon() * tst1
+        {
+            iter += 1;
+            if iter > MAX_ITER_PER_VALUE { return Err(MatrixError::NotConverged { op, iterations: MAX_ITER_PER_VALUE }); }
+
+            // shift from the leading 2x2 block
+            let g = d[l];
+            let p = (d[l + 1] - g) / (two * e[l]);
+            let r = if p < T::zero() { -p.hypot(T::one()) } else { p.hypot(T::one()) };
+            d[l] = e[l] / (p + r);
+            d[l + 1] = e[l] * (p + r);
+            let dl1 = d[l + 1];
+            let h = g - d[l];
+
+            for x in d[(l + 2)..].iter_mut() { *x = *x - h; }
+            f = f + h;
+
+            // chase the bulge from m back up to l
+            let mut p = d[m];
+            let (mut c, mut c2, mut c3) = (T::one(), T::one(), T::one());
+            let el1 = e[l + 1];
+            let (mut s, mut s2) = (T::zero(), T::zero());
+
+            for i in (l..m).rev()
+            {
+                c3 = c2;
+                c2 = c;
+                s2 = s;
+
+                let g = c * e[i];
+                let h = c * p;
+                let r = p.hypot(e[i]);
+                e[i + 1] = s * r;
+                s = e[i] / r;
+                c = p / r;
+                p = c * d[i] - s * g;
+                d[i + 1] = h + s * (c * g + s * d[i]);
+
+                for k in 0..n
+                {
+                    let h = v[k * n + i + 1];
+                    v[k * n + i + 1] = s * v[k * n + i] + c * h;
+                    v[k * n + i] = c * v[k * n + i] - s * h;
+                }
+            }
+
+            let p = -s * s2 * c3 * el1 * e[l] / dl1;
+            e[l] = s * p;
+            d[l] = c * p;
+        }
+
+        d[l] = d[l] + f;
+        e[l] = T::zero();
+    }
+
+    Ok(())
+}
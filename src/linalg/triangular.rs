@@ -0,0 +1,489 @@
+use num_traits::{Float, Num};
+use crate::core::{error::MatrixError, matrix::Matrix};
+
+//----------Triangular Solves----------//
+
+// the triangle named in the method is read from self, the other half is ignored
+// *_unit variants assume a unit diagonal without reading it, *_transpose variants solve with the
+// transpose of that triangle (an upper system for lower, and vice versa)
+impl<T> Matrix<T>
+where T: Float
+{
+    // L * X = B, forward substitution
+    pub fn solve_lower_triangular(&self, b: &Matrix<T>) -> Matrix<T>
+    {
+        self.try_solve_lower_triangular(b).unwrap_or_else(|e| panic!("{}", e))
+    }
+
+    pub fn try_solve_lower_triangular(&self, b: &Matrix<T>) -> Result<Matrix<T>, MatrixError>
+    {
+        self.triangular_solve(b, true, false, false, "solve_lower_triangular")
+    }
+
+    // U * X = B, back substitution
+    pub fn solve_upper_triangular(&self, b: &Matrix<T>) -> Matrix<T>
+    {
+        self.try_solve_upper_triangular(b).unwrap_or_else(|e| panic!("{}", e))
+    }
+
+    pub fn try_solve_upper_triangular(&self, b: &Matrix<T>) -> Result<Matrix<T>, MatrixError>
+    {
+        self.triangular_solve(b, false, false, false, "solve_upper_triangular")
+    }
+
+    pub fn solve_lower_triangular_unit(&self, b: &Matrix<T>) -> Matrix<T>
+    {
+        self.try_solve_lower_triangular_unit(b).unwrap_or_else(|e| panic!("{}", e))
+    }
+
+    pub fn try_solve_lower_triangular_unit(&self, b: &Matrix<T>) -> Result<Matrix<T>, MatrixError>
+    {
+        self.triangular_solve(b, true, false, true, "solve_lower_triangular_unit")
+    }
+
+    pub fn solve_upper_triangular_unit(&self, b: &Matrix<T>) -> Matrix<T>
+    {
+        self.try_solve_upper_triangular_unit(b).unwrap_or_else(|e| panic!("{}", e))
+    }
+
+    pub fn try_solve_upper_triangular_unit(&self, b: &Matrix<T>) -> Result<Matrix<T>, MatrixError>
+    {
+        self.triangular_solve(b, false, false, true, "solve_upper_triangular_unit")
+    }
+
+    // L^T * X = B, e.g. the second half of a Cholesky solve
+    pub fn solve_lower_triangular_transpose(&self, b: &Matrix<T>) -> Matrix<T>
+    {
+        self.try_solve_lower_triangular_transpose(b).unwrap_or_else(|e| panic!("{}", e))
+    }
+
+    pub fn try_solve_lower_triangular_transpose(&self, b: &Matrix<T>) -> Result<Matrix<T>, MatrixError>
+    {
+        self.triangular_solve(b, true, true, false, "solve_lower_triangular_transpose")
+    }
+
+    // U^T * X = B
+    pub fn solve_upper_triangular_transpose(&self, b: &Matrix<T>) -> Matrix<T>
+    {
+        self.try_solve_upper_triangular_transpose(b).unwrap_or_else(|e| panic!("{}", e))
+    }
+
+    pub fn try_solve_upper_triangular_transpose(&self, b: &Matrix<T>) -> Result<Matrix<T>, MatrixError>
+    {
+        self.triangular_solve(b, false, true, false, "solve_upper_triangular_transpose")
+    }
+
+    fn triangular_solve(&self, b: &Matrix<T>, lower: bool, trans: bool, unit: bool, op: &'static str) -> Result<Matrix<T>, MatrixError>
+    {
+        self.check_square(op)?;
+
+        let n = self.rows();
+        let a = self.as_slice();
+        let mut x = b.clone();
+
+        let at = |i: usize, j: usize| if trans { a[j * n + i] } else { a[i * n + j] };
+        substitute(at, n, lower != trans, unit, &mut x, op)?;
+
+        Ok(x)
+    }
+}
+
+//----------Packed Triangular Matrices----------//
+
+// square lower triangular matrix, only the n * (n + 1) / 2 entries on and below the diagonal are stored
+#[derive(Debug, Clone, PartialEq)]
+pub struct LowerTriangular<T>
+where T: Num + Copy
+{
+    n: usize,
+
+    // row-major, row i holds columns 0..=i
+    data: Vec<T>,
+}
+
+// square upper triangular matrix, only the entries on and above the diagonal are stored
+#[derive(Debug, Clone, PartialEq)]
+pub struct UpperTriangular<T>
+where T: Num + Copy
+{
+    n: usize,
+
+    // row-major, row i holds columns i..n
+    data: Vec<T>,
+}
+
+impl<T> Matrix<T>
+where T: Num + Copy
+{
+    // keeps the lower triangle (with diagonal), drops the rest
+    pub fn to_lower_triangular(&self) -> LowerTriangular<T>
+    {
+        self.try_to_lower_triangular().unwrap_or_else(|e| panic!("{}", e))
+    }
+
+    pub fn try_to_lower_triangular(&self) -> Result<LowerTriangular<T>, MatrixError>
+    {
+        self.check_square("to_lower_triangular")?;
+
+        let n = self.rows();
+        let data = self.as_slice().chunks_exact(n).enumerate().flat_map(|(i, row)| row[..=i].iter().copied()).collect();
+
+        Ok(LowerTriangular { n, data })
+    }
+
+    // keeps the upper triangle (with diagonal), drops the rest
+    pub fn to_upper_triangular(&self) -> UpperTriangular<T>
+    {
+        self.try_to_upper_triangular().unwrap_or_else(|e| panic!("{}", e))
+    }
+
+    pub fn try_to_upper_triangular(&self) -> Result<UpperTriangular<T>, MatrixError>
+    {
+        self.check_square("to_upper_triangular")?;
+
+        let n = self.rows();
+        let data = self.as_slice().chunks_exact(n).enumerate().flat_map(|(i, row)| row[i..].iter().copied()).collect();
+
+        Ok(UpperTriangular { n, data })
+    }
+}
+
+impl<T> LowerTriangular<T>
+where T: Num + Copy
+{
+    // X----------X
+    // accessors
+
+    pub fn size(&self) -> usize { self.n }
+
+    // packed storage, row by row
+    pub fn as_packed(&self) -> &[T] { &self.data }
+
+    // zero above the diagonal, None out of bounds
+    pub fn get(&self, i: usize, j: usize) -> Option<T>
+    {
+        if i >= self.n || j >= self.n { return None; }
+        Some(if j <= i { self.data[offset_lower(i) + j] } else { T::zero() })
+    }
+
+    pub fn to_matrix(&self) -> Matrix<T>
+    {
+        let n = self.n;
+        let mut out = Matrix::zeroes(n, n).unwrap();
+
+        for (i, row) in out.as_mut_slice().chunks_exact_mut(n).enumerate()
+        {
+            row[..=i].copy_from_slice(self.row(i));
+        }
+
+        out
+    }
+
+    // the transpose is upper triangular with the same packed entries, reordered
+    pub fn t(&self) -> UpperTriangular<T>
+    {
+        let n = self.n;
+        let data = (0..n).flat_map(|i| (i..n).map(move |j| (i, j))).map(|(i, j)| self.data[offset_lower(j) + i]).collect();
+        UpperTriangular { n, data }
+    }
+
+    pub fn diagonal(&self) -> Vec<T>
+    {
+        (0..self.n).map(|i| self.data[offset_lower(i) + i]).collect()
+    }
+
+    // X----------X
+    // products
+
+    // L * B, skips the structural zeros (about half the work of a dense matmul)
+    pub fn matmul(&self, b: &Matrix<T>) -> Matrix<T>
+    {
+        self.try_matmul(b).unwrap_or_else(|e| panic!("{}", e))
+    }
+
+    pub fn try_matmul(&self, b: &Matrix<T>) -> Result<Matrix<T>, MatrixError>
+    {
+        let n = self.n;
+
+        if b.rows() != n
+        {
+            return Err(MatrixError::InnerDimMismatch { op: "matmul", lhs: (n, n), rhs: b.shape() });
+        }
+
+        let w = b.cols();
+        let bs = b.as_slice();
+        let mut out = Matrix::zeroes(n, w)?;
+
+        for (i, row) in out.as_mut_slice().chunks_exact_mut(w).enumerate()
+        {
+            for (k, &l) in self.row(i).iter().enumerate()
+            {
+                for (o, &x) in row.iter_mut().zip(bs[k * w..(k + 1) * w].iter()) { *o = *o + l * x; }
+            }
+        }
+
+        Ok(out)
+    }
+
+    // L1 * L2 stays lower triangular
+    pub fn matmul_lower(&self, other: &LowerTriangular<T>) -> LowerTriangular<T>
+    {
+        self.try_matmul_lower(other).unwrap_or_else(|e| panic!("{}", e))
+    }
+
+    pub fn try_matmul_lower(&self, other: &LowerTriangular<T>) -> Result<LowerTriangular<T>, MatrixError>
+    {
+        let n = self.n;
+
+        if other.n != n
+        {
+            return Err(MatrixError::InnerDimMismatch { op: "matmul_lower", lhs: (n, n), rhs: (other.n, other.n) });
+        }
+
+        // (i, j) only sums over j <= k <= i
+        let data = (0..n).flat_map(|i| (0..=i).map(move |j| (i, j)))
+            .map(|(i, j)| (j..=i).fold(T::zero(), |acc, k| acc + self.data[offset_lower(i) + k] * other.data[offset_lower(k) + j]))
+            .collect();
+
+        Ok(LowerTriangular { n, data })
+    }
+
+    fn row(&self, i: usize) -> &[T]
+    {
+        let start = offset_lower(i);
+        &self.data[start..start + i + 1]
+    }
+}
+
+impl<T> UpperTriangular<T>
+where T: Num + Copy
+{
+    // X----------X
+    // accessors
+
+    pub fn size(&self) -> usize { self.n }
+
+    // packed storage, row by row
+    pub fn as_packed(&self) -> &[T] { &self.data }
+
+    // zero below the diagonal, None out of bounds
+    pub fn get(&self, i: usize, j: usize) -> Option<T>
+    {
+        if i >= self.n || j >= self.n { return None; }
+        Some(if j >= i { self.data[offset_upper(i, self.n) + j - i] } else { T::zero() })
+    }
+
+    pub fn to_matrix(&self) -> Matrix<T>
+    {
+        let n = self.n;
+        let mut out = Matrix::zeroes(n, n).unwrap();
+
+        for (i, row) in out.as_mut_slice().chunks_exact_mut(n).enumerate()
+        {
+            row[i..].copy_from_slice(self.row(i));
+        }
+
+        out
+    }
+
+    pub fn t(&self) -> LowerTriangular<T>
+    {
+        let n = self.n;
+        let data = (0..n).flat_map(|i| (0..=i).map(move |j| (i, j))).map(|(i, j)| self.data[offset_upper(j, n) + i - j]).collect();
+        LowerTriangular { n, data }
+    }
+
+    pub fn diagonal(&self) -> Vec<T>
+    {
+        (0..self.n).map(|i| self.data[offset_upper(i, self.n)]).collect()
+    }
+
+    // X----------X
+    // products
+
+    // U * B, skips the structural zeros
+    pub fn matmul(&self, b: &Matrix<T>) -> Matrix<T>
+    {
+        self.try_matmul(b).unwrap_or_else(|e| panic!("{}", e))
+    }
+
+    pub fn try_matmul(&self, b: &Matrix<T>) -> Result<Matrix<T>, MatrixError>
+    {
+        let n = self.n;
+
+        if b.rows() != n
+        {
+            return Err(MatrixError::InnerDimMismatch { op: "matmul", lhs: (n, n), rhs: b.shape() });
+        }
+
+        let w = b.cols();
+        let bs = b.as_slice();
+        let mut out = Matrix::zeroes(n, w)?;
+
+        for (i, row) in out.as_mut_slice().chunks_exact_mut(w).enumerate()
+        {
+            for (k, &u) in self.row(i).iter().enumerate()
+            {
+                let k = i + k;
+                for (o, &x) in row.iter_mut().zip(bs[k * w..(k + 1) * w].iter()) { *o = *o + u * x; }
+            }
+        }
+
+        Ok(out)
+    }
+
+    // U1 * U2 stays upper triangular
+    pub fn matmul_upper(&self, other: &UpperTriangular<T>) -> UpperTriangular<T>
+    {
+        self.try_matmul_upper(other).unwrap_or_else(|e| panic!("{}", e))
+    }
+
+    pub fn try_matmul_upper(&self, other: &UpperTriangular<T>) -> Result<UpperTriangular<T>, MatrixError>
+    {
+        let n = self.n;
+
+        if other.n != n
+        {
+            return Err(MatrixError::InnerDimMismatch { op: "matmul_upper", lhs: (n, n), rhs: (other.n, other.n) });
+        }
+
+        // (i, j) only sums over i <= k <= j
+        let data = (0..n).flat_map(|i| (i..n).map(move |j| (i, j)))
+            .map(|(i, j)| (i..=j).fold(T::zero(), |acc, k| acc + self.data[offset_upper(i, n) + k - i] * other.data[offset_upper(k, n) + j - k]))
+            .collect();
+
+        Ok(UpperTriangular { n, data })
+    }
+
+    fn row(&self, i: usize) -> &[T]
+    {
+        let start = offset_upper(i, self.n);
+        &self.data[start..start + self.n - i]
+    }
+}
+
+impl<T> LowerTriangular<T>
+where T: Float
+{
+    pub fn det(&self) -> T
+    {
+        self.diagonal().into_iter().fold(T::one(), |acc, x| acc * x)
+    }
+
+    // L * X = B
+    pub fn solve(&self, b: &Matrix<T>) -> Matrix<T>
+    {
+        self.try_solve(b).unwrap_or_else(|e| panic!("{}", e))
+    }
+
+    pub fn try_solve(&self, b: &Matrix<T>) -> Result<Matrix<T>, MatrixError>
+    {
+        let mut x = b.clone();
+        substitute(|i, j| self.data[offset_lower(i) + j], self.n, true, false, &mut x, "solve")?;
+        Ok(x)
+    }
+
+    // L^T * X = B
+    pub fn solve_transpose(&self, b: &Matrix<T>) -> Matrix<T>
+    {
+        self.try_solve_transpose(b).unwrap_or_else(|e| panic!("{}", e))
+    }
+
+    pub fn try_solve_transpose(&self, b: &Matrix<T>) -> Result<Matrix<T>, MatrixError>
+    {
+        let mut x = b.clone();
+        substitute(|i, j| self.data[offset_lower(j) + i], self.n, false, false, &mut x, "solve_transpose")?;
+        Ok(x)
+    }
+}
+
+impl<T> UpperTriangular<T>
+where T: Float
+{
+    pub fn det(&self) -> T
+    {
+        self.diagonal().into_iter().fold(T::one(), |acc, x| acc * x)
+    }
+
+    // U * X = B
+    pub fn solve(&self, b: &Matrix<T>) -> Matrix<T>
+    {
+        self.try_solve(b).unwrap_or_else(|e| panic!("{}", e))
+    }
+
+    pub fn try_solve(&self, b: &Matrix<T>) -> Result<Matrix<T>, MatrixError>
+    {
+        let n = self.n;
+        let mut x = b.clone();
+        substitute(|i, j| self.data[offset_upper(i, n) + j - i], n, false, false, &mut x, "solve")?;
+        Ok(x)
+    }
+
+    // U^T * X = B
+    pub fn solve_transpose(&self, b: &Matrix<T>) -> Matrix<T>
+    {
+        self.try_solve_transpose(b).unwrap_or_else(|e| panic!("{}", e))
+    }
+
+    pub fn try_solve_transpose(&self, b: &Matrix<T>) -> Result<Matrix<T>, MatrixError>
+    {
+        let n = self.n;
+        let mut x = b.clone();
+        substitute(|i, j| self.data[offset_upper(j, n) + i - j], n, true, false, &mut x, "solve_transpose")?;
+        Ok(x)
+    }
+}
+
+// X----------X
+// kernels
+
+// start of row i in packed lower storage
+fn offset_lower(i: usize) -> usize
+{
+    i * (i + 1) / 2
+}
+
+// start of row i in packed upper storage of size n
+fn offset_upper(i: usize, n: usize) -> usize
+{
+    i * n - i * i.saturating_sub(1) / 2
+}
+
+// solves M * X = B in place for the triangular (n x n) M given entrywise by <at>, only the
+// triangle named by <lower> is ever read; a zero on the diagonal is reported as Singular
+fn substitute<T: Float>(at: impl Fn(usize, usize) -> T, n: usize, lower: bool, unit: bool, x: &mut Matrix<T>, op: &'static str) -> Result<(), MatrixError>
+{
+    if x.rows() != n
+    {
+        return Err(MatrixError::InnerDimMismatch { op, lhs: (n, n), rhs: x.shape() });
+    }
+
+    if !unit && (0..n).any(|i| at(i, i) == T::zero())
+    {
+        return Err(MatrixError::Singular { op });
+    }
+
+    let w = x.cols();
+    let xs = x.as_mut_slice();
+
+    for step in 0..n
+    {
+        let i = if lower { step } else { n - 1 - step };
+        let done = if lower { 0..i } else { (i + 1)..n };
+
+        for k in done
+        {
+            let m = at(i, k);
+            if m == T::zero() { continue; }
+            for c in 0..w { xs[i * w + c] = xs[i * w + c] - m * xs[k * w + c]; }
+        }
+
+        if !unit
+        {
+            let d = at(i, i);
+            for c in 0..w { xs[i * w + c] = xs[i * w + c] / d; }
+        }
+    }
+
+    Ok(())
+}
@@ -1,2 +1,11 @@
 pub mod arithmetic;
-pub mod linear;
\ No newline at end of file
+mod dispatch;
+pub mod gemm;
+pub mod index;
+pub mod linear;
+
+#[cfg(feature = "parallel")]
+pub mod parallel;
+
+#[cfg(feature = "simd")]
+pub mod simd;
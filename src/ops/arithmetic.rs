@@ -1,32 +1,217 @@
 use std::ops;
-use crate::core::matrix::Matrix;
+use crate::core::{matrix::Matrix, view::{MatrixView, MatrixViewMut}};
 use num_traits::Num;
+use crate::core::error::MatrixError;
+#[cfg(feature = "parallel")]
+use crate::ops::parallel;
+#[cfg(feature = "simd")]
+use crate::ops::simd;
 
-// ----------Arithmetic Operations----------//
+// ----------Fallible Arithmetic----------//
 
-// addition
-impl<T> ops::Add<&Matrix<T>> for &Matrix<T>
-where T: Num + Copy + ops::Add<Output = T>
+impl<T> Matrix<T>
+where T: Num + Copy
 {
-    type Output = Matrix<T>;
-
-    fn add(self, rhs: &Matrix<T>) -> Self::Output 
+    // fallible addition
+    pub fn try_add(&self, rhs: &Matrix<T>) -> Result<Matrix<T>, MatrixError>
     {
-        let lhs_rows = self.rows();
-        let lhs_cols = self.cols();
-        let rhs_rows = rhs.rows();
-        let rhs_cols = rhs.cols();
-
         // ensure that the dims match up
-        if lhs_rows != rhs_rows || lhs_cols != rhs_cols
+        self.check_same_shape(rhs, "Add")?;
+
+        #[cfg(feature = "parallel")]
+        if parallel::enabled::<T>(self.as_slice().len())
         {
-            panic!("Matrix::Add: Dimension mismatch! ( ({}, {}) vs ({}, {}) )", lhs_rows, lhs_cols, rhs_rows, rhs_cols);
+            let mut out = vec![T::zero(); self.as_slice().len()];
+            parallel::zip(self.as_slice(), rhs.as_slice(), &mut out, self.cols(), |a, b| a + b);
+            return Matrix::new(self.rows(), self.cols(), out);
+        }
+
+        #[cfg(feature = "simd")]
+        if simd::enabled::<T>()
+        {
+            let mut out = vec![T::zero(); self.as_slice().len()];
+            simd::binary(simd::Binary::Add, self.as_slice(), rhs.as_slice(), &mut out);
+            return Matrix::new(self.rows(), self.cols(), out);
         }
 
         let result = self.as_slice().iter().zip(rhs.as_slice().iter()).map(|(a, b)| *a + *b ).collect();
 
-        Matrix::new(lhs_rows, lhs_cols, result).expect("Matrix::Add: Result has incorrect dimensions!")
-        
+        Matrix::new(self.rows(), self.cols(), result)
+    }
+
+    // fallible subtraction
+    pub fn try_sub(&self, rhs: &Matrix<T>) -> Result<Matrix<T>, MatrixError>
+    {
+        // ensure that the dims match up
+        self.check_same_shape(rhs, "Sub")?;
+
+        #[cfg(feature = "parallel")]
+        if parallel::enabled::<T>(self.as_slice().len())
+        {
+            let mut out = vec![T::zero(); self.as_slice().len()];
+            parallel::zip(self.as_slice(), rhs.as_slice(), &mut out, self.cols(), |a, b| a - b);
+            return Matrix::new(self.rows(), self.cols(), out);
+        }
+
+        #[cfg(feature = "simd")]
+        if simd::enabled::<T>()
+        {
+            let mut out = vec![T::zero(); self.as_slice().len()];
+            simd::binary(simd::Binary::Sub, self.as_slice(), rhs.as_slice(), &mut out);
+            return Matrix::new(self.rows(), self.cols(), out);
+        }
+
+        let result = self.as_slice().iter().zip(rhs.as_slice().iter()).map(|(a, b)| *a - *b ).collect();
+
+        Matrix::new(self.rows(), self.cols(), result)
+    }
+
+    // fallible scalar div, errors on a zero divisor
+    pub fn try_div_scalar<U>(&self, rhs: U) -> Result<Matrix<T>, MatrixError>
+    where T: ops::Div<U, Output = T>, U: Num + Copy
+    {
+        // TODO: epsilon
+        // check 0-divisor
+        if rhs == (U::zero())
+        {
+            return Err(MatrixError::DivisionByZero { op: "Div" });
+        }
+
+        #[cfg(feature = "parallel")]
+        if parallel::enabled_scalar::<T, U>(self.as_slice().len())
+        {
+            let mut out = vec![T::zero(); self.as_slice().len()];
+            parallel::map_scalar(self.as_slice(), &mut out, self.cols(), rhs, |a, b| a / b);
+            return Matrix::new(self.rows(), self.cols(), out);
+        }
+
+        let result = self.as_slice().iter().map(|a| *a / rhs).collect();
+
+        Matrix::new(self.rows(), self.cols(), result)
+    }
+
+    // out = self + rhs, reuses out's buffer
+    pub fn add_into(&self, rhs: &Matrix<T>, out: &mut Matrix<T>)
+    {
+        self.try_add_into(rhs, out).unwrap_or_else(|e| panic!("{}", e))
+    }
+
+    pub fn try_add_into(&self, rhs: &Matrix<T>, out: &mut Matrix<T>) -> Result<(), MatrixError>
+    {
+        self.zip_into(rhs, out, "add_into", |a, b| a + b)
+    }
+
+    // out = self - rhs, reuses out's buffer
+    pub fn sub_into(&self, rhs: &Matrix<T>, out: &mut Matrix<T>)
+    {
+        self.try_sub_into(rhs, out).unwrap_or_else(|e| panic!("{}", e))
+    }
+
+    pub fn try_sub_into(&self, rhs: &Matrix<T>, out: &mut Matrix<T>) -> Result<(), MatrixError>
+    {
+        self.zip_into(rhs, out, "sub_into", |a, b| a - b)
+    }
+
+    // out = self * scalar, reuses out's buffer
+    pub fn mul_scalar_into<U>(&self, rhs: U, out: &mut Matrix<T>)
+    where T: ops::Mul<U, Output = T>, U: Num + Copy
+    {
+        self.try_mul_scalar_into(rhs, out).unwrap_or_else(|e| panic!("{}", e))
+    }
+
+    pub fn try_mul_scalar_into<U>(&self, rhs: U, out: &mut Matrix<T>) -> Result<(), MatrixError>
+    where T: ops::Mul<U, Output = T>, U: Num + Copy
+    {
+        out.check_out_shape(self.shape(), "mul_scalar_into")?;
+
+        for (o, a) in out.as_mut_slice().iter_mut().zip(self.as_slice().iter())
+        {
+            *o = *a * rhs;
+        }
+
+        Ok(())
+    }
+
+    // out = self / scalar, reuses out's buffer
+    pub fn div_scalar_into<U>(&self, rhs: U, out: &mut Matrix<T>)
+    where T: ops::Div<U, Output = T>, U: Num + Copy
+    {
+        self.try_div_scalar_into(rhs, out).unwrap_or_else(|e| panic!("{}", e))
+    }
+
+    pub fn try_div_scalar_into<U>(&self, rhs: U, out: &mut Matrix<T>) -> Result<(), MatrixError>
+    where T: ops::Div<U, Output = T>, U: Num + Copy
+    {
+        // check 0-divisor
+        if rhs == (U::zero())
+        {
+            return Err(MatrixError::DivisionByZero { op: "div_scalar_into" });
+        }
+
+        out.check_out_shape(self.shape(), "div_scalar_into")?;
+
+        for (o, a) in out.as_mut_slice().iter_mut().zip(self.as_slice().iter())
+        {
+            *o = *a / rhs;
+        }
+
+        Ok(())
+    }
+
+    // element-wise combinator shared by add_into / sub_into
+    fn zip_into(&self, rhs: &Matrix<T>, out: &mut Matrix<T>, op: &'static str, func: impl Fn(T, T) -> T) -> Result<(), MatrixError>
+    {
+        self.check_same_shape(rhs, op)?;
+        out.check_out_shape(self.shape(), op)?;
+
+        for (o, (a, b)) in out.as_mut_slice().iter_mut().zip(self.as_slice().iter().zip(rhs.as_slice().iter()))
+        {
+            *o = func(*a, *b);
+        }
+
+        Ok(())
+    }
+
+    // fallible inplace addition
+    pub fn try_add_assign(&mut self, rhs: &Matrix<T>) -> Result<(), MatrixError>
+    where T: ops::AddAssign
+    {
+        self.check_same_shape(rhs, "AddAssign")?;
+
+        for (l, r) in self.as_mut_slice().iter_mut().zip(rhs.as_slice().iter())
+        {
+            *l += *r;
+        }
+
+        Ok(())
+    }
+
+    // fallible inplace subtraction
+    pub fn try_sub_assign(&mut self, rhs: &Matrix<T>) -> Result<(), MatrixError>
+    where T: ops::SubAssign
+    {
+        self.check_same_shape(rhs, "SubAssign")?;
+
+        for (l, r) in self.as_mut_slice().iter_mut().zip(rhs.as_slice().iter())
+        {
+            *l -= *r;
+        }
+
+        Ok(())
+    }
+}
+
+// ----------Arithmetic Operations----------//
+
+// addition
+impl<T> ops::Add<&Matrix<T>> for &Matrix<T>
+where T: Num + Copy + ops::Add<Output = T>
+{
+    type Output = Matrix<T>;
+
+    fn add(self, rhs: &Matrix<T>) -> Self::Output
+    {
+        self.try_add(rhs).unwrap_or_else(|e| panic!("{}", e))
     }
 }
 
@@ -36,23 +221,9 @@ where T: Num + Copy + ops::Sub<Output = T>
 {
     type Output = Matrix<T>;
 
-    fn sub(self, rhs: &Matrix<T>) -> Self::Output 
+    fn sub(self, rhs: &Matrix<T>) -> Self::Output
     {
-        let lhs_rows = self.rows();
-        let lhs_cols = self.cols();
-        let rhs_rows = rhs.rows();
-        let rhs_cols = rhs.cols();
-
-        // ensure that the dims match up
-        if lhs_rows != rhs_rows || lhs_cols != rhs_cols
-        {
-            panic!("Matrix::Sub: Dimension mismatch! ( ({}, {}) vs ({}, {}) )", lhs_rows, lhs_cols, rhs_rows, rhs_cols);
-        }
-
-        let result = self.as_slice().iter().zip(rhs.as_slice().iter()).map(|(a, b)| *a - *b ).collect();
-
-        Matrix::new(lhs_rows, lhs_cols, result).expect("Matrix::Sub: Result has incorrect dimensions!")
-        
+        self.try_sub(rhs).unwrap_or_else(|e| panic!("{}", e))
     }
 }
 
@@ -62,11 +233,27 @@ where T: Num + Copy + ops::Mul<U, Output = T>, U: Num + Copy,
 {
     type Output = Matrix<T>;
 
-    fn mul(self, rhs: U) -> Self::Output 
+    fn mul(self, rhs: U) -> Self::Output
     {
         let lhs_rows = self.rows();
         let lhs_cols = self.cols();
 
+        #[cfg(feature = "parallel")]
+        if parallel::enabled_scalar::<T, U>(self.as_slice().len())
+        {
+            let mut out = vec![T::zero(); self.as_slice().len()];
+            parallel::map_scalar(self.as_slice(), &mut out, lhs_cols, rhs, |a, b| a * b);
+            return Matrix::new(lhs_rows, lhs_cols, out).expect("Matrix::Mul: Result has incorrect dimensions!");
+        }
+
+        #[cfg(feature = "simd")]
+        if simd::enabled_scalar::<T, U>()
+        {
+            let mut out = vec![T::zero(); self.as_slice().len()];
+            simd::scale(self.as_slice(), rhs, &mut out);
+            return Matrix::new(lhs_rows, lhs_cols, out).expect("Matrix::Mul: Result has incorrect dimensions!");
+        }
+
         let result = self.as_slice().iter().map(|a| *a * rhs).collect();
 
         Matrix::new(lhs_rows, lhs_cols, result).expect("Matrix::Mul: Result has incorrect dimensions!")
@@ -80,7 +267,7 @@ where T: Num + Copy + ops::Mul<U, Output = T>, U: Num + Copy,
 // {
 //     type Output = Matrix<T>;
 
-//     fn mul(self, rhs: &Matrix<T>) -> Self::Output 
+//     fn mul(self, rhs: &Matrix<T>) -> Self::Output
 //     {
 //         rhs * self
 //     }
@@ -92,66 +279,164 @@ where T: Num + Copy + ops::Div<U, Output = T>, U: Num + Copy
 {
     type Output = Matrix<T>;
 
-    fn div(self, rhs: U) -> Self::Output 
+    fn div(self, rhs: U) -> Self::Output
+    {
+        self.try_div_scalar(rhs).unwrap_or_else(|e| panic!("{}", e))
+    }
+}
+
+// inplace add_assign
+impl<T> ops::AddAssign<&Matrix<T>> for Matrix<T>
+where T: Num + Copy + ops::AddAssign,
+{
+    fn add_assign(&mut self, rhs: &Matrix<T>)
+    {
+        self.try_add_assign(rhs).unwrap_or_else(|e| panic!("{}", e))
+    }
+}
+
+// inplace sub_assign
+impl<T> ops::SubAssign<&Matrix<T>> for Matrix<T>
+where T: Num + Copy + ops::SubAssign,
+{
+    fn sub_assign(&mut self, rhs: &Matrix<T>)
+    {
+        self.try_sub_assign(rhs).unwrap_or_else(|e| panic!("{}", e))
+    }
+}
+
+// ----------View Arithmetic----------//
+
+impl<T> MatrixView<'_, T>
+where T: Num + Copy
+{
+    // fallible scalar div, errors on a zero divisor
+    pub fn try_div_scalar<U>(&self, rhs: U) -> Result<Matrix<T>, MatrixError>
+    where T: ops::Div<U, Output = T>, U: Num + Copy
     {
-        // TODO: epsilon
         // check 0-divisor
         if rhs == (U::zero())
         {
-            panic!("Matrix::Div: Cannot divide by zero!");
+            return Err(MatrixError::DivisionByZero { op: "Div" });
         }
 
-        let lhs_rows = self.rows();
-        let lhs_cols = self.cols();
+        let result = self.iter().map(|a| *a / rhs).collect();
 
-        let result = self.as_slice().iter().map(|a| *a / rhs).collect();
+        Matrix::new(self.rows(), self.cols(), result)
+    }
+}
 
-        Matrix::new(lhs_rows, lhs_cols, result).expect("Matrix::Div: Result has incorrect dimensions!") 
+// addition
+impl<T> ops::Add<&MatrixView<'_, T>> for &MatrixView<'_, T>
+where T: Num + Copy
+{
+    type Output = Matrix<T>;
+
+    fn add(self, rhs: &MatrixView<'_, T>) -> Self::Output
+    {
+        self.try_add(rhs).unwrap_or_else(|e| panic!("{}", e))
     }
 }
 
-// inplace add_assign
-impl<T> ops::AddAssign<&Matrix<T>> for Matrix<T>
-where T: Num + Copy + ops::AddAssign,
+// subtraction
+impl<T> ops::Sub<&MatrixView<'_, T>> for &MatrixView<'_, T>
+where T: Num + Copy
 {
-    fn add_assign(&mut self, rhs: &Matrix<T>) 
+    type Output = Matrix<T>;
+
+    fn sub(self, rhs: &MatrixView<'_, T>) -> Self::Output
     {
-        let lhs_rows = self.rows();
-        let lhs_cols = self.cols();
-        let rhs_rows = rhs.rows();
-        let rhs_cols = rhs.cols();
+        self.try_sub(rhs).unwrap_or_else(|e| panic!("{}", e))
+    }
+}
 
-        if lhs_rows != rhs_rows || lhs_cols != rhs_cols 
-        {
-            panic!("Matrix::AddAssign: Dimension mismatch!");
-        }
+// mixed addition / subtraction with an owned Matrix
+impl<T> ops::Add<&Matrix<T>> for &MatrixView<'_, T>
+where T: Num + Copy
+{
+    type Output = Matrix<T>;
 
-        for (l, r) in self.as_mut_slice().iter_mut().zip(rhs.as_slice().iter()) 
-        {
-            *l += *r;
-        }
-    }   
+    fn add(self, rhs: &Matrix<T>) -> Self::Output
+    {
+        self.try_add(rhs).unwrap_or_else(|e| panic!("{}", e))
+    }
 }
 
-// inplace sub_assign
-impl<T> ops::SubAssign<&Matrix<T>> for Matrix<T>
-where T: Num + Copy + ops::SubAssign,
+impl<T> ops::Add<&MatrixView<'_, T>> for &Matrix<T>
+where T: Num + Copy
 {
-    fn sub_assign(&mut self, rhs: &Matrix<T>) 
-    {    
-        let lhs_rows = self.rows();
-        let lhs_cols = self.cols();
-        let rhs_rows = rhs.rows();
-        let rhs_cols = rhs.cols();
+    type Output = Matrix<T>;
 
-        if lhs_rows != rhs_rows || lhs_cols != rhs_cols 
-        {
-            panic!("Matrix::SubAssign: Dimension mismatch!");
-        }
+    fn add(self, rhs: &MatrixView<'_, T>) -> Self::Output
+    {
+        self.view().try_add(rhs).unwrap_or_else(|e| panic!("{}", e))
+    }
+}
 
-        for (l, r) in self.as_mut_slice().iter_mut().zip(rhs.as_slice().iter()) 
-        {
-            *l -= *r;
-        }
-    }   
+impl<T> ops::Sub<&Matrix<T>> for &MatrixView<'_, T>
+where T: Num + Copy
+{
+    type Output = Matrix<T>;
+
+    fn sub(self, rhs: &Matrix<T>) -> Self::Output
+    {
+        self.try_sub(rhs).unwrap_or_else(|e| panic!("{}", e))
+    }
+}
+
+impl<T> ops::Sub<&MatrixView<'_, T>> for &Matrix<T>
+where T: Num + Copy
+{
+    type Output = Matrix<T>;
+
+    fn sub(self, rhs: &MatrixView<'_, T>) -> Self::Output
+    {
+        self.view().try_sub(rhs).unwrap_or_else(|e| panic!("{}", e))
+    }
+}
+
+// scalar mul (view * scalar)
+impl<T, U> ops::Mul<U> for &MatrixView<'_, T>
+where T: Num + Copy + ops::Mul<U, Output = T>, U: Num + Copy,
+{
+    type Output = Matrix<T>;
+
+    fn mul(self, rhs: U) -> Self::Output
+    {
+        let result = self.iter().map(|a| *a * rhs).collect();
+
+        Matrix::new(self.rows(), self.cols(), result).expect("MatrixView::Mul: Result has incorrect dimensions!")
+    }
+}
+
+// scalar div (view / scalar)
+impl<T, U> ops::Div<U> for &MatrixView<'_, T>
+where T: Num + Copy + ops::Div<U, Output = T>, U: Num + Copy
+{
+    type Output = Matrix<T>;
+
+    fn div(self, rhs: U) -> Self::Output
+    {
+        self.try_div_scalar(rhs).unwrap_or_else(|e| panic!("{}", e))
+    }
+}
+
+// inplace add_assign into a mutable view
+impl<T> ops::AddAssign<&MatrixView<'_, T>> for MatrixViewMut<'_, T>
+where T: Num + Copy
+{
+    fn add_assign(&mut self, rhs: &MatrixView<'_, T>)
+    {
+        self.try_add_assign(rhs).unwrap_or_else(|e| panic!("{}", e))
+    }
+}
+
+// inplace sub_assign into a mutable view
+impl<T> ops::SubAssign<&MatrixView<'_, T>> for MatrixViewMut<'_, T>
+where T: Num + Copy
+{
+    fn sub_assign(&mut self, rhs: &MatrixView<'_, T>)
+    {
+        self.try_sub_assign(rhs).unwrap_or_else(|e| panic!("{}", e))
+    }
 }
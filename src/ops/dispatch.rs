@@ -0,0 +1,76 @@
+use std::any::TypeId;
+use std::marker::PhantomData;
+
+//----------Element Type Dispatch----------//
+
+// the public bounds stay generic (Num + Copy + ...), fast paths that only suit some element
+// types are picked here from the concrete type instead, the checks fold to constants
+
+// TypeId::of without the 'static bound
+// TypeId ignores lifetimes, so erasing the lifetime of the trait object below is sound
+trait ErasedType
+{
+    fn erased_type_id(&self) -> TypeId
+    where Self: 'static;
+}
+
+impl<T: ?Sized> ErasedType for PhantomData<T>
+{
+    fn erased_type_id(&self) -> TypeId
+    where Self: 'static
+    {
+        TypeId::of::<T>()
+    }
+}
+
+fn type_id_of<T: ?Sized>() -> TypeId
+{
+    let marker = PhantomData::<T>;
+    let erased: &dyn ErasedType = &marker;
+
+    // SAFETY: only the trait object lifetime changes, erased_type_id never reads through self
+    let erased: &(dyn ErasedType + 'static) = unsafe { std::mem::transmute(erased) };
+
+    erased.erased_type_id()
+}
+
+// true if T is exactly U
+pub(crate) fn is<T, U: 'static>() -> bool
+{
+    type_id_of::<T>() == TypeId::of::<U>()
+}
+
+// true for the primitive integer / float types, all of which are Send + Sync
+#[cfg(feature = "parallel")]
+pub(crate) fn is_primitive<T>() -> bool
+{
+    let id = type_id_of::<T>();
+
+    [
+        TypeId::of::<f32>(), TypeId::of::<f64>(),
+        TypeId::of::<i8>(), TypeId::of::<i16>(), TypeId::of::<i32>(), TypeId::of::<i64>(), TypeId::of::<i128>(), TypeId::of::<isize>(),
+        TypeId::of::<u8>(), TypeId::of::<u16>(), TypeId::of::<u32>(), TypeId::of::<u64>(), TypeId::of::<u128>(), TypeId::of::<usize>()
+    ].contains(&id)
+}
+
+// s as a slice of U, None unless T is U
+#[cfg(feature = "simd")]
+pub(crate) fn cast<T, U: 'static>(s: &[T]) -> Option<&[U]>
+{
+    // SAFETY: T and U are the same type
+    is::<T, U>().then(|| unsafe { &*(s as *const [T] as *const [U]) })
+}
+
+#[cfg(feature = "simd")]
+pub(crate) fn cast_mut<T, U: 'static>(s: &mut [T]) -> Option<&mut [U]>
+{
+    // SAFETY: T and U are the same type
+    is::<T, U>().then(|| unsafe { &mut *(s as *mut [T] as *mut [U]) })
+}
+
+#[cfg(feature = "simd")]
+pub(crate) fn cast_value<T: Copy, U: Copy + 'static>(x: T) -> Option<U>
+{
+    // SAFETY: T and U are the same type
+    is::<T, U>().then(|| unsafe { std::mem::transmute_copy(&x) })
+}
@@ -0,0 +1,350 @@
+use std::ops::AddAssign;
+use crate::core::{error::MatrixError, matrix::Matrix, view::{AsView, MatrixView}};
+use num_traits::Num;
+use crate::ops::dispatch;
+#[cfg(feature = "parallel")]
+use crate::ops::parallel;
+#[cfg(feature = "simd")]
+use crate::ops::simd;
+
+//----------General Matrix Multiply----------//
+
+// BLAS-style operand flag, op(X) = X or X^T
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Transpose
+{
+    No,
+    Yes
+}
+
+impl Transpose
+{
+    // applies the flag to a view, transposes are free (stride swap)
+    fn apply<'a, T>(self, view: MatrixView<'a, T>) -> MatrixView<'a, T>
+    where T: Num
+    {
+        match self
+        {
+            Transpose::No => view,
+            Transpose::Yes => view.t()
+        }
+    }
+}
+
+// tiling parameters for the blocked kernel
+// MR x NR is the register tile, KC / MC / NC size the packed panels for L1 / L2 / L3
+const MR: usize = 8;
+const NR: usize = 4;
+const KC: usize = 256;
+const MC: usize = 64;
+const NC: usize = 1024;
+
+// below this many multiply-adds, packing costs more than it saves
+const BLOCKED_MIN_WORK: usize = 64 * 64 * 64;
+
+// shared kernel entry point: out += alpha * (a * b)
+// out is row-major (a.rows x b.cols), shapes must already be validated
+pub(crate) fn gemm_kernel<T>(alpha: T, a: &MatrixView<'_, T>, b: &MatrixView<'_, T>, out: &mut [T])
+where T: Num + Copy + AddAssign
+{
+    #[cfg(feature = "parallel")]
+    if parallel::enabled::<T>(out.len())
+    {
+        parallel::gemm(alpha, a, b, out, gemm_serial);
+        return;
+    }
+
+    gemm_serial(alpha, a, b, out);
+}
+
+// f32 / f64 get the packed, register-blocked kernel once the product is big enough,
+// every other type keeps the plain i-k-j loop, so integer sums (and where a debug
+// overflow panic fires) follow the same order at every size
+// with simd, small f32 / f64 products run the i-k-j loop as vectorised row axpys
+fn gemm_serial<T>(alpha: T, a: &MatrixView<'_, T>, b: &MatrixView<'_, T>, out: &mut [T])
+where T: Num + Copy + AddAssign
+{
+    let (m, k_dim) = a.shape();
+    let n = b.cols();
+    let float = dispatch::is::<T, f32>() || dispatch::is::<T, f64>();
+
+    if float && m * n * k_dim >= BLOCKED_MIN_WORK
+    {
+        gemm_blocked(alpha, a, b, out);
+    }
+    else
+    {
+        #[cfg(feature = "simd")]
+        if float && b.col_stride() == 1
+        {
+            simd::gemm(alpha, a, b, out);
+            return;
+        }
+
+        gemm_naive(alpha, a, b, out);
+    }
+}
+
+// plain i-k-j loop, used for small products
+fn gemm_naive<T>(alpha: T, a: &MatrixView<'_, T>, b: &MatrixView<'_, T>, out: &mut [T])
+where T: Num + Copy + AddAssign
+{
+    let (m, k_dim) = a.shape();
+    let n = b.cols();
+
+    for i in 0..m
+    {
+        let out_row = &mut out[i * n..(i + 1) * n];
+
+        for k in 0..k_dim
+        {
+            // SAFETY: i < m and k < k_dim by the loop bounds
+            let lhs_val = alpha * unsafe { *a.get_unchecked(i, k) };
+
+            for (j, res) in out_row.iter_mut().enumerate()
+            {
+                // SAFETY: k < b.rows (== k_dim, validated by caller) and j < n
+                *res += lhs_val * unsafe { *b.get_unchecked(k, j) };
+            }
+        }
+    }
+}
+
+// cache-blocked kernel (Goto / BLIS loop order), f32 / f64 only
+// panels of a and b are packed into contiguous buffers, so strided views
+// and lazy transposes cost nothing extra once packed
+fn gemm_blocked<T>(alpha: T, a: &MatrixView<'_, T>, b: &MatrixView<'_, T>, out: &mut [T])
+where T: Num + Copy + AddAssign
+{
+    let (m, k_dim) = a.shape();
+    let n = b.cols();
+
+    let mut a_pack = vec![T::zero(); MC.div_ceil(MR) * MR * KC];
+    let mut b_pack = vec![T::zero(); NC.div_ceil(NR) * NR * KC];
+
+    for jc in (0..n).step_by(NC)
+    {
+        let nc = NC.min(n - jc);
+
+        for pc in (0..k_dim).step_by(KC)
+        {
+            let kc = KC.min(k_dim - pc);
+
+            pack_b(b, pc, kc, jc, nc, &mut b_pack);
+
+            for ic in (0..m).step_by(MC)
+            {
+                let mc = MC.min(m - ic);
+
+                pack_a(alpha, a, ic, mc, pc, kc, &mut a_pack);
+
+                for jr in (0..nc).step_by(NR)
+                {
+                    let nr = NR.min(nc - jr);
+                    let b_panel = &b_pack[jr * kc..(jr + NR) * kc];
+
+                    for ir in (0..mc).step_by(MR)
+                    {
+                        let mr = MR.min(mc - ir);
+                        let a_panel = &a_pack[ir * kc..(ir + MR) * kc];
+
+                        let acc = micro_kernel(a_panel, b_panel);
+
+                        // write back only the valid part of edge tiles
+                        for (ii, acc_row) in acc.iter().enumerate().take(mr)
+                        {
+                            let row_start = (ic + ir + ii) * n + jc + jr;
+
+                            for (res, val) in out[row_start..row_start + nr].iter_mut().zip(acc_row.iter())
+                            {
+                                *res += *val;
+                            }
+                        }
+                    }
+                }
+            }
+        }
+    }
+}
+
+// packs alpha * a[ic..ic+mc, pc..pc+kc] into MR-row panels, k-major within a panel
+// edge panels are zero padded so the micro-kernel never branches
+fn pack_a<T>(alpha: T, a: &MatrixView<'_, T>, ic: usize, mc: usize, pc: usize, kc: usize, buf: &mut [T])
+where T: Num + Copy
+{
+    for (panel, ir) in (0..mc).step_by(MR).enumerate()
+    {
+        let dst = &mut buf[panel * MR * kc..(panel + 1) * MR * kc];
+
+        for p in 0..kc
+        {
+            for ii in 0..MR
+            {
+                dst[p * MR + ii] = if ir + ii < mc
+                {
+                    // SAFETY: ic + ir + ii < m and pc + p < k_dim by construction
+                    alpha * unsafe { *a.get_unchecked(ic + ir + ii, pc + p) }
+                }
+                else
+                {
+                    T::zero()
+                };
+            }
+        }
+    }
+}
+
+// packs b[pc..pc+kc, jc..jc+nc] into NR-col panels, k-major within a panel
+fn pack_b<T>(b: &MatrixView<'_, T>, pc: usize, kc: usize, jc: usize, nc: usize, buf: &mut [T])
+where T: Num + Copy
+{
+    for (panel, jr) in (0..nc).step_by(NR).enumerate()
+    {
+        let dst = &mut buf[panel * NR * kc..(panel + 1) * NR * kc];
+
+        for p in 0..kc
+        {
+            for jj in 0..NR
+            {
+                dst[p * NR + jj] = if jr + jj < nc
+                {
+                    // SAFETY: pc + p < k_dim and jc + jr + jj < n by construction
+                    unsafe { *b.get_unchecked(pc + p, jc + jr + jj) }
+                }
+                else
+                {
+                    T::zero()
+                };
+            }
+        }
+    }
+}
+
+// MR x NR register tile: rank-1 updates over the packed k dimension
+// fixed-size loops let the compiler keep acc in registers and vectorise for f32 / f64
+#[inline(always)]
+fn micro_kernel<T>(a_panel: &[T], b_panel: &[T]) -> [[T; NR]; MR]
+where T: Num + Copy + AddAssign
+{
+    let mut acc = [[T::zero(); NR]; MR];
+
+    for (a_col, b_row) in a_panel.chunks_exact(MR).zip(b_panel.chunks_exact(NR))
+    {
+        for (acc_row, &a_val) in acc.iter_mut().zip(a_col.iter())
+        {
+            for (res, &b_val) in acc_row.iter_mut().zip(b_row.iter())
+            {
+                *res += a_val * b_val;
+            }
+        }
+    }
+
+    acc
+}
+
+impl<T> Matrix<T>
+where T: Num + Copy + AddAssign
+{
+    // c = alpha * op(a) * op(b) + beta * c
+    pub fn gemm(alpha: T, a: &impl AsView<T>, trans_a: Transpose, b: &impl AsView<T>, trans_b: Transpose, beta: T, c: &mut Matrix<T>)
+    {
+        Self::try_gemm(alpha, a, trans_a, b, trans_b, beta, c).unwrap_or_else(|e| panic!("{}", e))
+    }
+
+    // fallible gemm, c is left untouched on a shape error
+    pub fn try_gemm(alpha: T, a: &impl AsView<T>, trans_a: Transpose, b: &impl AsView<T>, trans_b: Transpose, beta: T, c: &mut Matrix<T>) -> Result<(), MatrixError>
+    {
+        Self::gemm_op("gemm", alpha, a.as_view(), trans_a, b.as_view(), trans_b, beta, c)
+    }
+
+    // gemm body, <op> names the public entry point in errors
+    #[allow(clippy::too_many_arguments)]
+    fn gemm_op(op: &'static str, alpha: T, a: MatrixView<'_, T>, trans_a: Transpose, b: MatrixView<'_, T>, trans_b: Transpose, beta: T, c: &mut Matrix<T>) -> Result<(), MatrixError>
+    {
+        let a = trans_a.apply(a);
+        let b = trans_b.apply(b);
+
+        // check size
+        if a.cols() != b.rows()
+        {
+            return Err(MatrixError::InnerDimMismatch { op, lhs: a.shape(), rhs: b.shape() });
+        }
+
+        c.check_out_shape((a.rows(), b.cols()), op)?;
+
+        // BLAS convention: beta == 0 overwrites c, so stale NaNs don't leak through
+        let out = c.as_mut_slice();
+
+        if beta == T::zero()
+        {
+            out.iter_mut().for_each(|x| *x = T::zero());
+        }
+        else if beta != T::one()
+        {
+            out.iter_mut().for_each(|x| *x = *x * beta);
+        }
+
+        gemm_kernel(alpha, &a, &b, out);
+
+        Ok(())
+    }
+
+    // out = self * other, reuses out's buffer
+    pub fn matmul_into(&self, other: &impl AsView<T>, out: &mut Matrix<T>)
+    {
+        self.try_matmul_into(other, out).unwrap_or_else(|e| panic!("{}", e))
+    }
+
+    pub fn try_matmul_into(&self, other: &impl AsView<T>, out: &mut Matrix<T>) -> Result<(), MatrixError>
+    {
+        Self::gemm_op("matmul", T::one(), self.view(), Transpose::No, other.as_view(), Transpose::No, T::zero(), out)
+    }
+
+    // self^T * other
+    pub fn matmul_tn(&self, other: &impl AsView<T>) -> Matrix<T>
+    {
+        self.try_matmul_tn(other).unwrap_or_else(|e| panic!("{}", e))
+    }
+
+    pub fn try_matmul_tn(&self, other: &impl AsView<T>) -> Result<Matrix<T>, MatrixError>
+    {
+        let mut out = Matrix::zeroes(self.cols(), other.as_view().cols())?;
+        self.try_matmul_tn_into(other, &mut out)?;
+        Ok(out)
+    }
+
+    // self * other^T
+    pub fn matmul_nt(&self, other: &impl AsView<T>) -> Matrix<T>
+    {
+        self.try_matmul_nt(other).unwrap_or_else(|e| panic!("{}", e))
+    }
+
+    pub fn try_matmul_nt(&self, other: &impl AsView<T>) -> Result<Matrix<T>, MatrixError>
+    {
+        let mut out = Matrix::zeroes(self.rows(), other.as_view().rows())?;
+        self.try_matmul_nt_into(other, &mut out)?;
+        Ok(out)
+    }
+
+    // out = self^T * other, reuses out's buffer
+    pub fn matmul_tn_into(&self, other: &impl AsView<T>, out: &mut Matrix<T>)
+    {
+        self.try_matmul_tn_into(other, out).unwrap_or_else(|e| panic!("{}", e))
+    }
+
+    pub fn try_matmul_tn_into(&self, other: &impl AsView<T>, out: &mut Matrix<T>) -> Result<(), MatrixError>
+    {
+        Self::gemm_op("matmul_tn", T::one(), self.view(), Transpose::Yes, other.as_view(), Transpose::No, T::zero(), out)
+    }
+
+    // out = self * other^T, reuses out's buffer
+    pub fn matmul_nt_into(&self, other: &impl AsView<T>, out: &mut Matrix<T>)
+    {
+        self.try_matmul_nt_into(other, out).unwrap_or_else(|e| panic!("{}", e))
+    }
+
+    pub fn try_matmul_nt_into(&self, other: &impl AsView<T>, out: &mut Matrix<T>) -> Result<(), MatrixError>
+    {
+        Self::gemm_op("matmul_nt", T::one(), self.view(), Transpose::No, other.as_view(), Transpose::Yes, T::zero(), out)
+    }
+}
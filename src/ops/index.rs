@@ -0,0 +1,75 @@
+use std::ops;
+use crate::core::{matrix::Matrix, view::{MatrixView, MatrixViewMut}};
+use num_traits::Num;
+
+//----------Element Indexing----------//
+
+// m[(i, j)]
+impl<T> ops::Index<(usize, usize)> for Matrix<T>
+where T: Num
+{
+    type Output = T;
+
+    fn index(&self, (i, j): (usize, usize)) -> &Self::Output
+    {
+        self.check_index(i, j, "Index");
+
+        // SAFETY: bounds checked above
+        unsafe { self.get_unchecked(i, j) }
+    }
+}
+
+// m[(i, j)] = val
+impl<T> ops::IndexMut<(usize, usize)> for Matrix<T>
+where T: Num
+{
+    fn index_mut(&mut self, (i, j): (usize, usize)) -> &mut Self::Output
+    {
+        self.check_index(i, j, "IndexMut");
+
+        // SAFETY: bounds checked above
+        unsafe { self.get_unchecked_mut(i, j) }
+    }
+}
+
+// view[(i, j)]
+impl<T> ops::Index<(usize, usize)> for MatrixView<'_, T>
+where T: Num
+{
+    type Output = T;
+
+    fn index(&self, (i, j): (usize, usize)) -> &Self::Output
+    {
+        self.check_index(i, j, "Index");
+
+        // SAFETY: bounds checked above
+        unsafe { self.get_unchecked(i, j) }
+    }
+}
+
+impl<T> ops::Index<(usize, usize)> for MatrixViewMut<'_, T>
+where T: Num
+{
+    type Output = T;
+
+    fn index(&self, (i, j): (usize, usize)) -> &Self::Output
+    {
+        self.check_index(i, j, "Index");
+
+        // SAFETY: bounds checked above
+        unsafe { self.get_unchecked(i, j) }
+    }
+}
+
+// view[(i, j)] = val
+impl<T> ops::IndexMut<(usize, usize)> for MatrixViewMut<'_, T>
+where T: Num
+{
+    fn index_mut(&mut self, (i, j): (usize, usize)) -> &mut Self::Output
+    {
+        self.check_index(i, j, "IndexMut");
+
+        // SAFETY: bounds checked above
+        unsafe { self.get_unchecked_mut(i, j) }
+    }
+}
@@ -1,5 +1,5 @@
 use std::ops;
-use crate::core::matrix::Matrix;
+use crate::core::{matrix::Matrix, view::MatrixView};
 use num_traits::Num;
 
 
@@ -50,4 +50,39 @@ where T: Num + Copy + ops::AddAssign
 
         self.matmul(rhs)
     }
-}
\ No newline at end of file
+}
+
+// view matmul
+impl<T> ops::Mul<&MatrixView<'_, T>> for &MatrixView<'_, T>
+where T: Num + Copy + ops::AddAssign
+{
+    type Output = Matrix<T>;
+
+    fn mul(self, rhs: &MatrixView<'_, T>) -> Self::Output
+    {
+        self.matmul(rhs)
+    }
+}
+
+// mixed matmul, e.g. &w.t_view() * &x
+impl<T> ops::Mul<&Matrix<T>> for &MatrixView<'_, T>
+where T: Num + Copy + ops::AddAssign
+{
+    type Output = Matrix<T>;
+
+    fn mul(self, rhs: &Matrix<T>) -> Self::Output
+    {
+        self.matmul(rhs)
+    }
+}
+
+impl<T> ops::Mul<&MatrixView<'_, T>> for &Matrix<T>
+where T: Num + Copy + ops::AddAssign
+{
+    type Output = Matrix<T>;
+
+    fn mul(self, rhs: &MatrixView<'_, T>) -> Self::Output
+    {
+        self.matmul(rhs)
+    }
+}
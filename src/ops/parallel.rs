@@ -0,0 +1,279 @@
+use std::sync::{Arc, RwLock};
+use std::sync::atomic::{AtomicUsize, Ordering};
+use rayon::prelude::*;
+use rayon::{ThreadPool, ThreadPoolBuildError, ThreadPoolBuilder};
+use num_traits::Num;
+use crate::core::{matrix::Matrix, view::MatrixView};
+use crate::ops::dispatch;
+
+//----------Parallel Execution (feature = "parallel")----------//
+
+// with this feature, matmul / gemm, component_mul, sum and the Matrix +, -, * scalar, / scalar
+// operators split their work over row chunks once the output has at least parallel_threshold()
+// elements, smaller inputs stay on the serial path
+// their bounds don't ask for Send + Sync, so only the primitive number types (known to be
+// Send + Sync) take the parallel path, closures need par_apply / par_apply_inplace
+
+// output element count below which the serial path is used
+pub const DEFAULT_PARALLEL_THRESHOLD: usize = 1 << 14;
+
+static THRESHOLD: AtomicUsize = AtomicUsize::new(DEFAULT_PARALLEL_THRESHOLD);
+
+// crate-local pool, None means the rayon global pool
+static POOL: RwLock<Option<Arc<ThreadPool>>> = RwLock::new(None);
+
+// runs the parallel paths on a dedicated pool with <n> threads
+// n == 0 drops the dedicated pool and falls back to the rayon global pool
+pub fn set_num_threads(n: usize) -> Result<(), ThreadPoolBuildError>
+{
+    let pool = if n == 0 { None } else { Some(Arc::new(ThreadPoolBuilder::new().num_threads(n).build()?)) };
+
+    *POOL.write().unwrap_or_else(|e| e.into_inner()) = pool;
+
+    Ok(())
+}
+
+// number of threads the parallel paths will use
+pub fn num_threads() -> usize
+{
+    install(rayon::current_num_threads)
+}
+
+// minimum element count (of the output) before an op goes parallel
+pub fn set_parallel_threshold(elems: usize)
+{
+    THRESHOLD.store(elems, Ordering::Relaxed);
+}
+
+pub fn parallel_threshold() -> usize
+{
+    THRESHOLD.load(Ordering::Relaxed)
+}
+
+fn install<R: Send>(op: impl FnOnce() -> R + Send) -> R
+{
+    let pool = POOL.read().unwrap_or_else(|e| e.into_inner()).clone();
+
+    match pool
+    {
+        Some(pool) => pool.install(op),
+        None => op()
+    }
+}
+
+// true if an op producing <elems> outputs of type T should take the parallel path
+pub(crate) fn enabled<T>(elems: usize) -> bool
+{
+    elems > 0 && elems >= parallel_threshold() && dispatch::is_primitive::<T>()
+}
+
+// same, for ops that also send a scalar of type U to the pool
+pub(crate) fn enabled_scalar<T, U>(elems: usize) -> bool
+{
+    enabled::<T>(elems) && dispatch::is_primitive::<U>()
+}
+
+// rows per work item, a few chunks per thread for load balancing
+fn rows_per_chunk(rows: usize) -> usize
+{
+    rows.div_ceil(rayon::current_num_threads() * 4).max(1)
+}
+
+// X----------X
+// lets element data cross into the pool without a Send + Sync bound on T
+// every constructor checks that T is a primitive number type, which is Send + Sync
+
+#[repr(transparent)]
+#[derive(Clone, Copy)]
+struct Shared<X>(X);
+
+// SAFETY: only ever built around primitive element types (see assert_primitive)
+unsafe impl<X> Send for Shared<X> {}
+unsafe impl<X> Sync for Shared<X> {}
+
+impl<X> Shared<X>
+{
+    // wraps a value built from elements of type T
+    fn new<T>(x: X) -> Self
+    {
+        assert_primitive::<T>();
+        Shared(x)
+    }
+
+    // by method, so closures capture the whole wrapper and not the inner field
+    fn get(&self) -> &X
+    {
+        &self.0
+    }
+}
+
+fn assert_primitive<T>()
+{
+    assert!(dispatch::is_primitive::<T>(), "parallel kernel called for a non-primitive element type");
+}
+
+fn shared<T>(s: &[T]) -> &[Shared<T>]
+{
+    assert_primitive::<T>();
+
+    // SAFETY: Shared is repr(transparent) over T
+    unsafe { &*(s as *const [T] as *const [Shared<T>]) }
+}
+
+fn shared_mut<T>(s: &mut [T]) -> &mut [Shared<T>]
+{
+    assert_primitive::<T>();
+
+    // SAFETY: Shared is repr(transparent) over T
+    unsafe { &mut *(s as *mut [T] as *mut [Shared<T>]) }
+}
+
+fn unshared_mut<T>(s: &mut [Shared<T>]) -> &mut [T]
+{
+    // SAFETY: Shared is repr(transparent) over T
+    unsafe { &mut *(s as *mut [Shared<T>] as *mut [T]) }
+}
+
+// X----------X
+// row-chunked kernels, slices are row-major with <cols> columns
+// callers check enabled::<T>() first, the kernels panic for non-primitive T
+
+// out[i] = func(lhs[i], rhs[i])
+pub(crate) fn zip<T>(lhs: &[T], rhs: &[T], out: &mut [T], cols: usize, func: fn(T, T) -> T)
+where T: Copy
+{
+    let (lhs, rhs, out) = (shared(lhs), shared(rhs), shared_mut(out));
+
+    install(||
+        {
+            let chunk = rows_per_chunk(lhs.len() / cols) * cols;
+
+            out.par_chunks_mut(chunk).zip(lhs.par_chunks(chunk).zip(rhs.par_chunks(chunk))).for_each(|(o, (l, r))|
+                {
+                    for (o, (l, r)) in o.iter_mut().zip(l.iter().zip(r.iter()))
+                    {
+                        o.0 = func(l.0, r.0);
+                    }
+                });
+        });
+}
+
+// out[i] = func(src[i], scalar), the scalar must be a primitive as well
+pub(crate) fn map_scalar<T, U>(src: &[T], out: &mut [T], cols: usize, scalar: U, func: fn(T, U) -> T)
+where T: Copy, U: Copy
+{
+    let (src, out, scalar) = (shared(src), shared_mut(out), Shared::new::<U>(scalar));
+
+    install(||
+        {
+            let chunk = rows_per_chunk(src.len() / cols) * cols;
+
+            out.par_chunks_mut(chunk).zip(src.par_chunks(chunk)).for_each(|(o, s)|
+                {
+                    for (o, s) in o.iter_mut().zip(s.iter())
+                    {
+                        o.0 = func(s.0, *scalar.get());
+                    }
+                });
+        });
+}
+
+// per-chunk partial sums, combined in chunk order at the end
+// float results can differ from the serial sum in the last bits (different association)
+pub(crate) fn sum<T>(data: &[T], cols: usize) -> T
+where T: Copy + std::iter::Sum<T>
+{
+    let data = shared(data);
+
+    let partials = install(||
+        {
+            let chunk = rows_per_chunk(data.len() / cols) * cols;
+
+            data.par_chunks(chunk).map(|c| Shared::new::<T>(c.iter().map(|x| x.0).sum::<T>())).collect::<Vec<_>>()
+        });
+
+    partials.into_iter().map(|x| x.0).sum()
+}
+
+// out += alpha * (a * b), output rows are split across the pool and each chunk runs
+// the serial <kernel> on the matching rows of a, so every element keeps its summation order
+pub(crate) fn gemm<T>(alpha: T, a: &MatrixView<'_, T>, b: &MatrixView<'_, T>, out: &mut [T], kernel: fn(T, &MatrixView<'_, T>, &MatrixView<'_, T>, &mut [T]))
+where T: Num + Copy
+{
+    let (m, k_dim) = a.shape();
+    let n = b.cols();
+    let (args, out) = (Shared::new::<T>((alpha, *a, *b)), shared_mut(out));
+
+    install(||
+        {
+            let rows = rows_per_chunk(m);
+
+            out.par_chunks_mut(rows * n).enumerate().for_each(|(c, chunk)|
+                {
+                    let (alpha, a, b) = *args.get();
+                    let chunk = unshared_mut(chunk);
+                    let r0 = c * rows;
+                    let a_rows = a.slice(r0..r0 + chunk.len() / n, 0..k_dim);
+                    kernel(alpha, &a_rows, &b, chunk);
+                });
+        });
+}
+
+//----------Parallel Apply----------//
+
+// closures carry their own Send / Sync, so these ask for it in the bounds
+// and run on the pool for any element type
+impl<T> Matrix<T>
+where T: Num + Copy + Send + Sync
+{
+    // parallel apply, row chunks are mapped on the pool
+    pub fn par_apply(&self, func: impl Fn(T) -> T + Sync + Send) -> Matrix<T>
+    {
+        let len = self.as_slice().len();
+
+        if len == 0 || len < parallel_threshold() { return self.apply(func); }
+
+        let mut out = vec![T::zero(); len];
+        let cols = self.cols();
+
+        install(||
+            {
+                let chunk = rows_per_chunk(self.rows()) * cols;
+
+                out.par_chunks_mut(chunk).zip(self.as_slice().par_chunks(chunk)).for_each(|(o, s)|
+                    {
+                        for (o, s) in o.iter_mut().zip(s.iter())
+                        {
+                            *o = func(*s);
+                        }
+                    });
+            });
+
+        Matrix::new(self.rows(), cols, out).unwrap()
+    }
+
+    // parallel inplace apply
+    pub fn par_apply_inplace(mut self, func: impl Fn(T) -> T + Sync + Send) -> Self
+    {
+        let len = self.as_slice().len();
+
+        if len == 0 || len < parallel_threshold() { return self.apply_inplace(func); }
+
+        let (rows, cols) = self.shape();
+
+        install(||
+            {
+                let chunk = rows_per_chunk(rows) * cols;
+
+                self.as_mut_slice().par_chunks_mut(chunk).for_each(|c|
+                    {
+                        for val in c.iter_mut()
+                        {
+                            *val = func(*val);
+                        }
+                    });
+            });
+
+        self
+    }
+}
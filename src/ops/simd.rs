@@ -0,0 +1,545 @@
+use std::ops::AddAssign;
+use num_traits::Num;
+use crate::core::{error::MatrixError, matrix::Matrix, view::MatrixView};
+use crate::ops::dispatch;
+
+//----------Explicit SIMD Kernels (feature = "simd")----------//
+
+// with this feature, f32 / f64 matrices also run these kernels inside the existing ops:
+// Matrix +, -, * scalar, component_mul and the small-product matmul / gemm loop
+
+// true if the AVX2 + FMA kernels will be used on this machine
+pub fn avx2_fma_available() -> bool
+{
+    #[cfg(target_arch = "x86_64")]
+    {
+        is_x86_feature_detected!("avx2") && is_x86_feature_detected!("fma")
+    }
+
+    #[cfg(not(target_arch = "x86_64"))]
+    {
+        false
+    }
+}
+
+// slice kernels for the float types, dispatched at runtime:
+// AVX2 + FMA on x86_64 when the CPU has it, plain scalar loops otherwise
+// all slices must have the same length (checked, panics otherwise)
+pub trait SimdScalar: Num + Copy + AddAssign
+{
+    // out = a + b
+    fn simd_add(a: &[Self], b: &[Self], out: &mut [Self]);
+
+    // out = a - b
+    fn simd_sub(a: &[Self], b: &[Self], out: &mut [Self]);
+
+    // out = a * b (element-wise)
+    fn simd_mul(a: &[Self], b: &[Self], out: &mut [Self]);
+
+    // out = a * alpha
+    fn simd_scale(alpha: Self, a: &[Self], out: &mut [Self]);
+
+    // y += alpha * x
+    fn simd_axpy(alpha: Self, x: &[Self], y: &mut [Self]);
+
+    // sum(a * b)
+    fn simd_dot(a: &[Self], b: &[Self]) -> Self;
+}
+
+#[cfg(target_arch = "x86_64")]
+mod x86
+{
+    // generates the AVX2 / FMA kernels for one float type
+    macro_rules! avx_kernels
+    {
+        ($name:ident, $t:ty, $lanes:expr, $loadu:ident, $storeu:ident, $add:ident, $sub:ident, $mul:ident, $fmadd:ident, $set1:ident, $setzero:ident) =>
+        {
+            pub mod $name
+            {
+                use std::arch::x86_64::*;
+
+                const LANES: usize = $lanes;
+
+                // element-wise binary kernels, scalar tail for the remainder
+                macro_rules! binary
+                {
+                    ($fn_name:ident, $vec_op:ident, $op:tt) =>
+                    {
+                        // SAFETY: caller must ensure avx2 + fma are available and all lengths match
+                        #[target_feature(enable = "avx2,fma")]
+                        pub unsafe fn $fn_name(a: &[$t], b: &[$t], out: &mut [$t])
+                        {
+                            let n = out.len();
+                            let body = n - n % LANES;
+
+                            for i in (0..body).step_by(LANES)
+                            {
+                                unsafe
+                                {
+                                    let va = $loadu(a.as_ptr().add(i));
+                                    let vb = $loadu(b.as_ptr().add(i));
+                                    $storeu(out.as_mut_ptr().add(i), $vec_op(va, vb));
+                                }
+                            }
+
+                            for i in body..n
+                            {
+                                out[i] = a[i] $op b[i];
+                            }
+                        }
+                    };
+                }
+
+                binary!(add, $add, +);
+                binary!(sub, $sub, -);
+                binary!(mul, $mul, *);
+
+                // SAFETY: caller must ensure avx2 + fma are available and a.len() == out.len()
+                #[target_feature(enable = "avx2,fma")]
+                pub unsafe fn scale(alpha: $t, a: &[$t], out: &mut [$t])
+                {
+                    let n = out.len();
+                    let body = n - n % LANES;
+                    let va = $set1(alpha);
+
+                    for i in (0..body).step_by(LANES)
+                    {
+                        unsafe { $storeu(out.as_mut_ptr().add(i), $mul($loadu(a.as_ptr().add(i)), va)); }
+                    }
+
+                    for i in body..n
+                    {
+                        out[i] = a[i] * alpha;
+                    }
+                }
+
+                // SAFETY: caller must ensure avx2 + fma are available and x.len() == y.len()
+                #[target_feature(enable = "avx2,fma")]
+                pub unsafe fn axpy(alpha: $t, x: &[$t], y: &mut [$t])
+                {
+                    let n = y.len();
+                    let body = n - n % LANES;
+                    let va = $set1(alpha);
+
+                    for i in (0..body).step_by(LANES)
+                    {
+                        unsafe
+                        {
+                            let vx = $loadu(x.as_ptr().add(i));
+                            let vy = $loadu(y.as_ptr().add(i));
+                            $storeu(y.as_mut_ptr().add(i), $fmadd(va, vx, vy));
+                        }
+                    }
+
+                    for i in body..n
+                    {
+                        y[i] = alpha.mul_add(x[i], y[i]);
+                    }
+                }
+
+                // two accumulators to hide FMA latency
+                // SAFETY: caller must ensure avx2 + fma are available and a.len() == b.len()
+                #[target_feature(enable = "avx2,fma")]
+                pub unsafe fn dot(a: &[$t], b: &[$t]) -> $t
+                {
+                    let n = a.len();
+                    let body = n - n % (2 * LANES);
+                    let mut acc0 = $setzero();
+                    let mut acc1 = $setzero();
+
+                    for i in (0..body).step_by(2 * LANES)
+                    {
+                        unsafe
+                        {
+                            acc0 = $fmadd($loadu(a.as_ptr().add(i)), $loadu(b.as_ptr().add(i)), acc0);
+                            acc1 = $fmadd($loadu(a.as_ptr().add(i + LANES)), $loadu(b.as_ptr().add(i + LANES)), acc1);
+                        }
+                    }
+
+                    let mut lanes = [0.0 as $t; LANES];
+                    unsafe { $storeu(lanes.as_mut_ptr(), $add(acc0, acc1)); }
+
+                    let mut sum: $t = lanes.iter().sum();
+
+                    for i in body..n
+                    {
+                        sum += a[i] * b[i];
+                    }
+
+                    sum
+                }
+            }
+        };
+    }
+
+    avx_kernels!(f32x8, f32, 8, _mm256_loadu_ps, _mm256_storeu_ps, _mm256_add_ps, _mm256_sub_ps, _mm256_mul_ps, _mm256_fmadd_ps, _mm256_set1_ps, _mm256_setzero_ps);
+    avx_kernels!(f64x4, f64, 4, _mm256_loadu_pd, _mm256_storeu_pd, _mm256_add_pd, _mm256_sub_pd, _mm256_mul_pd, _mm256_fmadd_pd, _mm256_set1_pd, _mm256_setzero_pd);
+}
+
+// X----------X
+// kernel tables: the CPU features are detected once per call and the whole set of kernels
+// is picked up front, so loops over rows don't re-detect or re-check anything
+// every entry expects equal slice lengths, callers check them (or slice them to match)
+
+struct Kernels<T>
+{
+    add: unsafe fn(&[T], &[T], &mut [T]),
+    sub: unsafe fn(&[T], &[T], &mut [T]),
+    mul: unsafe fn(&[T], &[T], &mut [T]),
+    scale: unsafe fn(T, &[T], &mut [T]),
+    axpy: unsafe fn(T, &[T], &mut [T]),
+    dot: unsafe fn(&[T], &[T]) -> T
+}
+
+trait Detect: Sized
+{
+    fn kernels() -> Kernels<Self>;
+}
+
+// portable fallbacks
+mod scalar
+{
+    use std::ops::AddAssign;
+    use num_traits::Num;
+
+    pub fn add<T: Num + Copy>(a: &[T], b: &[T], out: &mut [T])
+    {
+        for ((o, x), y) in out.iter_mut().zip(a.iter()).zip(b.iter()) { *o = *x + *y; }
+    }
+
+    pub fn sub<T: Num + Copy>(a: &[T], b: &[T], out: &mut [T])
+    {
+        for ((o, x), y) in out.iter_mut().zip(a.iter()).zip(b.iter()) { *o = *x - *y; }
+    }
+
+    pub fn mul<T: Num + Copy>(a: &[T], b: &[T], out: &mut [T])
+    {
+        for ((o, x), y) in out.iter_mut().zip(a.iter()).zip(b.iter()) { *o = *x * *y; }
+    }
+
+    pub fn scale<T: Num + Copy>(alpha: T, a: &[T], out: &mut [T])
+    {
+        for (o, x) in out.iter_mut().zip(a.iter()) { *o = *x * alpha; }
+    }
+
+    pub fn axpy<T: Num + Copy + AddAssign>(alpha: T, x: &[T], y: &mut [T])
+    {
+        for (o, v) in y.iter_mut().zip(x.iter()) { *o += alpha * *v; }
+    }
+
+    pub fn dot<T: Num + Copy + std::iter::Sum<T>>(a: &[T], b: &[T]) -> T
+    {
+        a.iter().zip(b.iter()).map(|(x, y)| *x * *y).sum()
+    }
+}
+
+// kernel table + SimdScalar impl for one float type
+macro_rules! impl_simd_scalar
+{
+    ($t:ty, $kernels:ident) =>
+    {
+        impl Detect for $t
+        {
+            fn kernels() -> Kernels<$t>
+            {
+                #[cfg(target_arch = "x86_64")]
+                if avx2_fma_available()
+                {
+                    return Kernels
+                    {
+                        add: x86::$kernels::add,
+                        sub: x86::$kernels::sub,
+                        mul: x86::$kernels::mul,
+                        scale: x86::$kernels::scale,
+                        axpy: x86::$kernels::axpy,
+                        dot: x86::$kernels::dot
+                    };
+                }
+
+                Kernels { add: scalar::add, sub: scalar::sub, mul: scalar::mul, scale: scalar::scale, axpy: scalar::axpy, dot: scalar::dot }
+            }
+        }
+
+        impl SimdScalar for $t
+        {
+            fn simd_add(a: &[$t], b: &[$t], out: &mut [$t])
+            {
+                check_lens(a.len(), b.len(), out.len());
+
+                // SAFETY: lengths checked
+                unsafe { (Self::kernels().add)(a, b, out) }
+            }
+
+            fn simd_sub(a: &[$t], b: &[$t], out: &mut [$t])
+            {
+                check_lens(a.len(), b.len(), out.len());
+
+                // SAFETY: lengths checked
+                unsafe { (Self::kernels().sub)(a, b, out) }
+            }
+
+            fn simd_mul(a: &[$t], b: &[$t], out: &mut [$t])
+            {
+                check_lens(a.len(), b.len(), out.len());
+
+                // SAFETY: lengths checked
+                unsafe { (Self::kernels().mul)(a, b, out) }
+            }
+
+            fn simd_scale(alpha: $t, a: &[$t], out: &mut [$t])
+            {
+                check_lens(a.len(), out.len(), out.len());
+
+                // SAFETY: lengths checked
+                unsafe { (Self::kernels().scale)(alpha, a, out) }
+            }
+
+            fn simd_axpy(alpha: $t, x: &[$t], y: &mut [$t])
+            {
+                check_lens(x.len(), y.len(), y.len());
+
+                // SAFETY: lengths checked
+                unsafe { (Self::kernels().axpy)(alpha, x, y) }
+            }
+
+            fn simd_dot(a: &[$t], b: &[$t]) -> $t
+            {
+                check_lens(a.len(), b.len(), b.len());
+
+                // SAFETY: lengths checked
+                unsafe { (Self::kernels().dot)(a, b) }
+            }
+        }
+    };
+}
+
+impl_simd_scalar!(f32, f32x8);
+impl_simd_scalar!(f64, f64x4);
+
+// the AVX kernels index raw pointers, so mismatched slices must never reach them
+fn check_lens(a: usize, b: usize, out: usize)
+{
+    if a != b || a != out
+    {
+        panic!("simd: slice length mismatch ({}, {}, {})", a, b, out);
+    }
+}
+
+// X----------X
+// hooks for the generic ops, the bounds there stay Num + Copy so the element type is
+// matched at runtime: callers check enabled::<T>() first and fall back to their own loops
+
+#[derive(Clone, Copy)]
+pub(crate) enum Binary
+{
+    Add,
+    Sub,
+    Mul
+}
+
+// true if T has kernels (f32 / f64)
+pub(crate) fn enabled<T>() -> bool
+{
+    dispatch::is::<T, f32>() || dispatch::is::<T, f64>()
+}
+
+// same, for ops that also take a scalar, which must be the same float type
+pub(crate) fn enabled_scalar<T, U>() -> bool
+{
+    (dispatch::is::<T, f32>() && dispatch::is::<U, f32>()) || (dispatch::is::<T, f64>() && dispatch::is::<U, f64>())
+}
+
+// out = a (op) b
+pub(crate) fn binary<T>(op: Binary, a: &[T], b: &[T], out: &mut [T])
+{
+    let routed = binary_as::<T, f32>(op, a, b, out) || binary_as::<T, f64>(op, a, b, out);
+    assert!(routed, "simd: no kernels for this element type");
+}
+
+fn binary_as<T, F>(op: Binary, a: &[T], b: &[T], out: &mut [T]) -> bool
+where F: Detect + 'static
+{
+    let (Some(a), Some(b), Some(out)) = (dispatch::cast::<T, F>(a), dispatch::cast::<T, F>(b), dispatch::cast_mut::<T, F>(out)) else { return false };
+
+    check_lens(a.len(), b.len(), out.len());
+
+    let kernels = F::kernels();
+    let kernel = match op
+    {
+        Binary::Add => kernels.add,
+        Binary::Sub => kernels.sub,
+        Binary::Mul => kernels.mul
+    };
+
+    // SAFETY: lengths checked
+    unsafe { kernel(a, b, out) };
+
+    true
+}
+
+// out = a * alpha
+pub(crate) fn scale<T, U>(a: &[T], alpha: U, out: &mut [T])
+where U: Copy
+{
+    let routed = scale_as::<T, U, f32>(a, alpha, out) || scale_as::<T, U, f64>(a, alpha, out);
+    assert!(routed, "simd: no kernels for this element type");
+}
+
+fn scale_as<T, U, F>(a: &[T], alpha: U, out: &mut [T]) -> bool
+where U: Copy, F: Detect + Copy + 'static
+{
+    let (Some(a), Some(alpha), Some(out)) = (dispatch::cast::<T, F>(a), dispatch::cast_value::<U, F>(alpha), dispatch::cast_mut::<T, F>(out)) else { return false };
+
+    check_lens(a.len(), out.len(), out.len());
+
+    // SAFETY: lengths checked
+    unsafe { (F::kernels().scale)(alpha, a, out) };
+
+    true
+}
+
+// out += alpha * (a * b) as i-k-j row updates, every out row gets one axpy per k
+// b must have unit-stride rows, out is row-major (a.rows x b.cols)
+pub(crate) fn gemm<T>(alpha: T, a: &MatrixView<'_, T>, b: &MatrixView<'_, T>, out: &mut [T])
+where T: Num + Copy
+{
+    let routed = gemm_as::<T, f32>(alpha, a, b, out) || gemm_as::<T, f64>(alpha, a, b, out);
+    assert!(routed, "simd: no kernels for this element type");
+}
+
+fn gemm_as<T, F>(alpha: T, a: &MatrixView<'_, T>, b: &MatrixView<'_, T>, out: &mut [T]) -> bool
+where T: Num + Copy, F: Detect + Copy + 'static
+{
+    let Some(out) = dispatch::cast_mut::<T, F>(out) else { return false };
+
+    let (m, k_dim) = a.shape();
+    let n = b.cols();
+    let rhs_rows: Vec<&[F]> = (0..k_dim).map(|k| b.row_slice(k).and_then(dispatch::cast::<T, F>).expect("simd: gemm needs unit-stride rows in b")).collect();
+    let axpy = F::kernels().axpy;
+
+    for (i, out_row) in out.chunks_exact_mut(n).take(m).enumerate()
+    {
+        for (k, rhs_row) in rhs_rows.iter().enumerate()
+        {
+            // SAFETY: i < m and k < k_dim by the loop bounds
+            let lhs_val = dispatch::cast_value::<T, F>(alpha * unsafe { *a.get_unchecked(i, k) }).unwrap();
+
+            // SAFETY: rhs_row and out_row both have n elements
+            unsafe { axpy(lhs_val, rhs_row, out_row) };
+        }
+    }
+
+    true
+}
+
+// X----------X
+// Matrix entry points, mirror the generic ops but always run the SIMD kernels
+impl<T> Matrix<T>
+where T: SimdScalar
+{
+    pub fn simd_add(&self, rhs: &Matrix<T>) -> Matrix<T>
+    {
+        self.try_simd_add(rhs).unwrap_or_else(|e| panic!("{}", e))
+    }
+
+    pub fn try_simd_add(&self, rhs: &Matrix<T>) -> Result<Matrix<T>, MatrixError>
+    {
+        self.simd_binary(rhs, "Add", T::simd_add)
+    }
+
+    pub fn simd_sub(&self, rhs: &Matrix<T>) -> Matrix<T>
+    {
+        self.try_simd_sub(rhs).unwrap_or_else(|e| panic!("{}", e))
+    }
+
+    pub fn try_simd_sub(&self, rhs: &Matrix<T>) -> Result<Matrix<T>, MatrixError>
+    {
+        self.simd_binary(rhs, "Sub", T::simd_sub)
+    }
+
+    // Hadamard multiplication
+    pub fn simd_component_mul(&self, rhs: &Matrix<T>) -> Matrix<T>
+    {
+        self.try_simd_component_mul(rhs).unwrap_or_else(|e| panic!("{}", e))
+    }
+
+    pub fn try_simd_component_mul(&self, rhs: &Matrix<T>) -> Result<Matrix<T>, MatrixError>
+    {
+        self.simd_binary(rhs, "component_mul", T::simd_mul)
+    }
+
+    // self += alpha * x
+    pub fn simd_axpy(&mut self, alpha: T, x: &Matrix<T>)
+    {
+        self.try_simd_axpy(alpha, x).unwrap_or_else(|e| panic!("{}", e))
+    }
+
+    pub fn try_simd_axpy(&mut self, alpha: T, x: &Matrix<T>) -> Result<(), MatrixError>
+    {
+        self.check_same_shape(x, "axpy")?;
+        T::simd_axpy(alpha, x.as_slice(), self.as_mut_slice());
+        Ok(())
+    }
+
+    // Frobenius inner product, sum of self[i, j] * other[i, j]
+    pub fn simd_dot(&self, other: &Matrix<T>) -> T
+    {
+        self.try_simd_dot(other).unwrap_or_else(|e| panic!("{}", e))
+    }
+
+    pub fn try_simd_dot(&self, other: &Matrix<T>) -> Result<T, MatrixError>
+    {
+        self.check_same_shape(other, "dot")?;
+        Ok(T::simd_dot(self.as_slice(), other.as_slice()))
+    }
+
+    // i-k-j matmul, each inner row update is one axpy
+    pub fn simd_matmul(&self, other: &Matrix<T>) -> Matrix<T>
+    {
+        self.try_simd_matmul(other).unwrap_or_else(|e| panic!("{}", e))
+    }
+
+    pub fn try_simd_matmul(&self, other: &Matrix<T>) -> Result<Matrix<T>, MatrixError>
+    {
+        let (m, k_dim) = self.shape();
+        let n = other.cols();
+
+        // check size
+        if k_dim != other.rows()
+        {
+            return Err(MatrixError::InnerDimMismatch { op: "matmul", lhs: self.shape(), rhs: other.shape() });
+        }
+
+        let mut result = vec![T::zero(); m * n];
+
+        if enabled::<T>()
+        {
+            gemm(T::one(), &self.view(), &other.view(), &mut result);
+        }
+        else
+        {
+            // SimdScalar implemented outside this crate, go through its slice kernels
+            let (lhs, rhs) = (self.as_slice(), other.as_slice());
+
+            for (i, out_row) in result.chunks_exact_mut(n).enumerate()
+            {
+                for k in 0..k_dim
+                {
+                    T::simd_axpy(lhs[i * k_dim + k], &rhs[k * n..(k + 1) * n], out_row);
+                }
+            }
+        }
+
+        Matrix::new(m, n, result)
+    }
+
+    fn simd_binary(&self, rhs: &Matrix<T>, op: &'static str, kernel: fn(&[T], &[T], &mut [T])) -> Result<Matrix<T>, MatrixError>
+    {
+        self.check_same_shape(rhs, op)?;
+
+        let mut result = vec![T::zero(); self.as_slice().len()];
+        kernel(self.as_slice(), rhs.as_slice(), &mut result);
+
+        Matrix::new(self.rows(), self.cols(), result)
+    }
+}
@@ -0,0 +1,7 @@
+pub(crate) mod compressed;
+
+pub mod amd;
+pub mod cholesky;
+pub mod coo;
+pub mod csc;
+pub mod csr;
\ No newline at end of file
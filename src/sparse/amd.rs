@@ -0,0 +1,157 @@
+use std::collections::BTreeSet;
+use num_traits::Num;
+use crate::core::error::MatrixError;
+use crate::sparse::csc::CscMatrix;
+
+//----------Approximate Minimum Degree Ordering----------//
+
+impl<T> CscMatrix<T>
+where T: Num + Copy
+{
+    // fill-reducing permutation for a sparse Cholesky of a symmetric matrix, perm[new] = old
+    // only the pattern of A + A^T is used, values and the diagonal are ignored
+    pub fn amd(&self) -> Vec<usize>
+    {
+        self.try_amd().unwrap_or_else(|e| panic!("{}", e))
+    }
+
+    pub fn try_amd(&self) -> Result<Vec<usize>, MatrixError>
+    {
+        if self.rows() != self.cols()
+        {
+            return Err(MatrixError::NotSquare { op: "amd", rows: self.rows(), cols: self.cols() });
+        }
+
+        let n = self.cols();
+        let mut adj = vec![Vec::new(); n];
+
+        for j in 0..n
+        {
+            for (i, _) in self.col(j).filter(|&(i, _)| i != j)
+            {
+                adj[i].push(j);
+                adj[j].push(i);
+            }
+        }
+
+        for list in adj.iter_mut()
+        {
+            list.sort_unstable();
+            list.dedup();
+        }
+
+        Ok(minimum_degree(adj))
+    }
+}
+
+// X----------X
+// elimination on the quotient graph (Amestoy, Davis, Duff)
+
+// eliminating pivot p turns it into an element whose boundary L_p is every variable reachable from p,
+// elements adjacent to p are absorbed into it so the graph never grows past its initial size
+// the degree of i is the AMD bound |A_i| + |L_p \ i| + sum over other elements e of |L_e \ L_p|,
+// which is exact for most variables and never below the true external degree
+// no supervariable detection, so indistinguishable variables are still eliminated one by one
+fn minimum_degree(mut vars: Vec<Vec<usize>>) -> Vec<usize>
+{
+    let n = vars.len();
+
+    // elements adjacent to each variable, and the variable boundary of each element (indexed by its pivot)
+    let mut elems: Vec<Vec<usize>> = vec![Vec::new(); n];
+    let mut bound: Vec<Vec<usize>> = vec![Vec::new(); n];
+    let mut absorbed = vec![false; n];
+    let mut eliminated = vec![false; n];
+
+    let mut degree: Vec<usize> = vars.iter().map(Vec::len).collect();
+    let mut queue: BTreeSet<(usize, usize)> = (0..n).map(|i| (degree[i], i)).collect();
+
+    // mark[i] == k when i is in L_p of step k, w[e] is |L_e \ L_p| when w_mark[e] == k
+    let mut mark = vec![usize::MAX; n];
+    let mut w = vec![0; n];
+    let mut w_mark = vec![usize::MAX; n];
+
+    let mut perm = Vec::with_capacity(n);
+
+    for k in 0..n
+    {
+        let (_, p) = queue.pop_first().unwrap();
+        eliminated[p] = true;
+        perm.push(p);
+
+        // L_p = (A_p union every L_e adjacent to p) minus p
+        let mut lp = Vec::new();
+        mark[p] = k;
+
+        for i in std::mem::take(&mut vars[p])
+        {
+            if !eliminated[i] && mark[i] != k
+            {
+                mark[i] = k;
+                lp.push(i);
+            }
+        }
+
+        for e in std::mem::take(&mut elems[p])
+        {
+            for i in std::mem::take(&mut bound[e])
+            {
+                if mark[i] != k
+                {
+                    mark[i] = k;
+                    lp.push(i);
+                }
+            }
+            absorbed[e] = true;
+        }
+
+        // p becomes an element, edges between members of L_p are implied by it and pruned
+        for &i in lp.iter()
+        {
+            queue.remove(&(degree[i], i));
+            elems[i].retain(|&e| !absorbed[e]);
+            elems[i].push(p);
+            vars[i].retain(|&j| !eliminated[j] && mark[j] != k);
+        }
+
+        // |L_e \ L_p| = |L_e| minus one for every member of L_p that also lies in L_e
+        for &i in lp.iter()
+        {
+            for &e in elems[i].iter().filter(|&&e| e != p)
+            {
+                if w_mark[e] != k
+                {
+                    w_mark[e] = k;
+                    w[e] = bound[e].len();
+                }
+                w[e] -= 1;
+            }
+        }
+
+        // aggressive absorption, an element inside L_p adds nothing new
+        for &i in lp.iter()
+        {
+            for &e in elems[i].iter().filter(|&&e| e != p && w[e] == 0)
+            {
+                absorbed[e] = true;
+                bound[e].clear();
+            }
+        }
+
+        let remaining = n - k - 1;
+
+        for &i in lp.iter()
+        {
+            elems[i].retain(|&e| !absorbed[e]);
+
+            let ext: usize = elems[i].iter().filter(|&&e| e != p).map(|&e| w[e]).sum();
+            let d = (vars[i].len() + lp.len() - 1 + ext).min(degree[i] + lp.len() - 1).min(remaining - 1);
+
+            degree[i] = d;
+            queue.insert((d, i));
+        }
+
+        bound[p] = lp;
+    }
+
+    perm
+}
@@ -0,0 +1,409 @@
+use num_traits::{Float, Num};
+use crate::core::{error::MatrixError, matrix::Matrix};
+use crate::sparse::{compressed::Compressed, csc::CscMatrix};
+
+//----------Sparse Cholesky Decomposition----------//
+
+// P * A * P^T = L * L^T for sparse symmetric positive definite A, split in two phases:
+// the symbolic analysis depends only on the pattern of A (ordering, elimination tree, pattern of L),
+// the numeric factorisation fills in the values and can be repeated for every A with that pattern
+
+// X----------X
+// symbolic analysis
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct SymbolicCholesky
+{
+    n: usize,
+    // perm[new] = old
+    perm: Vec<usize>,
+    // elimination tree of P * A * P^T, None at the roots
+    parent: Vec<Option<usize>>,
+    // pattern of the A that was analysed, refactorisations must match it
+    a_indptr: Vec<usize>,
+    a_indices: Vec<usize>,
+    // lower triangle of P * A * P^T in CSC, values are positions in A's value array
+    lower: Compressed<usize>,
+    // pattern of L in CSC, the diagonal comes first in every column
+    l_indptr: Vec<usize>,
+    l_indices: Vec<usize>,
+}
+
+impl<T> CscMatrix<T>
+where T: Num + Copy
+{
+    // symbolic analysis with the AMD ordering
+    pub fn symbolic_cholesky(&self) -> SymbolicCholesky
+    {
+        self.try_symbolic_cholesky().unwrap_or_else(|e| panic!("{}", e))
+    }
+
+    pub fn try_symbolic_cholesky(&self) -> Result<SymbolicCholesky, MatrixError>
+    {
+        self.analyse(None, "symbolic_cholesky")
+    }
+
+    // symbolic analysis with a caller supplied ordering, perm[new] = old
+    pub fn symbolic_cholesky_with_perm(&self, perm: &[usize]) -> SymbolicCholesky
+    {
+        self.try_symbolic_cholesky_with_perm(perm).unwrap_or_else(|e| panic!("{}", e))
+    }
+
+    pub fn try_symbolic_cholesky_with_perm(&self, perm: &[usize]) -> Result<SymbolicCholesky, MatrixError>
+    {
+        self.analyse(Some(perm), "symbolic_cholesky")
+    }
+
+    // AMD ordering unless perm is given
+    fn analyse(&self, perm: Option<&[usize]>, op: &'static str) -> Result<SymbolicCholesky, MatrixError>
+    {
+        if self.rows() != self.cols()
+        {
+            return Err(MatrixError::NotSquare { op, rows: self.rows(), cols: self.cols() });
+        }
+
+        let perm = match perm
+        {
+            Some(perm) => perm.to_vec(),
+            None => self.try_amd()?,
+        };
+
+        let n = self.cols();
+        let mut iperm = vec![usize::MAX; n];
+
+        if perm.len() != n
+        {
+            return Err(MatrixError::InvalidStructure { op, reason: "perm length must match the matrix size" });
+        }
+
+        for (new, &old) in perm.iter().enumerate()
+        {
+            if old >= n || iperm[old] != usize::MAX
+            {
+                return Err(MatrixError::InvalidStructure { op, reason: "perm is not a permutation of 0..n" });
+            }
+            iperm[old] = new;
+        }
+
+        // permuted lower triangle, only one of each mirrored pair of A survives
+        let (ap, ai) = (self.indptr(), self.indices());
+        let entries = (0..n).flat_map(|jo| (ap[jo]..ap[jo + 1]).map(move |q| (jo, q)))
+            .map(|(jo, q)| (iperm[ai[q]], iperm[jo], q))
+            .filter(|&(i, j, _)| i >= j)
+            .map(|(i, j, q)| (j, i, q));
+        let lower = Compressed::from_triplets(n, n, entries);
+
+        // column k of the upper triangle is row k of the lower one, so the pattern is symmetric by construction
+        let upper = lower.swap_axes();
+        let parent = etree(&upper);
+
+        // row k of L is the set of nodes on the etree paths from every i in column k of the upper triangle up to k
+        let mut cols: Vec<Vec<usize>> = (0..n).map(|j| vec![j]).collect();
+        let mut mark = vec![usize::MAX; n];
+
+        for k in 0..n
+        {
+            mark[k] = k;
+            for &i in upper.indices[upper.line(k)].iter()
+            {
+                let mut i = i;
+                while mark[i] != k
+                {
+                    mark[i] = k;
+                    cols[i].push(k);
+                    i = parent[i].unwrap();
+                }
+            }
+        }
+
+        let mut l_indptr = vec![0; n + 1];
+        for j in 0..n { l_indptr[j + 1] = l_indptr[j] + cols[j].len(); }
+        let l_indices = cols.concat();
+
+        Ok(SymbolicCholesky
+        {
+            n,
+            perm,
+            parent,
+            a_indptr: ap.to_vec(),
+            a_indices: ai.to_vec(),
+            lower,
+            l_indptr,
+            l_indices,
+        })
+    }
+}
+
+// elimination tree from the upper triangle in CSC, with path compression through ancestor
+fn etree(upper: &Compressed<usize>) -> Vec<Option<usize>>
+{
+    let n = upper.major;
+    let mut parent = vec![None; n];
+    let mut ancestor: Vec<Option<usize>> = vec![None; n];
+
+    for k in 0..n
+    {
+        for &i in upper.indices[upper.line(k)].iter()
+        {
+            let mut i = Some(i);
+            while let Some(c) = i.filter(|&c| c < k)
+            {
+                i = ancestor[c];
+                ancestor[c] = Some(k);
+                if i.is_none() { parent[c] = Some(k); }
+            }
+        }
+    }
+
+    parent
+}
+
+impl SymbolicCholesky
+{
+    pub fn size(&self) -> usize { self.n }
+
+    // perm[new] = old
+    pub fn perm(&self) -> &[usize] { &self.perm }
+
+    // parent of every column in the elimination tree, None at the roots
+    pub fn etree(&self) -> &[Option<usize>] { &self.parent }
+
+    // entries of L including the diagonal, known before any numeric work
+    pub fn nnz_l(&self) -> usize { self.l_indices.len() }
+
+    // numeric factorisation on top of this analysis, A must have the analysed pattern
+    pub fn factor<T>(&self, a: &CscMatrix<T>) -> SparseCholesky<T>
+    where T: Float
+    {
+        self.try_factor(a).unwrap_or_else(|e| panic!("{}", e))
+    }
+
+    pub fn try_factor<T>(&self, a: &CscMatrix<T>) -> Result<SparseCholesky<T>, MatrixError>
+    where T: Float
+    {
+        let mut values = Vec::new();
+        self.numeric(a, &mut values, "factor")?;
+
+        Ok(SparseCholesky { symbolic: self.clone(), values })
+    }
+
+    // left-looking: column j of L is column j of A minus L[j.., k] * L[j, k] for every k < j with L[j, k] != 0,
+    // the columns k pending for row j are kept in linked lists keyed by the next row they touch
+    fn numeric<T>(&self, a: &CscMatrix<T>, lx: &mut Vec<T>, op: &'static str) -> Result<(), MatrixError>
+    where T: Float
+    {
+        if a.indptr() != self.a_indptr.as_slice() || a.indices() != self.a_indices.as_slice()
+        {
+            return Err(MatrixError::InvalidStructure { op, reason: "sparsity pattern differs from the symbolic analysis" });
+        }
+
+        check_symmetric(a, op)?;
+
+        let n = self.n;
+        let (lp, li) = (&self.l_indptr, &self.l_indices);
+        let av = a.values();
+
+        lx.clear();
+        lx.resize(li.len(), T::zero());
+
+        let mut x = vec![T::zero(); n];
+        let mut head: Vec<Option<usize>> = vec![None; n];
+        let mut link: Vec<Option<usize>> = vec![None; n];
+        let mut next = vec![0; n];
+
+        for j in 0..n
+        {
+            for q in self.lower.line(j) { x[self.lower.indices[q]] = av[self.lower.values[q]]; }
+
+            // every pending k has L[j, k] at lx[next[k]]
+            let mut pending = head[j];
+            while let Some(k) = pending
+            {
+                pending = link[k];
+
+                let q = next[k];
+                let ljk = lx[q];
+                for r in q..lp[k + 1] { x[li[r]] = x[li[r]] - lx[r] * ljk; }
+
+                next[k] = q + 1;
+                if q + 1 < lp[k + 1]
+                {
+                    let row = li[q + 1];
+                    link[k] = head[row];
+                    head[row] = Some(k);
+                }
+            }
+
+            // also catches NaN
+            let d = x[j];
+            if d.is_nan() || d <= T::zero() { return Err(MatrixError::NotPositiveDefinite { op }); }
+
+            let ljj = d.sqrt();
+            lx[lp[j]] = ljj;
+            x[j] = T::zero();
+
+            for r in lp[j] + 1..lp[j + 1]
+            {
+                lx[r] = x[li[r]] / ljj;
+                x[li[r]] = T::zero();
+            }
+
+            if lp[j] + 1 < lp[j + 1]
+            {
+                next[j] = lp[j] + 1;
+                let row = li[next[j]];
+                link[j] = head[row];
+                head[row] = Some(j);
+            }
+        }
+
+        Ok(())
+    }
+}
+
+// symmetric up to rounding, |a_ij - a_ji| <= n * eps * max|a|, same test as the dense Cholesky
+fn check_symmetric<T>(a: &CscMatrix<T>, op: &'static str) -> Result<(), MatrixError>
+where T: Float
+{
+    let n = a.cols();
+    let scale = a.values().iter().fold(T::zero(), |acc, x| acc.max(x.abs()));
+    let tol = T::from(n).unwrap() * T::epsilon() * scale;
+
+    for j in 0..n
+    {
+        for (i, x) in a.col(j)
+        {
+            if (x - a.get(j, i).unwrap()).abs() > tol { return Err(MatrixError::NotSymmetric { op }); }
+        }
+    }
+
+    Ok(())
+}
+
+// X----------X
+// numeric factorisation
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct SparseCholesky<T>
+where T: Float
+{
+    symbolic: SymbolicCholesky,
+    // values of L, laid out by the symbolic pattern
+    values: Vec<T>,
+}
+
+impl<T> CscMatrix<T>
+where T: Float
+{
+    // AMD ordering, symbolic analysis and numeric factorisation in one go
+    pub fn cholesky(&self) -> SparseCholesky<T>
+    {
+        self.try_cholesky().unwrap_or_else(|e| panic!("{}", e))
+    }
+
+    pub fn try_cholesky(&self) -> Result<SparseCholesky<T>, MatrixError>
+    {
+        let symbolic = self.analyse(None, "cholesky")?;
+
+        let mut values = Vec::new();
+        symbolic.numeric(self, &mut values, "cholesky")?;
+
+        Ok(SparseCholesky { symbolic, values })
+    }
+}
+
+impl<T> SparseCholesky<T>
+where T: Float
+{
+    pub fn size(&self) -> usize { self.symbolic.n }
+
+    pub fn symbolic(&self) -> &SymbolicCholesky { &self.symbolic }
+
+    // perm[new] = old
+    pub fn perm(&self) -> &[usize] { &self.symbolic.perm }
+
+    // L of the permuted matrix P * A * P^T
+    pub fn l(&self) -> CscMatrix<T>
+    {
+        let s = &self.symbolic;
+        CscMatrix::from_compressed(Compressed { major: s.n, minor: s.n, indptr: s.l_indptr.clone(), indices: s.l_indices.clone(), values: self.values.clone() })
+    }
+
+    // new values with the same pattern, the ordering and the pattern of L are reused
+    // on failure the previous factor is left in place
+    pub fn refactor(&mut self, a: &CscMatrix<T>)
+    {
+        self.try_refactor(a).unwrap_or_else(|e| panic!("{}", e))
+    }
+
+    pub fn try_refactor(&mut self, a: &CscMatrix<T>) -> Result<(), MatrixError>
+    {
+        let mut values = Vec::with_capacity(self.values.len());
+        self.symbolic.numeric(a, &mut values, "refactor")?;
+        self.values = values;
+
+        Ok(())
+    }
+
+    // log(det(A)) = 2 * sum(log(l_jj)), the permutation does not change it
+    pub fn log_det(&self) -> T
+    {
+        let s = &self.symbolic;
+        let two = T::one() + T::one();
+        (0..s.n).fold(T::zero(), |acc, j| acc + self.values[s.l_indptr[j]].ln()) * two
+    }
+
+    pub fn det(&self) -> T
+    {
+        let s = &self.symbolic;
+        let prod = (0..s.n).fold(T::one(), |acc, j| acc * self.values[s.l_indptr[j]]);
+        prod * prod
+    }
+
+    // solves A * X = B for every column of B
+    pub fn solve(&self, b: &Matrix<T>) -> Matrix<T>
+    {
+        self.try_solve(b).unwrap_or_else(|e| panic!("{}", e))
+    }
+
+    pub fn try_solve(&self, b: &Matrix<T>) -> Result<Matrix<T>, MatrixError>
+    {
+        let s = &self.symbolic;
+        let n = s.n;
+
+        if b.rows() != n
+        {
+            return Err(MatrixError::InnerDimMismatch { op: "solve", lhs: (n, n), rhs: b.shape() });
+        }
+
+        let (lp, li, lx) = (&s.l_indptr, &s.l_indices, &self.values);
+        let w = b.cols();
+        let bs = b.as_slice();
+        let mut out = Matrix::zeroes(n, w)?;
+        let data = out.as_mut_slice();
+        let mut y = vec![T::zero(); n];
+
+        for c in 0..w
+        {
+            for (i, yi) in y.iter_mut().enumerate() { *yi = bs[s.perm[i] * w + c]; }
+
+            // L * z = P * b, column oriented
+            for j in 0..n
+            {
+                y[j] = y[j] / lx[lp[j]];
+                for r in lp[j] + 1..lp[j + 1] { y[li[r]] = y[li[r]] - lx[r] * y[j]; }
+            }
+
+            // L^T * y = z, column j of L is row j of L^T
+            for j in (0..n).rev()
+            {
+                let dot = (lp[j] + 1..lp[j + 1]).fold(T::zero(), |acc, r| acc + lx[r] * y[li[r]]);
+                y[j] = (y[j] - dot) / lx[lp[j]];
+            }
+
+            for (i, &yi) in y.iter().enumerate() { data[s.perm[i] * w + c] = yi; }
+        }
+
+        Ok(out)
+    }
+}
@@ -0,0 +1,213 @@
+use num_traits::Num;
+use crate::core::error::MatrixError;
+
+//----------Compressed Storage (shared by CSR and CSC)----------//
+
+// CSR stores rows as the major axis, CSC stores columns; everything below is written in terms of
+// major / minor so both formats share one implementation
+// entries of major line p are indices[indptr[p]..indptr[p + 1]], sorted, without duplicates
+#[derive(Debug, Clone, PartialEq)]
+pub(crate) struct Compressed<T>
+{
+    pub(crate) major: usize,
+    pub(crate) minor: usize,
+    pub(crate) indptr: Vec<usize>,
+    pub(crate) indices: Vec<usize>,
+    pub(crate) values: Vec<T>,
+}
+
+impl<T> Compressed<T>
+where T: Num + Copy
+{
+    // validates user-supplied arrays
+    pub(crate) fn from_parts(major: usize, minor: usize, indptr: Vec<usize>, indices: Vec<usize>, values: Vec<T>, op: &'static str) -> Result<Self, MatrixError>
+    {
+        let invalid = |reason| Err(MatrixError::InvalidStructure { op, reason });
+
+        if indptr.len() != major + 1 { return invalid("indptr length must be the major dim + 1"); }
+        if indices.len() != values.len() { return invalid("indices and values differ in length"); }
+        if indptr[0] != 0 || indptr[major] != indices.len() { return invalid("indptr must start at 0 and end at nnz"); }
+        if indptr.windows(2).any(|w| w[0] > w[1]) { return invalid("indptr is not non-decreasing"); }
+
+        for p in 0..major
+        {
+            let line = &indices[indptr[p]..indptr[p + 1]];
+            if line.iter().any(|&i| i >= minor) { return invalid("index out of bounds"); }
+            if line.windows(2).any(|w| w[0] >= w[1]) { return invalid("indices within a line must be strictly increasing"); }
+        }
+
+        Ok(Compressed { major, minor, indptr, indices, values })
+    }
+
+    // (major, minor, value) entries in any order, duplicates are summed
+    pub(crate) fn from_triplets(major: usize, minor: usize, entries: impl Iterator<Item = (usize, usize, T)> + Clone) -> Self
+    {
+        // counting sort by major index
+        let mut indptr = vec![0; major + 1];
+        for (p, _, _) in entries.clone() { indptr[p + 1] += 1; }
+        for p in 0..major { indptr[p + 1] += indptr[p]; }
+
+        let nnz = indptr[major];
+        let mut next = indptr.clone();
+        let mut scattered = vec![(0, T::zero()); nnz];
+
+        for (p, i, v) in entries
+        {
+            scattered[next[p]] = (i, v);
+            next[p] += 1;
+        }
+
+        // sort every line by minor index and fold duplicates
+        let mut out_ptr = vec![0; major + 1];
+        let mut indices = Vec::with_capacity(nnz);
+        let mut values = Vec::with_capacity(nnz);
+
+        for p in 0..major
+        {
+            let line = &mut scattered[indptr[p]..indptr[p + 1]];
+            line.sort_by_key(|&(i, _)| i);
+
+            for &(i, v) in line.iter()
+            {
+                if indices.len() > out_ptr[p] && indices.last() == Some(&i)
+                {
+                    let last = values.len() - 1;
+                    values[last] = values[last] + v;
+                }
+                else
+                {
+                    indices.push(i);
+                    values.push(v);
+                }
+            }
+
+            out_ptr[p + 1] = indices.len();
+        }
+
+        Compressed { major, minor, indptr: out_ptr, indices, values }
+    }
+
+    pub(crate) fn nnz(&self) -> usize { self.values.len() }
+
+    // (range into indices / values) of major line p
+    pub(crate) fn line(&self, p: usize) -> std::ops::Range<usize>
+    {
+        self.indptr[p]..self.indptr[p + 1]
+    }
+
+    pub(crate) fn get(&self, p: usize, i: usize) -> T
+    {
+        let range = self.line(p);
+        match self.indices[range.clone()].binary_search(&i)
+        {
+            Ok(k) => self.values[range.start + k],
+            Err(_) => T::zero(),
+        }
+    }
+
+    // same matrix with the axes swapped, i.e. CSR <-> CSC of one matrix, or the transpose in one format
+    pub(crate) fn swap_axes(&self) -> Self
+    {
+        let entries = (0..self.major).flat_map(|p| self.line(p).map(move |k| (p, k)))
+            .map(|(p, k)| (self.indices[k], p, self.values[k]));
+
+        // entries of each new line arrive in increasing old major order, so the sort inside is a no-op
+        Compressed::from_triplets(self.minor, self.major, entries)
+    }
+
+    // entry-wise sum of two structures with equal dims, exact zeros are dropped
+    pub(crate) fn add(&self, other: &Self) -> Self
+    {
+        let mut indptr = vec![0; self.major + 1];
+        let mut indices = Vec::with_capacity(self.nnz() + other.nnz());
+        let mut values = Vec::with_capacity(self.nnz() + other.nnz());
+
+        for p in 0..self.major
+        {
+            let (mut a, a_end) = (self.indptr[p], self.indptr[p + 1]);
+            let (mut b, b_end) = (other.indptr[p], other.indptr[p + 1]);
+
+            // merge two sorted lines
+            while a < a_end || b < b_end
+            {
+                let (i, v) = if b == b_end || (a < a_end && self.indices[a] < other.indices[b])
+                {
+                    a += 1;
+                    (self.indices[a - 1], self.values[a - 1])
+                }
+                else if a == a_end || other.indices[b] < self.indices[a]
+                {
+                    b += 1;
+                    (other.indices[b - 1], other.values[b - 1])
+                }
+                else
+                {
+                    a += 1;
+                    b += 1;
+                    (self.indices[a - 1], self.values[a - 1] + other.values[b - 1])
+                };
+
+                if v != T::zero()
+                {
+                    indices.push(i);
+                    values.push(v);
+                }
+            }
+
+            indptr[p + 1] = indices.len();
+        }
+
+        Compressed { major: self.major, minor: self.minor, indptr, indices, values }
+    }
+
+    // row-by-row product (Gustavson) with both operands and the result compressed along rows:
+    // line p of the result is the sum over k in line p of self of self[p, k] * (line k of other)
+    pub(crate) fn spgemm(&self, other: &Self) -> Self
+    {
+        let n = other.minor;
+        let mut indptr = vec![0; self.major + 1];
+        let mut indices = Vec::new();
+        let mut values = Vec::new();
+
+        // dense accumulator plus the list of touched minor indices
+        let mut acc = vec![T::zero(); n];
+        let mut seen = vec![false; n];
+        let mut touched = Vec::new();
+
+        for p in 0..self.major
+        {
+            for a in self.line(p)
+            {
+                let (k, x) = (self.indices[a], self.values[a]);
+
+                for b in other.line(k)
+                {
+                    let j = other.indices[b];
+                    if !seen[j]
+                    {
+                        seen[j] = true;
+                        touched.push(j);
+                    }
+                    acc[j] = acc[j] + x * other.values[b];
+                }
+            }
+
+            touched.sort_unstable();
+            for &j in touched.iter()
+            {
+                if acc[j] != T::zero()
+                {
+                    indices.push(j);
+                    values.push(acc[j]);
+                }
+                acc[j] = T::zero();
+                seen[j] = false;
+            }
+            touched.clear();
+
+            indptr[p + 1] = indices.len();
+        }
+
+        Compressed { major: self.major, minor: n, indptr, indices, values }
+    }
+}
@@ -0,0 +1,161 @@
+use std::fmt::{self, Display};
+use num_traits::Num;
+use crate::core::{error::MatrixError, matrix::{self, Matrix}};
+use crate::sparse::{compressed::Compressed, csc::CscMatrix, csr::CsrMatrix};
+
+//----------Coordinate (triplet) Format----------//
+
+// unordered (row, col, value) triplets, duplicates allowed and summed on conversion
+// cheap to build incrementally, convert to CSR / CSC for arithmetic
+#[derive(Debug, Clone, PartialEq)]
+pub struct CooMatrix<T>
+where T: Num + Copy
+{
+    rows: usize,
+    cols: usize,
+    row_idx: Vec<usize>,
+    col_idx: Vec<usize>,
+    values: Vec<T>,
+}
+
+impl<T> CooMatrix<T>
+where T: Num + Copy
+{
+    // empty (rows x cols) matrix
+    pub fn new(rows: usize, cols: usize) -> Result<Self, MatrixError>
+    {
+        if rows == 0 || cols == 0 { return Err(MatrixError::ZeroDimension { rows, cols }); }
+
+        Ok(CooMatrix { rows, cols, row_idx: Vec::new(), col_idx: Vec::new(), values: Vec::new() })
+    }
+
+    pub fn from_triplets(rows: usize, cols: usize, row_idx: Vec<usize>, col_idx: Vec<usize>, values: Vec<T>) -> Result<Self, MatrixError>
+    {
+        const OP: &str = "from_triplets";
+
+        if rows == 0 || cols == 0 { return Err(MatrixError::ZeroDimension { rows, cols }); }
+
+        if row_idx.len() != values.len() || col_idx.len() != values.len()
+        {
+            return Err(MatrixError::InvalidStructure { op: OP, reason: "row, col and value arrays differ in length" });
+        }
+
+        if let Some((&i, &j)) = row_idx.iter().zip(col_idx.iter()).find(|&(&i, &j)| i >= rows || j >= cols)
+        {
+            return Err(MatrixError::IndexOutOfBounds { op: OP, index: (i, j), shape: (rows, cols) });
+        }
+
+        Ok(CooMatrix { rows, cols, row_idx, col_idx, values })
+    }
+
+    // keeps the nonzero entries of a dense matrix, in row-major order
+    pub fn from_dense(a: &Matrix<T>) -> Self
+    {
+        let cols = a.cols();
+        let entries = a.as_slice().iter().enumerate()
+            .filter(|&(_, &x)| x != T::zero())
+            .map(|(k, &x)| (k / cols, k % cols, x));
+
+        CooMatrix::from_entries(a.rows(), cols, entries)
+    }
+
+    // trusted entries, indices already checked by the caller
+    pub(crate) fn from_entries(rows: usize, cols: usize, entries: impl Iterator<Item = (usize, usize, T)>) -> Self
+    {
+        let mut coo = CooMatrix { rows, cols, row_idx: Vec::new(), col_idx: Vec::new(), values: Vec::new() };
+
+        for (i, j, x) in entries
+        {
+            coo.row_idx.push(i);
+            coo.col_idx.push(j);
+            coo.values.push(x);
+        }
+
+        coo
+    }
+
+    // appends one entry, adds to any earlier entry at the same position once converted
+    pub fn push(&mut self, i: usize, j: usize, value: T)
+    {
+        self.try_push(i, j, value).unwrap_or_else(|e| panic!("{}", e))
+    }
+
+    pub fn try_push(&mut self, i: usize, j: usize, value: T) -> Result<(), MatrixError>
+    {
+        if i >= self.rows || j >= self.cols
+        {
+            return Err(MatrixError::IndexOutOfBounds { op: "push", index: (i, j), shape: self.shape() });
+        }
+
+        self.row_idx.push(i);
+        self.col_idx.push(j);
+        self.values.push(value);
+
+        Ok(())
+    }
+
+    // X----------X
+    // accessors
+
+    pub fn rows(&self) -> usize { self.rows }
+    pub fn cols(&self) -> usize { self.cols }
+    pub fn shape(&self) -> (usize, usize) { (self.rows, self.cols) }
+
+    // number of stored triplets, duplicates counted separately
+    pub fn nnz(&self) -> usize { self.values.len() }
+
+    pub fn row_indices(&self) -> &[usize] { &self.row_idx }
+    pub fn col_indices(&self) -> &[usize] { &self.col_idx }
+    pub fn values(&self) -> &[T] { &self.values }
+
+    // (row, col, value) triplets in insertion order
+    pub fn triplets(&self) -> impl Iterator<Item = (usize, usize, T)> + Clone + '_
+    {
+        self.row_idx.iter().zip(self.col_idx.iter()).zip(self.values.iter()).map(|((&i, &j), &x)| (i, j, x))
+    }
+
+    // X----------X
+    // conversions
+
+    // duplicates are summed
+    pub fn to_dense(&self) -> Matrix<T>
+    {
+        let mut out = Matrix::zeroes(self.rows, self.cols).unwrap();
+        let data = out.as_mut_slice();
+
+        for (i, j, x) in self.triplets() { data[i * self.cols + j] = data[i * self.cols + j] + x; }
+
+        out
+    }
+
+    // duplicates are summed, rows come out sorted
+    pub fn to_csr(&self) -> CsrMatrix<T>
+    {
+        CsrMatrix::from_compressed(Compressed::from_triplets(self.rows, self.cols, self.triplets()))
+    }
+
+    // duplicates are summed, columns come out sorted
+    pub fn to_csc(&self) -> CscMatrix<T>
+    {
+        let entries = self.triplets().map(|(i, j, x)| (j, i, x));
+        CscMatrix::from_compressed(Compressed::from_triplets(self.cols, self.rows, entries))
+    }
+
+    // swaps the index arrays, no reordering needed
+    pub fn t(&self) -> CooMatrix<T>
+    {
+        CooMatrix { rows: self.cols, cols: self.rows, row_idx: self.col_idx.clone(), col_idx: self.row_idx.clone(), values: self.values.clone() }
+    }
+}
+
+// X----------X
+// impl fmt::Display trait for CooMatrix, prints the dense grid like Matrix
+impl<T> fmt::Display for CooMatrix<T>
+where T: Num + Copy + Display
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result
+    {
+        let dense = self.to_dense();
+        matrix::fmt_grid(f, self.rows, self.cols, |i, j| &dense.as_slice()[i * self.cols + j])
+    }
+}
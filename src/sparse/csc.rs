@@ -0,0 +1,198 @@
+use std::fmt::{self, Display};
+use std::ops;
+use num_traits::Num;
+use crate::core::{error::MatrixError, matrix::{self, Matrix}};
+use crate::sparse::{compressed::Compressed, coo::CooMatrix, csr::CsrMatrix};
+
+//----------Compressed Sparse Column----------//
+
+// column j holds row indices indices[indptr[j]..indptr[j + 1]] (sorted, no duplicates)
+// and the matching values; fast column access, the natural input for sparse factorisations
+#[derive(Debug, Clone, PartialEq)]
+pub struct CscMatrix<T>
+where T: Num + Copy
+{
+    inner: Compressed<T>,
+}
+
+impl<T> CscMatrix<T>
+where T: Num + Copy
+{
+    // validates the arrays, see InvalidStructure for what is checked
+    pub fn from_parts(rows: usize, cols: usize, indptr: Vec<usize>, indices: Vec<usize>, values: Vec<T>) -> Result<Self, MatrixError>
+    {
+        if rows == 0 || cols == 0 { return Err(MatrixError::ZeroDimension { rows, cols }); }
+
+        Ok(CscMatrix { inner: Compressed::from_parts(cols, rows, indptr, indices, values, "from_parts")? })
+    }
+
+    // keeps the nonzero entries of a dense matrix
+    pub fn from_dense(a: &Matrix<T>) -> Self
+    {
+        let (rows, cols) = a.shape();
+        let entries = a.as_slice().iter().enumerate()
+            .filter(|&(_, &x)| x != T::zero())
+            .map(move |(k, &x)| (k % cols, k / cols, x));
+
+        CscMatrix { inner: Compressed::from_triplets(cols, rows, entries) }
+    }
+
+    pub(crate) fn from_compressed(inner: Compressed<T>) -> Self { CscMatrix { inner } }
+
+    // X----------X
+    // accessors
+
+    pub fn rows(&self) -> usize { self.inner.minor }
+    pub fn cols(&self) -> usize { self.inner.major }
+    pub fn shape(&self) -> (usize, usize) { (self.rows(), self.cols()) }
+
+    // number of stored entries
+    pub fn nnz(&self) -> usize { self.inner.nnz() }
+
+    pub fn indptr(&self) -> &[usize] { &self.inner.indptr }
+    pub fn indices(&self) -> &[usize] { &self.inner.indices }
+    pub fn values(&self) -> &[T] { &self.inner.values }
+
+    // zero for entries that are not stored, None out of bounds
+    pub fn get(&self, i: usize, j: usize) -> Option<T>
+    {
+        if i >= self.rows() || j >= self.cols() { return None; }
+        Some(self.inner.get(j, i))
+    }
+
+    // (row, value) pairs of column j
+    pub fn col(&self, j: usize) -> impl Iterator<Item = (usize, T)> + '_
+    {
+        self.inner.line(j).map(move |k| (self.inner.indices[k], self.inner.values[k]))
+    }
+
+    // X----------X
+    // conversions
+
+    pub fn to_dense(&self) -> Matrix<T>
+    {
+        let cols = self.cols();
+        let mut out = Matrix::zeroes(self.rows(), cols).unwrap();
+        let data = out.as_mut_slice();
+
+        for j in 0..cols
+        {
+            for (i, x) in self.col(j) { data[i * cols + j] = x; }
+        }
+
+        out
+    }
+
+    pub fn to_csr(&self) -> CsrMatrix<T>
+    {
+        CsrMatrix::from_compressed(self.inner.swap_axes())
+    }
+
+    pub fn to_coo(&self) -> CooMatrix<T>
+    {
+        let entries = (0..self.cols()).flat_map(|j| self.col(j).map(move |(i, x)| (i, j, x)));
+        CooMatrix::from_entries(self.rows(), self.cols(), entries)
+    }
+
+    // X----------X
+    // algebra
+
+    // A^T, still in CSC
+    pub fn t(&self) -> CscMatrix<T>
+    {
+        CscMatrix { inner: self.inner.swap_axes() }
+    }
+
+    // sparse * dense, scatters column j of A against row j of B
+    pub fn matmul(&self, b: &Matrix<T>) -> Matrix<T>
+    {
+        self.try_matmul(b).unwrap_or_else(|e| panic!("{}", e))
+    }
+
+    pub fn try_matmul(&self, b: &Matrix<T>) -> Result<Matrix<T>, MatrixError>
+    {
+        if self.cols() != b.rows()
+        {
+            return Err(MatrixError::InnerDimMismatch { op: "matmul", lhs: self.shape(), rhs: b.shape() });
+        }
+
+        let w = b.cols();
+        let bs = b.as_slice();
+        let mut out = Matrix::zeroes(self.rows(), w)?;
+        let data = out.as_mut_slice();
+
+        for j in 0..self.cols()
+        {
+            let brow = &bs[j * w..(j + 1) * w];
+            for (i, x) in self.col(j)
+            {
+                for (o, &y) in data[i * w..(i + 1) * w].iter_mut().zip(brow.iter()) { *o = *o + x * y; }
+            }
+        }
+
+        Ok(out)
+    }
+
+    // sparse * sparse, result stays sparse
+    pub fn matmul_sparse(&self, other: &CscMatrix<T>) -> CscMatrix<T>
+    {
+        self.try_matmul_sparse(other).unwrap_or_else(|e| panic!("{}", e))
+    }
+
+    pub fn try_matmul_sparse(&self, other: &CscMatrix<T>) -> Result<CscMatrix<T>, MatrixError>
+    {
+        if self.cols() != other.rows()
+        {
+            return Err(MatrixError::InnerDimMismatch { op: "matmul_sparse", lhs: self.shape(), rhs: other.shape() });
+        }
+
+        // CSC arrays of A * B are the CSR arrays of B^T * A^T
+        Ok(CscMatrix { inner: other.inner.spgemm(&self.inner) })
+    }
+
+    // fallible addition, entries that cancel to zero are not stored
+    pub fn try_add(&self, rhs: &CscMatrix<T>) -> Result<CscMatrix<T>, MatrixError>
+    {
+        if self.shape() != rhs.shape()
+        {
+            return Err(MatrixError::ShapeMismatch { op: "Add", lhs: self.shape(), rhs: rhs.shape() });
+        }
+
+        Ok(CscMatrix { inner: self.inner.add(&rhs.inner) })
+    }
+}
+
+// X----------X
+// impl ops::Add for references, panics on a shape mismatch
+impl<T> ops::Add<&CscMatrix<T>> for &CscMatrix<T>
+where T: Num + Copy
+{
+    type Output = CscMatrix<T>;
+
+    fn add(self, rhs: &CscMatrix<T>) -> CscMatrix<T>
+    {
+        self.try_add(rhs).unwrap_or_else(|e| panic!("{}", e))
+    }
+}
+
+// X----------X
+// impl fmt::Display trait for CscMatrix, prints the dense grid like Matrix
+impl<T> fmt::Display for CscMatrix<T>
+where T: Num + Copy + Display
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result
+    {
+        let zero = T::zero();
+        let values = self.values();
+
+        matrix::fmt_grid(f, self.rows(), self.cols(), |i, j|
+        {
+            let range = self.inner.line(j);
+            match self.inner.indices[range.clone()].binary_search(&i)
+            {
+                Ok(k) => &values[range.start + k],
+                Err(_) => &zero,
+            }
+        })
+    }
+}
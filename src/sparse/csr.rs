@@ -0,0 +1,195 @@
+use std::fmt::{self, Display};
+use std::ops;
+use num_traits::Num;
+use crate::core::{error::MatrixError, matrix::{self, Matrix}};
+use crate::sparse::{compressed::Compressed, coo::CooMatrix, csc::CscMatrix};
+
+//----------Compressed Sparse Row----------//
+
+// row i holds column indices indices[indptr[i]..indptr[i + 1]] (sorted, no duplicates)
+// and the matching values; fast row access and A * x
+#[derive(Debug, Clone, PartialEq)]
+pub struct CsrMatrix<T>
+where T: Num + Copy
+{
+    inner: Compressed<T>,
+}
+
+impl<T> CsrMatrix<T>
+where T: Num + Copy
+{
+    // validates the arrays, see InvalidStructure for what is checked
+    pub fn from_parts(rows: usize, cols: usize, indptr: Vec<usize>, indices: Vec<usize>, values: Vec<T>) -> Result<Self, MatrixError>
+    {
+        if rows == 0 || cols == 0 { return Err(MatrixError::ZeroDimension { rows, cols }); }
+
+        Ok(CsrMatrix { inner: Compressed::from_parts(rows, cols, indptr, indices, values, "from_parts")? })
+    }
+
+    // keeps the nonzero entries of a dense matrix
+    pub fn from_dense(a: &Matrix<T>) -> Self
+    {
+        let (rows, cols) = a.shape();
+        let entries = a.as_slice().iter().enumerate()
+            .filter(|&(_, &x)| x != T::zero())
+            .map(move |(k, &x)| (k / cols, k % cols, x));
+
+        CsrMatrix { inner: Compressed::from_triplets(rows, cols, entries) }
+    }
+
+    pub(crate) fn from_compressed(inner: Compressed<T>) -> Self { CsrMatrix { inner } }
+
+    // X----------X
+    // accessors
+
+    pub fn rows(&self) -> usize { self.inner.major }
+    pub fn cols(&self) -> usize { self.inner.minor }
+    pub fn shape(&self) -> (usize, usize) { (self.rows(), self.cols()) }
+
+    // number of stored entries
+    pub fn nnz(&self) -> usize { self.inner.nnz() }
+
+    pub fn indptr(&self) -> &[usize] { &self.inner.indptr }
+    pub fn indices(&self) -> &[usize] { &self.inner.indices }
+    pub fn values(&self) -> &[T] { &self.inner.values }
+
+    // zero for entries that are not stored, None out of bounds
+    pub fn get(&self, i: usize, j: usize) -> Option<T>
+    {
+        if i >= self.rows() || j >= self.cols() { return None; }
+        Some(self.inner.get(i, j))
+    }
+
+    // (column, value) pairs of row i
+    pub fn row(&self, i: usize) -> impl Iterator<Item = (usize, T)> + '_
+    {
+        self.inner.line(i).map(move |k| (self.inner.indices[k], self.inner.values[k]))
+    }
+
+    // X----------X
+    // conversions
+
+    pub fn to_dense(&self) -> Matrix<T>
+    {
+        let cols = self.cols();
+        let mut out = Matrix::zeroes(self.rows(), cols).unwrap();
+        let data = out.as_mut_slice();
+
+        for i in 0..self.rows()
+        {
+            for (j, x) in self.row(i) { data[i * cols + j] = x; }
+        }
+
+        out
+    }
+
+    pub fn to_csc(&self) -> CscMatrix<T>
+    {
+        CscMatrix::from_compressed(self.inner.swap_axes())
+    }
+
+    pub fn to_coo(&self) -> CooMatrix<T>
+    {
+        let entries = (0..self.rows()).flat_map(|i| self.row(i).map(move |(j, x)| (i, j, x)));
+        CooMatrix::from_entries(self.rows(), self.cols(), entries)
+    }
+
+    // X----------X
+    // algebra
+
+    // A^T, still in CSR
+    pub fn t(&self) -> CsrMatrix<T>
+    {
+        CsrMatrix { inner: self.inner.swap_axes() }
+    }
+
+    // sparse * dense, one pass over the stored entries
+    pub fn matmul(&self, b: &Matrix<T>) -> Matrix<T>
+    {
+        self.try_matmul(b).unwrap_or_else(|e| panic!("{}", e))
+    }
+
+    pub fn try_matmul(&self, b: &Matrix<T>) -> Result<Matrix<T>, MatrixError>
+    {
+        if self.cols() != b.rows()
+        {
+            return Err(MatrixError::InnerDimMismatch { op: "matmul", lhs: self.shape(), rhs: b.shape() });
+        }
+
+        let w = b.cols();
+        let bs = b.as_slice();
+        let mut out = Matrix::zeroes(self.rows(), w)?;
+
+        for (i, row) in out.as_mut_slice().chunks_exact_mut(w).enumerate()
+        {
+            for (k, x) in self.row(i)
+            {
+                for (o, &y) in row.iter_mut().zip(bs[k * w..(k + 1) * w].iter()) { *o = *o + x * y; }
+            }
+        }
+
+        Ok(out)
+    }
+
+    // sparse * sparse, result stays sparse
+    pub fn matmul_sparse(&self, other: &CsrMatrix<T>) -> CsrMatrix<T>
+    {
+        self.try_matmul_sparse(other).unwrap_or_else(|e| panic!("{}", e))
+    }
+
+    pub fn try_matmul_sparse(&self, other: &CsrMatrix<T>) -> Result<CsrMatrix<T>, MatrixError>
+    {
+        if self.cols() != other.rows()
+        {
+            return Err(MatrixError::InnerDimMismatch { op: "matmul_sparse", lhs: self.shape(), rhs: other.shape() });
+        }
+
+        Ok(CsrMatrix { inner: self.inner.spgemm(&other.inner) })
+    }
+
+    // fallible addition, entries that cancel to zero are not stored
+    pub fn try_add(&self, rhs: &CsrMatrix<T>) -> Result<CsrMatrix<T>, MatrixError>
+    {
+        if self.shape() != rhs.shape()
+        {
+            return Err(MatrixError::ShapeMismatch { op: "Add", lhs: self.shape(), rhs: rhs.shape() });
+        }
+
+        Ok(CsrMatrix { inner: self.inner.add(&rhs.inner) })
+    }
+}
+
+// X----------X
+// impl ops::Add for references, panics on a shape mismatch
+impl<T> ops::Add<&CsrMatrix<T>> for &CsrMatrix<T>
+where T: Num + Copy
+{
+    type Output = CsrMatrix<T>;
+
+    fn add(self, rhs: &CsrMatrix<T>) -> CsrMatrix<T>
+    {
+        self.try_add(rhs).unwrap_or_else(|e| panic!("{}", e))
+    }
+}
+
+// X----------X
+// impl fmt::Display trait for CsrMatrix, prints the dense grid like Matrix
+impl<T> fmt::Display for CsrMatrix<T>
+where T: Num + Copy + Display
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result
+    {
+        let zero = T::zero();
+        let values = self.values();
+
+        matrix::fmt_grid(f, self.rows(), self.cols(), |i, j|
+        {
+            let range = self.inner.line(i);
+            match self.inner.indices[range.clone()].binary_search(&j)
+            {
+                Ok(k) => &values[range.start + k],
+                Err(_) => &zero,
+            }
+        })
+    }
+}
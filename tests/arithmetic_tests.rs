@@ -58,6 +58,7 @@ where
     assert_eq!(c.as_slice(), a.as_slice());
 }
 
+#[allow(clippy::eq_op)]
 fn test_subtract_from_self<T>()
 where
     T: Num + Copy + Clone + PartialEq + Debug + Default + Zero + From<u8>,
@@ -0,0 +1,155 @@
+use linalgae_rs::{core::matrix::Matrix, ops::gemm::Transpose};
+use num_traits::Num;
+use std::fmt::Debug;
+use std::num::Wrapping;
+
+mod common;
+use common::filled_int;
+
+// -------- Generic Test Helpers --------
+
+// reference triple loop over raw slices
+fn reference<T>(a: &Matrix<T>, b: &Matrix<T>) -> Vec<T>
+where
+    T: Num + Copy,
+{
+    let (m, k) = (a.rows(), a.cols());
+    let n = b.cols();
+    let mut out = vec![T::zero(); m * n];
+    for i in 0..m {
+        for j in 0..n {
+            let mut acc = T::zero();
+            for p in 0..k {
+                acc = acc + a.as_slice()[i * k + p] * b.as_slice()[p * n + j];
+            }
+            out[i * n + j] = acc;
+        }
+    }
+    out
+}
+
+fn test_blocked_matches_reference<T>(m: usize, k: usize, n: usize)
+where
+    T: Num + Copy + PartialEq + Debug + From<u8> + std::ops::AddAssign,
+{
+    let a = filled_int::<T>(m, k, 1);
+    let b = filled_int::<T>(k, n, 2);
+    let c = a.matmul(&b);
+    assert_eq!(c.shape(), (m, n));
+    assert_eq!(c.as_slice(), &reference(&a, &b)[..]);
+}
+
+fn test_blocked_transposed_operands<T>()
+where
+    T: Num + Copy + PartialEq + Debug + Default + From<u8> + std::ops::AddAssign,
+{
+    let a = filled_int::<T>(70, 90, 3);
+    let b = filled_int::<T>(110, 90, 4);
+    let expected = reference(&a, &b.t());
+    assert_eq!(a.matmul_nt(&b).as_slice(), &expected[..]);
+    assert_eq!(a.t().matmul_tn(&b.t()).as_slice(), &expected[..]);
+}
+
+fn test_blocked_gemm_alpha_beta<T>()
+where
+    T: Num + Copy + PartialEq + Debug + From<u8> + std::ops::AddAssign,
+{
+    let a = filled_int::<T>(65, 80, 5);
+    let b = filled_int::<T>(80, 67, 6);
+    let c0 = filled_int::<T>(65, 67, 7);
+    let mut c = c0.clone();
+    Matrix::gemm(T::from(2), &a, Transpose::No, &b, Transpose::No, T::from(3), &mut c);
+
+    let ab = reference(&a, &b);
+    for (i, val) in c.as_slice().iter().enumerate() {
+        assert_eq!(*val, T::from(2) * ab[i] + T::from(3) * c0.as_slice()[i]);
+    }
+}
+
+// -------- f64 Tests --------
+
+#[test]
+fn test_blocked_square_f64() { test_blocked_matches_reference::<f64>(128, 128, 128); }
+
+#[test]
+fn test_blocked_ragged_edges_f64() { test_blocked_matches_reference::<f64>(67, 301, 1030); }
+
+#[test]
+fn test_blocked_tall_skinny_f64() { test_blocked_matches_reference::<f64>(515, 70, 9); }
+
+#[test]
+fn test_blocked_transposed_operands_f64() { test_blocked_transposed_operands::<f64>(); }
+
+#[test]
+fn test_blocked_gemm_alpha_beta_f64() { test_blocked_gemm_alpha_beta::<f64>(); }
+
+// -------- f32 Tests --------
+
+#[test]
+fn test_blocked_square_f32() { test_blocked_matches_reference::<f32>(96, 96, 96); }
+
+#[test]
+fn test_blocked_ragged_edges_f32() { test_blocked_matches_reference::<f32>(129, 257, 65); }
+
+#[test]
+fn test_blocked_transposed_operands_f32() { test_blocked_transposed_operands::<f32>(); }
+
+// -------- i32 Tests --------
+
+#[test]
+fn test_blocked_square_i32() { test_blocked_matches_reference::<i32>(100, 100, 100); }
+
+#[test]
+fn test_blocked_ragged_edges_i32() { test_blocked_matches_reference::<i32>(73, 263, 81); }
+
+#[test]
+fn test_blocked_transposed_operands_i32() { test_blocked_transposed_operands::<i32>(); }
+
+#[test]
+fn test_blocked_gemm_alpha_beta_i32() { test_blocked_gemm_alpha_beta::<i32>(); }
+
+#[test]
+fn test_integer_matmul_keeps_naive_order() {
+    // k > KC, so the blocked kernel would sum the tail k >= 256 on its own
+    // (-2e9 + -2e9 overflows in debug), the i-k-j loop keeps a running sum that stays in range
+    let (m, k, n) = (64, 258, 64);
+    let mut a = Matrix::<i32>::zeroes(m, k).unwrap();
+    let mut b = Matrix::<i32>::zeroes(k, n).unwrap();
+    for i in 0..m {
+        a[(i, 0)] = 1;
+        a[(i, 256)] = -1;
+        a[(i, 257)] = -1;
+    }
+    for j in 0..n {
+        b[(0, j)] = 2_000_000_000;
+        b[(256, j)] = 2_000_000_000;
+        b[(257, j)] = 2_000_000_000;
+    }
+    assert!(m * k * n >= 64 * 64 * 64);
+    assert!(a.matmul(&b).as_slice().iter().all(|&x| x == -2_000_000_000));
+}
+
+#[test]
+fn test_matmul_other_num_types() {
+    // any Num + Copy + AddAssign type is accepted and runs the i-k-j loop
+    let a = filled_int::<i32>(70, 260, 1);
+    let b = filled_int::<i32>(260, 66, 2);
+    let wrap = |m: &Matrix<i32>| Matrix::new(m.rows(), m.cols(), m.as_slice().iter().map(|&x| Wrapping(x)).collect()).unwrap();
+    assert_eq!(wrap(&a).matmul(&wrap(&b)), wrap(&a.matmul(&b)));
+}
+
+// -------- i64 Tests --------
+
+#[test]
+fn test_blocked_square_i64() { test_blocked_matches_reference::<i64>(80, 90, 100); }
+
+// -------- View Tests --------
+
+#[test]
+fn test_blocked_on_sub_views() {
+    let big = filled_int::<f64>(150, 150, 8);
+    let a = big.slice(10..120, 5..105);
+    let b = big.slice(20..120, 30..140);
+    let c = a.matmul(&b);
+    assert_eq!(c.as_slice(), &reference(&a.to_owned(), &b.to_owned())[..]);
+}
@@ -0,0 +1,227 @@
+use linalgae_rs::{matrix, core::{error::MatrixError, matrix::Matrix}};
+use num_traits::Float;
+use std::fmt::Debug;
+use std::ops::AddAssign;
+
+mod common;
+use common::{assert_close, spd, v};
+
+// -------- Generic Test Helpers --------
+
+fn test_cholesky_reconstructs<T>(tol: T)
+where
+    T: Float + Debug + Default + AddAssign,
+{
+    let a = spd::<T>(5);
+    let chol = a.cholesky();
+    let l = chol.l();
+
+    for i in 0..5 {
+        assert!(l[(i, i)] > T::zero());
+        for j in (i + 1)..5 {
+            assert_eq!(l[(i, j)], T::zero());
+        }
+    }
+
+    assert_close(&l.matmul(&l.t()), &a, tol);
+}
+
+fn test_cholesky_solve<T>(tol: T)
+where
+    T: Float + Debug + Default + AddAssign,
+{
+    let a = spd::<T>(4);
+    let x = matrix![[v(1.0), v(2.0)], [v(-1.0), v(0.0)], [v(0.5), v(1.0)], [v(3.0), v(-2.0)]];
+    assert_close(&a.cholesky().solve(&a.matmul(&x)), &x, tol);
+}
+
+fn test_cholesky_log_det<T>(tol: T)
+where
+    T: Float + Debug + Default + AddAssign,
+{
+    let a = spd::<T>(4);
+    let chol = a.cholesky();
+    assert!((chol.log_det() - a.determinant().ln()).abs() <= tol);
+    assert!((chol.det() / a.determinant() - T::one()).abs() <= tol);
+}
+
+fn test_rank_one_update_downdate<T>(tol: T)
+where
+    T: Float + Debug + Default + AddAssign,
+{
+    let a = spd::<T>(5);
+    let x = matrix![[v(1.0)], [v(-2.0)], [v(0.5)], [v(0.0)], [v(3.0)]];
+    let xxt = x.matmul(&x.t());
+
+    let mut chol = a.cholesky();
+    chol.rank_one_update(&x);
+    let updated = &a + &xxt;
+    assert_close(&chol.l().matmul(&chol.l().t()), &updated, tol);
+    assert_close(chol.l(), updated.cholesky().l(), tol);
+
+    chol.rank_one_downdate(&x);
+    assert_close(&chol.l().matmul(&chol.l().t()), &a, tol);
+}
+
+// -------- f64 Tests --------
+
+#[test]
+fn test_cholesky_reconstructs_f64() { test_cholesky_reconstructs::<f64>(1e-10); }
+
+#[test]
+fn test_cholesky_solve_f64() { test_cholesky_solve::<f64>(1e-10); }
+
+#[test]
+fn test_cholesky_log_det_f64() { test_cholesky_log_det::<f64>(1e-10); }
+
+#[test]
+fn test_rank_one_update_downdate_f64() { test_rank_one_update_downdate::<f64>(1e-10); }
+
+// -------- f32 Tests --------
+
+#[test]
+fn test_cholesky_reconstructs_f32() { test_cholesky_reconstructs::<f32>(1e-3); }
+
+#[test]
+fn test_cholesky_solve_f32() { test_cholesky_solve::<f32>(1e-3); }
+
+#[test]
+fn test_cholesky_log_det_f32() { test_cholesky_log_det::<f32>(1e-3); }
+
+#[test]
+fn test_rank_one_update_downdate_f32() { test_rank_one_update_downdate::<f32>(1e-3); }
+
+// -------- Cholesky Edge Cases --------
+
+#[test]
+fn test_cholesky_log_det_no_overflow() {
+    // det = 1e400 overflows f64, the log does not
+    let a = Matrix::new(2, 2, vec![1e200, 0.0, 0.0, 1e200]).unwrap();
+    let chol = a.cholesky();
+    assert!(chol.det().is_infinite());
+    assert!((chol.log_det() - 400.0 * 10f64.ln()).abs() < 1e-9);
+}
+
+#[test]
+fn test_downdate_failure_keeps_factor() {
+    let a = matrix![[4.0, 2.0], [2.0, 3.0]];
+    let mut chol = a.cholesky();
+    let before = chol.clone();
+
+    // A - x x^T has a negative eigenvalue
+    let err = chol.try_rank_one_downdate(&matrix![[3.0], [0.0]]).unwrap_err();
+    assert_eq!(err, MatrixError::NotPositiveDefinite { op: "rank_one_downdate" });
+    assert_eq!(chol, before);
+}
+
+// -------- LDL^T Tests --------
+
+#[test]
+fn test_ldlt_indefinite_reconstructs() {
+    let a = matrix![
+        [1.0, 2.0, 0.0, 3.0],
+        [2.0, -1.0, 4.0, 0.0],
+        [0.0, 4.0, 0.5, -2.0],
+        [3.0, 0.0, -2.0, -3.0]
+    ];
+    let f = a.ldlt();
+    let p = f.p();
+    let lhs = p.matmul(&a).matmul(&p.t());
+    let rhs = f.l().matmul(&f.d()).matmul(&f.l().t());
+    assert_close(&lhs, &rhs, 1e-12);
+    assert!((f.det() - a.determinant()).abs() < 1e-9);
+}
+
+#[test]
+fn test_ldlt_zero_diagonal_needs_2x2_pivot() {
+    // no 1x1 pivot exists, Cholesky and plain LDL^T both fail here
+    let a = matrix![[0.0, 1.0], [1.0, 0.0]];
+    let f = a.ldlt();
+    assert_eq!(f.block_sizes(), vec![2]);
+    assert_eq!(f.inertia(), (1, 1, 0));
+    assert_close(&f.solve(&matrix![[2.0], [3.0]]), &matrix![[3.0], [2.0]], 1e-15);
+}
+
+#[test]
+fn test_ldlt_solve() {
+    let a = matrix![
+        [0.0, 2.0, 1.0, 0.0, 1.0],
+        [2.0, 0.0, 0.0, 1.0, -1.0],
+        [1.0, 0.0, -4.0, 2.0, 0.0],
+        [0.0, 1.0, 2.0, 0.0, 3.0],
+        [1.0, -1.0, 0.0, 3.0, 1.0]
+    ];
+    let x = matrix![[1.0, 0.0], [2.0, -1.0], [-1.0, 1.0], [0.5, 2.0], [3.0, 0.0]];
+    assert_close(&a.ldlt().solve(&a.matmul(&x)), &x, 1e-10);
+}
+
+#[test]
+fn test_ldlt_inertia() {
+    // eigenvalues 3, 1, -2
+    let a = matrix![[2.0, 1.0, 0.0], [1.0, 2.0, 0.0], [0.0, 0.0, -2.0]];
+    assert_eq!(a.ldlt().inertia(), (2, 1, 0));
+
+    let spd_inertia = spd::<f64>(4).ldlt().inertia();
+    assert_eq!(spd_inertia, (4, 0, 0));
+}
+
+#[test]
+fn test_ldlt_singular() {
+    let a = matrix![[1.0, 1.0], [1.0, 1.0]];
+    let f = a.ldlt();
+    assert_eq!(f.inertia(), (1, 0, 1));
+    let err = f.try_solve(&matrix![[1.0], [1.0]]).unwrap_err();
+    assert_eq!(err, MatrixError::Singular { op: "solve" });
+}
+
+#[test]
+fn test_ldlt_singular_indefinite() {
+    // rank 2 with eigenvalues of both signs, rounding leaves a tiny nonzero last pivot
+    let a = matrix![[2.0, 6.0, 10.0], [6.0, 10.0, 14.0], [10.0, 14.0, 18.0]];
+    let f = a.ldlt();
+    assert!(f.is_singular());
+    assert_eq!(f.inertia(), (1, 1, 1));
+    let err = f.try_solve(&matrix![[1.0], [1.0], [1.0]]).unwrap_err();
+    assert_eq!(err, MatrixError::Singular { op: "solve" });
+}
+
+// -------- Error Tests --------
+
+#[test]
+fn test_try_cholesky_not_positive_definite() {
+    let a = matrix![[1.0, 2.0], [2.0, 1.0]];
+    let err = a.try_cholesky().unwrap_err();
+    assert_eq!(err, MatrixError::NotPositiveDefinite { op: "cholesky" });
+}
+
+#[test]
+fn test_try_cholesky_not_symmetric() {
+    let a = matrix![[4.0, 1.0], [0.0, 4.0]];
+    let err = a.try_cholesky().unwrap_err();
+    assert_eq!(err, MatrixError::NotSymmetric { op: "cholesky" });
+}
+
+#[test]
+fn test_try_cholesky_not_square() {
+    let err = Matrix::<f64>::ones(2, 3).unwrap().try_cholesky().unwrap_err();
+    assert_eq!(err, MatrixError::NotSquare { op: "cholesky", rows: 2, cols: 3 });
+}
+
+#[test]
+fn test_try_ldlt_not_symmetric() {
+    let err = matrix![[0.0, 1.0], [2.0, 0.0]].try_ldlt().unwrap_err();
+    assert_eq!(err, MatrixError::NotSymmetric { op: "ldlt" });
+}
+
+#[test]
+fn test_try_rank_one_update_bad_shape() {
+    let mut chol = spd::<f64>(3).cholesky();
+    let err = chol.try_rank_one_update(&Matrix::ones(1, 3).unwrap()).unwrap_err();
+    assert_eq!(err, MatrixError::ShapeMismatch { op: "rank_one_update", lhs: (3, 1), rhs: (1, 3) });
+}
+
+#[test]
+#[should_panic(expected = "not positive definite")]
+fn test_cholesky_negative_diagonal_panics() {
+    let _ = matrix![[-1.0]].cholesky();
+}
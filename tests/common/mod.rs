@@ -0,0 +1,55 @@
+// helpers shared by the integration tests, pulled in with `mod common;`
+// not every test binary uses every helper
+#![allow(dead_code)]
+
+use linalgae_rs::core::matrix::Matrix;
+use num_traits::{Float, Num};
+use std::fmt::Debug;
+use std::ops::AddAssign;
+
+// f64 literal as any float type
+pub fn v<T: Float>(x: f64) -> T {
+    T::from(x).unwrap()
+}
+
+// same shape and every entry within tol
+pub fn assert_close<T>(a: &Matrix<T>, b: &Matrix<T>, tol: T)
+where
+    T: Float + Debug,
+{
+    assert_eq!(a.shape(), b.shape());
+    for (x, y) in a.as_slice().iter().zip(b.as_slice().iter()) {
+        assert!((*x - *y).abs() <= tol, "{:?} vs {:?}", x, y);
+    }
+}
+
+// deterministic float fill in [-2, 2], quarter steps
+pub fn filled<T: Float>(rows: usize, cols: usize, seed: usize) -> Matrix<T> {
+    let data = (0..rows * cols).map(|i| v::<T>(((i * 7 + seed * 13) % 17) as f64 - 8.0) / v(4.0)).collect();
+    Matrix::new(rows, cols, data).unwrap()
+}
+
+// deterministic small non-negative integers, so integer products stay exact
+pub fn filled_int<T>(rows: usize, cols: usize, seed: usize) -> Matrix<T>
+where
+    T: Num + Copy + From<u8>,
+{
+    let data = (0..rows * cols).map(|i| T::from(((i * 7 + seed * 13) % 11) as u8)).collect();
+    Matrix::new(rows, cols, data).unwrap()
+}
+
+// B^T * B + I, symmetric positive definite
+pub fn spd<T>(n: usize) -> Matrix<T>
+where
+    T: Float + Default + AddAssign,
+{
+    let data = (0..n * n).map(|i| v::<T>(((i * 5 + 3) % 7) as f64 - 3.0)).collect();
+    let b = Matrix::new(n, n, data).unwrap();
+    &b.t().matmul(&b) + &Matrix::identity(n).unwrap()
+}
+
+// right-hand side(s) with entries in {-2, ..., 2}
+pub fn rhs<T: Float>(rows: usize, cols: usize) -> Matrix<T> {
+    let data = (0..rows * cols).map(|i| v::<T>(((i * 3 + 1) % 5) as f64 - 2.0)).collect();
+    Matrix::new(rows, cols, data).unwrap()
+}
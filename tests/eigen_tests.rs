@@ -0,0 +1,206 @@
+use linalgae_rs::{matrix, core::{error::MatrixError, matrix::Matrix}};
+use num_complex::Complex64;
+
+mod common;
+use common::{assert_close, filled};
+
+// -------- Test Helpers --------
+
+fn assert_complex_close(a: &Matrix<Complex64>, b: &Matrix<Complex64>, tol: f64) {
+    assert_eq!(a.shape(), b.shape());
+    for (x, y) in a.as_slice().iter().zip(b.as_slice().iter()) {
+        assert!((x - y).norm() <= tol, "{:?} vs {:?}", x, y);
+    }
+}
+
+fn to_complex(a: &Matrix<f64>) -> Matrix<Complex64> {
+    let data = a.as_slice().iter().map(|&x| Complex64::new(x, 0.0)).collect();
+    Matrix::new(a.rows(), a.cols(), data).unwrap()
+}
+
+fn diag(values: &[Complex64]) -> Matrix<Complex64> {
+    let n = values.len();
+    let mut d = Matrix::zeroes(n, n).unwrap();
+    for (i, &x) in values.iter().enumerate() {
+        d[(i, i)] = x;
+    }
+    d
+}
+
+// A * V = V * Lambda, with unit columns
+fn assert_eigenpairs(a: &Matrix<f64>, tol: f64) {
+    let eig = a.eigen();
+    let v = eig.vectors();
+
+    assert_complex_close(&to_complex(a).matmul(v), &v.matmul(&diag(eig.values())), tol);
+
+    for j in 0..a.cols() {
+        let norm: f64 = (0..a.rows()).map(|i| v[(i, j)].norm_sqr()).sum();
+        assert!((norm - 1.0).abs() < 1e-12);
+    }
+}
+
+// -------- Hessenberg Tests --------
+
+#[test]
+fn test_hessenberg_reconstructs() {
+    let a = filled(6, 6, 1);
+    let hess = a.hessenberg();
+    let (h, q) = (hess.h(), hess.q());
+
+    for i in 0..6usize {
+        for j in 0..i.saturating_sub(1) {
+            assert_eq!(h[(i, j)], 0.0);
+        }
+    }
+
+    assert_close(&q.t().matmul(q), &Matrix::identity(6).unwrap(), 1e-12);
+    assert_close(&q.matmul(h).matmul(&q.t()), &a, 1e-12);
+}
+
+// -------- Schur Tests --------
+
+#[test]
+fn test_schur_reconstructs() {
+    let a = filled(7, 7, 2);
+    let schur = a.schur();
+    let (t, z) = (schur.t(), schur.z());
+
+    assert_close(&z.t().matmul(z), &Matrix::identity(7).unwrap(), 1e-12);
+    assert_close(&z.matmul(t).matmul(&z.t()), &a, 1e-11);
+
+    // quasi triangular, a subdiagonal entry only inside a complex block
+    for i in 1..7 {
+        for j in 0..i - 1 {
+            assert_eq!(t[(i, j)], 0.0);
+        }
+        if t[(i, i - 1)] != 0.0 {
+            assert!(schur.eigenvalues()[i - 1].im > 0.0);
+        }
+    }
+}
+
+#[test]
+fn test_schur_of_rotation_is_a_single_block() {
+    // 90 degree rotation, eigenvalues +-i
+    let a = matrix![[0.0, -1.0], [1.0, 0.0]];
+    let schur = a.schur();
+    let values = schur.eigenvalues();
+
+    assert!((values[0] - Complex64::new(0.0, 1.0)).norm() < 1e-15);
+    assert!((values[1] - Complex64::new(0.0, -1.0)).norm() < 1e-15);
+    assert_close(&schur.z().matmul(schur.t()).matmul(&schur.z().t()), &a, 1e-15);
+}
+
+// -------- Eigen Tests --------
+
+#[test]
+fn test_eigen_real_spectrum() {
+    // upper triangular, eigenvalues are the diagonal
+    let a = matrix![[2.0, 1.0, 3.0], [0.0, -1.0, 4.0], [0.0, 0.0, 5.0]];
+    let mut re: Vec<f64> = a.eigenvalues().iter().map(|x| { assert_eq!(x.im, 0.0); x.re }).collect();
+    re.sort_by(|a, b| a.partial_cmp(b).unwrap());
+
+    assert!((re[0] + 1.0).abs() < 1e-12);
+    assert!((re[1] - 2.0).abs() < 1e-12);
+    assert!((re[2] - 5.0).abs() < 1e-12);
+    assert_eigenpairs(&a, 1e-12);
+}
+
+#[test]
+fn test_eigen_complex_pairs() {
+    let a = matrix![
+        [0.0, -2.0, 0.0, 0.0],
+        [2.0, 0.0, 0.0, 0.0],
+        [1.0, 0.0, 1.0, -3.0],
+        [0.0, 1.0, 3.0, 1.0]
+    ];
+    let values = a.eigenvalues();
+
+    // +-2i and 1 +- 3i, each pair adjacent with conjugates matching
+    for pair in values.chunks_exact(2) {
+        assert!(pair[0].im > 0.0);
+        assert!((pair[0] - pair[1].conj()).norm() < 1e-12);
+    }
+
+    let mut im: Vec<f64> = values.iter().map(|x| x.im.abs()).collect();
+    im.sort_by(|a, b| a.partial_cmp(b).unwrap());
+    assert!((im[0] - 2.0).abs() < 1e-12);
+    assert!((im[3] - 3.0).abs() < 1e-12);
+
+    assert_eigenpairs(&a, 1e-12);
+}
+
+#[test]
+fn test_eigen_general_matrices() {
+    for (n, seed) in [(3, 1), (5, 2), (8, 3), (12, 4), (20, 5)] {
+        assert_eigenpairs(&filled(n, n, seed), 1e-10);
+    }
+}
+
+#[test]
+fn test_eigenvalues_match_trace_and_determinant() {
+    let a = filled(6, 6, 7);
+    let values = a.eigenvalues();
+
+    let sum = values.iter().fold(Complex64::new(0.0, 0.0), |acc, &x| acc + x);
+    let prod = values.iter().fold(Complex64::new(1.0, 0.0), |acc, &x| acc * x);
+
+    assert!((sum - Complex64::new(a.trace(), 0.0)).norm() < 1e-10);
+    assert!((prod - Complex64::new(a.determinant(), 0.0)).norm() < 1e-8);
+}
+
+#[test]
+fn test_eigen_companion_matrix_roots() {
+    // companion matrix of (x - 1)(x - 2)(x^2 + 1) = x^4 - 3x^3 + 3x^2 - 3x + 2
+    let a = matrix![
+        [3.0, -3.0, 3.0, -2.0],
+        [1.0, 0.0, 0.0, 0.0],
+        [0.0, 1.0, 0.0, 0.0],
+        [0.0, 0.0, 1.0, 0.0]
+    ];
+    let values = a.eigenvalues();
+
+    for root in [Complex64::new(1.0, 0.0), Complex64::new(2.0, 0.0), Complex64::new(0.0, 1.0), Complex64::new(0.0, -1.0)] {
+        assert!(values.iter().any(|x| (x - root).norm() < 1e-10), "missing root {}", root);
+    }
+}
+
+#[test]
+fn test_spectral_radius() {
+    // stable discrete system, complex pair with |lambda|^2 = det = 0.26
+    let a = matrix![[0.5, 0.2], [-0.3, 0.4]];
+    let rho = a.eigen().spectral_radius();
+    assert!(rho < 1.0);
+    assert!((rho - 0.26f64.sqrt()).abs() < 1e-12);
+}
+
+#[test]
+fn test_eigen_one_by_one_and_zero() {
+    assert_eq!(matrix![[4.0]].eigenvalues(), vec![Complex64::new(4.0, 0.0)]);
+
+    let zero = Matrix::<f64>::zeroes(3, 3).unwrap().eigen();
+    assert!(zero.values().iter().all(|x| x.norm() == 0.0));
+}
+
+// -------- Error Tests --------
+
+#[test]
+fn test_try_eigen_not_square() {
+    let err = Matrix::<f64>::ones(2, 3).unwrap().try_eigen().unwrap_err();
+    assert_eq!(err, MatrixError::NotSquare { op: "eigen", rows: 2, cols: 3 });
+}
+
+#[test]
+fn test_try_schur_not_converged() {
+    // NaN never deflates
+    let a = matrix![[1.0, f64::NAN, 0.0], [1.0, 2.0, 1.0], [0.0, 1.0, 3.0]];
+    let err = a.try_schur().unwrap_err();
+    assert_eq!(err, MatrixError::NotConverged { op: "schur", iterations: 300 });
+}
+
+#[test]
+#[should_panic(expected = "Failed to converge")]
+fn test_eigenvalues_not_converged_panics() {
+    let _ = matrix![[0.0, 1.0, 2.0], [1.0, f64::NAN, 0.0], [0.0, 1.0, 1.0]].eigenvalues();
+}
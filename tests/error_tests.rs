@@ -0,0 +1,183 @@
+use linalgae_rs::core::{error::MatrixError, matrix::Matrix};
+use std::error::Error;
+
+// -------- Constructor Errors --------
+
+#[test]
+fn test_new_zero_rows() {
+    let err = Matrix::<f64>::new(0, 3, vec![]).unwrap_err();
+    assert_eq!(err, MatrixError::ZeroDimension { rows: 0, cols: 3 });
+}
+
+#[test]
+fn test_new_zero_cols() {
+    let err = Matrix::<i32>::new(3, 0, vec![]).unwrap_err();
+    assert_eq!(err, MatrixError::ZeroDimension { rows: 3, cols: 0 });
+}
+
+#[test]
+fn test_new_length_mismatch() {
+    let err = Matrix::<f64>::new(2, 2, vec![1.0, 2.0, 3.0]).unwrap_err();
+    assert_eq!(err, MatrixError::LengthMismatch { rows: 2, cols: 2, len: 3 });
+}
+
+#[test]
+fn test_full_zero_dimension() {
+    let err = Matrix::<f32>::full(0, 0, 1.0).unwrap_err();
+    assert_eq!(err, MatrixError::ZeroDimension { rows: 0, cols: 0 });
+}
+
+#[test]
+fn test_zeroes_zero_dimension() {
+    let err = Matrix::<i64>::zeroes(4, 0).unwrap_err();
+    assert_eq!(err, MatrixError::ZeroDimension { rows: 4, cols: 0 });
+}
+
+#[test]
+fn test_ones_zero_dimension() {
+    let err = Matrix::<f64>::ones(0, 2).unwrap_err();
+    assert_eq!(err, MatrixError::ZeroDimension { rows: 0, cols: 2 });
+}
+
+#[test]
+fn test_identity_zero_dimension() {
+    let err = Matrix::<f64>::identity(0).unwrap_err();
+    assert_eq!(err, MatrixError::ZeroDimension { rows: 0, cols: 0 });
+}
+
+// -------- Display Tests --------
+
+#[test]
+fn test_display_zero_dimension() {
+    let err = MatrixError::ZeroDimension { rows: 0, cols: 5 };
+    assert_eq!(err.to_string(), "Row dim should be positive! (got 0 x 5)");
+
+    let err = MatrixError::ZeroDimension { rows: 5, cols: 0 };
+    assert_eq!(err.to_string(), "Col dim should be positive! (got 5 x 0)");
+}
+
+#[test]
+fn test_display_length_mismatch() {
+    let err = MatrixError::LengthMismatch { rows: 2, cols: 3, len: 5 };
+    assert_eq!(err.to_string(), "Input vector does not match dimensions! (expected 2 x 3 = 6, got 5)");
+}
+
+#[test]
+fn test_display_shape_mismatch() {
+    let err = MatrixError::ShapeMismatch { op: "Add", lhs: (2, 3), rhs: (3, 2) };
+    assert_eq!(err.to_string(), "Matrix::Add: Dimension mismatch! ( (2, 3) vs (3, 2) )");
+}
+
+#[test]
+fn test_display_inner_dim_mismatch() {
+    let err = MatrixError::InnerDimMismatch { op: "matmul", lhs: (2, 3), rhs: (2, 2) };
+    assert_eq!(err.to_string(), "Matrix::matmul: lhs_cols (3) != rhs_rows (2) ( (2, 3) vs (2, 2) )");
+}
+
+#[test]
+fn test_display_slice_out_of_bounds() {
+    let err = MatrixError::SliceOutOfBounds { rows: (0, 4), cols: (1, 2), shape: (3, 3) };
+    assert_eq!(err.to_string(), "Matrix::slice: Range [0..4, 1..2] out of bounds for shape (3, 3)");
+}
+
+#[test]
+fn test_display_not_square() {
+    let err = MatrixError::NotSquare { op: "det", rows: 2, cols: 3 };
+    assert_eq!(err.to_string(), "Matrix::det: Expected a square matrix, got (2, 3)!");
+}
+
+#[test]
+fn test_display_underdetermined() {
+    let err = MatrixError::Underdetermined { op: "solve_least_squares", rows: 2, cols: 3 };
+    assert_eq!(err.to_string(), "Matrix::solve_least_squares: Expected rows >= cols, got (2, 3)!");
+}
+
+#[test]
+fn test_display_division_by_zero() {
+    let err = MatrixError::DivisionByZero { op: "Div" };
+    assert_eq!(err.to_string(), "Matrix::Div: Cannot divide by zero!");
+}
+
+#[test]
+fn test_display_singular() {
+    let err = MatrixError::Singular { op: "inverse" };
+    assert_eq!(err.to_string(), "Matrix::inverse: Matrix is singular!");
+}
+
+#[test]
+fn test_display_overflow() {
+    let err = MatrixError::Overflow { op: "det_exact" };
+    assert_eq!(err.to_string(), "Matrix::det_exact: Integer overflow in intermediate value!");
+}
+
+#[test]
+fn test_display_not_symmetric() {
+    let err = MatrixError::NotSymmetric { op: "cholesky" };
+    assert_eq!(err.to_string(), "Matrix::cholesky: Matrix is not symmetric!");
+}
+
+#[test]
+fn test_display_not_positive_definite() {
+    let err = MatrixError::NotPositiveDefinite { op: "cholesky" };
+    assert_eq!(err.to_string(), "Matrix::cholesky: Matrix is not positive definite!");
+}
+
+#[test]
+fn test_display_not_converged() {
+    let err = MatrixError::NotConverged { op: "svd", iterations: 60 };
+    assert_eq!(err.to_string(), "Matrix::svd: Failed to converge after 60 iterations!");
+}
+
+#[test]
+fn test_display_index_out_of_bounds() {
+    let err = MatrixError::IndexOutOfBounds { op: "push", index: (3, 1), shape: (2, 2) };
+    assert_eq!(err.to_string(), "Matrix::push: Index (3, 1) out of bounds for shape (2, 2)!");
+}
+
+#[test]
+fn test_display_invalid_structure() {
+    let err = MatrixError::InvalidStructure { op: "from_parts", reason: "indptr is not non-decreasing" };
+    assert_eq!(err.to_string(), "Matrix::from_parts: Invalid sparse structure, indptr is not non-decreasing!");
+}
+
+#[test]
+fn test_display_invalid_parameter() {
+    let err = MatrixError::InvalidParameter { op: "ssor", reason: "omega must lie in (0, 2)" };
+    assert_eq!(err.to_string(), "Matrix::ssor: Invalid parameter, omega must lie in (0, 2)!");
+}
+
+// -------- Trait Tests --------
+
+#[test]
+fn test_error_trait_object() {
+    let err: Box<dyn Error> = Box::new(MatrixError::DivisionByZero { op: "Div" });
+    assert!(err.source().is_none());
+    assert!(err.to_string().contains("Cannot divide by zero"));
+}
+
+#[test]
+fn test_question_mark_propagation() {
+    fn build() -> Result<Matrix<f64>, Box<dyn Error>> {
+        let m = Matrix::<f64>::zeroes(0, 1)?;
+        Ok(m)
+    }
+    assert!(build().is_err());
+}
+
+// -------- Panic Message Tests --------
+
+#[test]
+#[should_panic(expected = "Matrix::component_mul: Dimension mismatch! ( (2, 2) vs (2, 3) )")]
+fn test_component_mul_panic_reports_shapes() {
+    let a = Matrix::<f64>::zeroes(2, 2).unwrap();
+    let b = Matrix::<f64>::zeroes(2, 3).unwrap();
+    let _ = a.component_mul(&b);
+}
+
+#[test]
+#[should_panic(expected = "Matrix::AddAssign: Dimension mismatch! ( (2, 2) vs (3, 2) )")]
+fn test_add_assign_panic_reports_shapes() {
+    let mut a = Matrix::<i32>::zeroes(2, 2).unwrap();
+    let b = Matrix::<i32>::zeroes(3, 2).unwrap();
+    a += &b;
+}
@@ -0,0 +1,175 @@
+use linalgae_rs::{matrix, core::{error::MatrixError, matrix::Matrix}};
+use num_traits::{PrimInt, Signed};
+use std::fmt::Debug;
+
+// -------- Generic Test Functions --------
+
+fn v<T: PrimInt>(x: i64) -> T {
+    T::from(x).unwrap()
+}
+
+fn test_det_exact_small<T>()
+where
+    T: PrimInt + Signed + Debug,
+{
+    let a: Matrix<T> = matrix![[v(3), v(8)], [v(4), v(6)]];
+    assert_eq!(a.det_exact(), v(-14));
+
+    let b: Matrix<T> = matrix![
+        [v(6), v(1), v(1)],
+        [v(4), v(-2), v(5)],
+        [v(2), v(8), v(7)]
+    ];
+    assert_eq!(b.det_exact(), v(-306));
+}
+
+fn test_det_exact_row_swaps<T>()
+where
+    T: PrimInt + Signed + Debug,
+{
+    // zero leading entry forces a swap
+    let a: Matrix<T> = matrix![
+        [v(0), v(2), v(1)],
+        [v(4), v(-1), v(3)],
+        [v(2), v(5), v(-2)]
+    ];
+    assert_eq!(a.det_exact(), v(50));
+
+    // swapping two rows flips the sign
+    let b: Matrix<T> = matrix![
+        [v(4), v(-1), v(3)],
+        [v(0), v(2), v(1)],
+        [v(2), v(5), v(-2)]
+    ];
+    assert_eq!(b.det_exact(), v(-50));
+}
+
+fn test_det_exact_vandermonde<T>()
+where
+    T: PrimInt + Signed + Debug,
+{
+    // det = prod_{i < j} (x_j - x_i) for x = 1, 2, 3, 4 -> 1*2*3*1*2*1 = 12
+    let x = [1i8, 2, 3, 4];
+    let data = x.iter().flat_map(|&xi| (0..4).map(move |p| v::<T>(xi as i64).pow(p))).collect();
+    let vander = Matrix::new(4, 4, data).unwrap();
+    assert_eq!(vander.det_exact(), v(12));
+}
+
+fn test_det_exact_singular<T>()
+where
+    T: PrimInt + Signed + Debug,
+{
+    let a: Matrix<T> = matrix![
+        [v(1), v(2), v(3)],
+        [v(4), v(5), v(6)],
+        [v(7), v(8), v(9)]
+    ];
+    assert_eq!(a.det_exact(), T::zero());
+    assert_eq!(Matrix::<T>::zeroes(3, 3).unwrap().det_exact(), T::zero());
+}
+
+fn test_rank_exact<T>()
+where
+    T: PrimInt + Signed + Debug + Default,
+{
+    assert_eq!(Matrix::<T>::identity(5).unwrap().rank_exact(), 5);
+    assert_eq!(Matrix::<T>::zeroes(3, 4).unwrap().rank_exact(), 0);
+    assert_eq!(Matrix::<T>::ones(4, 2).unwrap().rank_exact(), 1);
+
+    // row 3 = row 1 + row 2
+    let a: Matrix<T> = matrix![
+        [v(1), v(2), v(0), v(-1)],
+        [v(0), v(1), v(3), v(2)],
+        [v(1), v(3), v(3), v(1)]
+    ];
+    assert_eq!(a.rank_exact(), 2);
+    assert_eq!(a.t().rank_exact(), 2);
+
+    // leading zero column is skipped
+    let b: Matrix<T> = matrix![[v(0), v(1), v(2)], [v(0), v(2), v(5)]];
+    assert_eq!(b.rank_exact(), 2);
+}
+
+// -------- i32 Tests --------
+
+#[test]
+fn test_det_exact_small_i32() { test_det_exact_small::<i32>(); }
+
+#[test]
+fn test_det_exact_row_swaps_i32() { test_det_exact_row_swaps::<i32>(); }
+
+#[test]
+fn test_det_exact_vandermonde_i32() { test_det_exact_vandermonde::<i32>(); }
+
+#[test]
+fn test_det_exact_singular_i32() { test_det_exact_singular::<i32>(); }
+
+#[test]
+fn test_rank_exact_i32() { test_rank_exact::<i32>(); }
+
+// -------- i64 Tests --------
+
+#[test]
+fn test_det_exact_small_i64() { test_det_exact_small::<i64>(); }
+
+#[test]
+fn test_det_exact_row_swaps_i64() { test_det_exact_row_swaps::<i64>(); }
+
+#[test]
+fn test_det_exact_vandermonde_i64() { test_det_exact_vandermonde::<i64>(); }
+
+#[test]
+fn test_det_exact_singular_i64() { test_det_exact_singular::<i64>(); }
+
+#[test]
+fn test_rank_exact_i64() { test_rank_exact::<i64>(); }
+
+// -------- Precision Tests --------
+
+#[test]
+fn test_det_exact_beyond_f64_precision() {
+    // products are ~9.2e18, far past the 2^53 mantissa; the exact answer is 1
+    let a: Matrix<i64> = matrix![[3037000499, 3037000498], [3037000500, 3037000499]];
+    assert_eq!(a.det_exact(), 1);
+}
+
+#[test]
+fn test_det_exact_matches_float_det() {
+    let data: Vec<i64> = (0..36).map(|i| (i * 7 % 11) - 5).collect();
+    let a = Matrix::new(6, 6, data.clone()).unwrap();
+    let f = Matrix::new(6, 6, data.iter().map(|&x| x as f64).collect()).unwrap();
+    assert!((a.det_exact() as f64 - f.determinant()).abs() < 1e-6);
+}
+
+// -------- Error Tests --------
+
+#[test]
+fn test_try_det_exact_overflow() {
+    let a: Matrix<i32> = matrix![[100000, 1], [1, 100000]];
+    let err = a.try_det_exact().unwrap_err();
+    assert_eq!(err, MatrixError::Overflow { op: "det_exact" });
+
+    // the same matrix fits in i64
+    let b: Matrix<i64> = matrix![[100000, 1], [1, 100000]];
+    assert_eq!(b.try_det_exact(), Ok(9_999_999_999));
+}
+
+#[test]
+fn test_try_rank_exact_overflow() {
+    let a: Matrix<i32> = matrix![[70000, 70000, 1], [70000, 1, 70000]];
+    let err = a.try_rank_exact().unwrap_err();
+    assert_eq!(err, MatrixError::Overflow { op: "rank_exact" });
+}
+
+#[test]
+fn test_try_det_exact_not_square() {
+    let err = Matrix::<i64>::ones(2, 3).unwrap().try_det_exact().unwrap_err();
+    assert_eq!(err, MatrixError::NotSquare { op: "det_exact", rows: 2, cols: 3 });
+}
+
+#[test]
+#[should_panic(expected = "Integer overflow")]
+fn test_det_exact_overflow_panics() {
+    let a: Matrix<i32> = matrix![[i32::MAX, 2], [2, i32::MAX]];
+    let _ = a.det_exact();
+}
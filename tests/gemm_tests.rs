@@ -0,0 +1,177 @@
+use linalgae_rs::{matrix, core::{error::MatrixError, matrix::Matrix}, ops::gemm::Transpose};
+use num_traits::Num;
+use std::fmt::Debug;
+
+// -------- Generic Test Helpers --------
+
+fn lhs<T>() -> Matrix<T>
+where
+    T: Num + Copy + From<u8>,
+{
+    matrix![[T::from(1), T::from(2), T::from(3)], [T::from(4), T::from(5), T::from(6)]]
+}
+
+fn rhs<T>() -> Matrix<T>
+where
+    T: Num + Copy + From<u8>,
+{
+    matrix![[T::from(2), T::from(0), T::from(1)], [T::from(1), T::from(3), T::from(0)]]
+}
+
+fn test_matmul_tn<T>()
+where
+    T: Num + Copy + PartialEq + Debug + Default + From<u8> + std::ops::AddAssign,
+{
+    let a = lhs::<T>();
+    let b = rhs::<T>();
+    let c = a.matmul_tn(&b);
+    assert_eq!(c.shape(), (3, 3));
+    assert_eq!(c, a.t().matmul(&b));
+}
+
+fn test_matmul_nt<T>()
+where
+    T: Num + Copy + PartialEq + Debug + Default + From<u8> + std::ops::AddAssign,
+{
+    let a = lhs::<T>();
+    let b = rhs::<T>();
+    let c = a.matmul_nt(&b);
+    assert_eq!(c.shape(), (2, 2));
+    assert_eq!(c, a.matmul(&b.t()));
+}
+
+fn test_matmul_tn_nt_into<T>()
+where
+    T: Num + Copy + PartialEq + Debug + Default + From<u8> + std::ops::AddAssign,
+{
+    let a = lhs::<T>();
+    let b = rhs::<T>();
+
+    let mut out = Matrix::<T>::full(3, 3, T::from(9)).unwrap();
+    a.matmul_tn_into(&b, &mut out);
+    assert_eq!(out, a.t().matmul(&b));
+
+    let mut out = Matrix::<T>::full(2, 2, T::from(9)).unwrap();
+    a.matmul_nt_into(&b, &mut out);
+    assert_eq!(out, a.matmul(&b.t()));
+}
+
+fn test_gemm_alpha_beta<T>()
+where
+    T: Num + Copy + PartialEq + Debug + Default + From<u8> + std::ops::AddAssign,
+{
+    let a = lhs::<T>();
+    let b = rhs::<T>();
+    let c0 = matrix![[T::from(1), T::from(1)], [T::from(1), T::from(1)]];
+
+    // c = 2 * a * b^T + 3 * c
+    let mut c = c0.clone();
+    Matrix::gemm(T::from(2), &a, Transpose::No, &b, Transpose::Yes, T::from(3), &mut c);
+
+    let expected = &(&a.matmul(&b.t()) * T::from(2)) + &(&c0 * T::from(3));
+    assert_eq!(c, expected);
+}
+
+fn test_gemm_both_transposed<T>()
+where
+    T: Num + Copy + PartialEq + Debug + Default + From<u8> + std::ops::AddAssign,
+{
+    let a = lhs::<T>();
+    let b = rhs::<T>();
+    let mut c = Matrix::<T>::zeroes(3, 3).unwrap();
+    Matrix::gemm(T::one(), &a, Transpose::Yes, &b.t(), Transpose::Yes, T::zero(), &mut c);
+    assert_eq!(c, a.t().matmul(&b));
+}
+
+fn test_gemm_on_views<T>()
+where
+    T: Num + Copy + PartialEq + Debug + Default + From<u8> + std::ops::AddAssign,
+{
+    let a = lhs::<T>();
+    let b = rhs::<T>();
+    let mut c = Matrix::<T>::zeroes(2, 2).unwrap();
+    Matrix::gemm(T::one(), &a.slice(0..2, 1..3), Transpose::No, &b.slice(0..2, 0..2), Transpose::No, T::zero(), &mut c);
+    assert_eq!(c, a.slice(0..2, 1..3).to_owned().matmul(&b.slice(0..2, 0..2).to_owned()));
+}
+
+// -------- f64 Tests --------
+
+#[test]
+fn test_matmul_tn_f64() { test_matmul_tn::<f64>(); }
+
+#[test]
+fn test_matmul_nt_f64() { test_matmul_nt::<f64>(); }
+
+#[test]
+fn test_matmul_tn_nt_into_f64() { test_matmul_tn_nt_into::<f64>(); }
+
+#[test]
+fn test_gemm_alpha_beta_f64() { test_gemm_alpha_beta::<f64>(); }
+
+#[test]
+fn test_gemm_both_transposed_f64() { test_gemm_both_transposed::<f64>(); }
+
+#[test]
+fn test_gemm_on_views_f64() { test_gemm_on_views::<f64>(); }
+
+// -------- i32 Tests --------
+
+#[test]
+fn test_matmul_tn_i32() { test_matmul_tn::<i32>(); }
+
+#[test]
+fn test_matmul_nt_i32() { test_matmul_nt::<i32>(); }
+
+#[test]
+fn test_matmul_tn_nt_into_i32() { test_matmul_tn_nt_into::<i32>(); }
+
+#[test]
+fn test_gemm_alpha_beta_i32() { test_gemm_alpha_beta::<i32>(); }
+
+#[test]
+fn test_gemm_both_transposed_i32() { test_gemm_both_transposed::<i32>(); }
+
+#[test]
+fn test_gemm_on_views_i32() { test_gemm_on_views::<i32>(); }
+
+// -------- Float-specific Edge Cases --------
+
+#[test]
+fn test_gemm_beta_zero_ignores_nan() {
+    let a = lhs::<f64>();
+    let b = rhs::<f64>();
+    let mut c = Matrix::full(2, 2, f64::NAN).unwrap();
+    Matrix::gemm(1.0, &a, Transpose::No, &b, Transpose::Yes, 0.0, &mut c);
+    assert_eq!(c, a.matmul(&b.t()));
+}
+
+// -------- Error Tests --------
+
+#[test]
+fn test_try_gemm_inner_mismatch() {
+    let a = lhs::<f64>();
+    let b = rhs::<f64>();
+    let mut c = Matrix::<f64>::zeroes(2, 3).unwrap();
+    let err = Matrix::try_gemm(1.0, &a, Transpose::No, &b, Transpose::No, 0.0, &mut c).unwrap_err();
+    assert_eq!(err, MatrixError::InnerDimMismatch { op: "gemm", lhs: (2, 3), rhs: (2, 3) });
+}
+
+#[test]
+fn test_try_gemm_output_mismatch() {
+    let a = lhs::<f64>();
+    let b = rhs::<f64>();
+    let mut c = Matrix::<f64>::full(3, 3, 7.0).unwrap();
+    let err = Matrix::try_gemm(1.0, &a, Transpose::No, &b, Transpose::Yes, 0.0, &mut c).unwrap_err();
+    assert_eq!(err, MatrixError::ShapeMismatch { op: "gemm", lhs: (2, 2), rhs: (3, 3) });
+
+    // output untouched on error
+    assert!(c.as_slice().iter().all(|&x| x == 7.0));
+}
+
+#[test]
+#[should_panic(expected = "lhs_cols")]
+fn test_matmul_tn_mismatch() {
+    let a = lhs::<f64>();
+    let b = Matrix::<f64>::zeroes(3, 3).unwrap();
+    let _ = a.matmul_tn(&b);
+}
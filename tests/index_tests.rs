@@ -0,0 +1,133 @@
+use linalgae_rs::{matrix, core::matrix::Matrix};
+use num_traits::Num;
+use std::fmt::Debug;
+
+// -------- Generic Test Functions --------
+
+fn test_index_read<T>()
+where
+    T: Num + Copy + PartialEq + Debug + From<u8>,
+{
+    let m = matrix![[T::from(1), T::from(2), T::from(3)], [T::from(4), T::from(5), T::from(6)]];
+    assert_eq!(m[(0, 0)], T::from(1));
+    assert_eq!(m[(0, 2)], T::from(3));
+    assert_eq!(m[(1, 0)], T::from(4));
+    assert_eq!(m[(1, 2)], T::from(6));
+}
+
+fn test_index_matches_slice<T>()
+where
+    T: Num + Copy + PartialEq + Debug + From<u8>,
+{
+    let m = matrix![[T::from(1), T::from(2)], [T::from(3), T::from(4)], [T::from(5), T::from(6)]];
+    for i in 0..m.rows() {
+        for j in 0..m.cols() {
+            assert_eq!(m[(i, j)], m.as_slice()[i * m.cols() + j]);
+        }
+    }
+}
+
+fn test_index_write<T>()
+where
+    T: Num + Copy + PartialEq + Debug + From<u8>,
+{
+    let mut m = Matrix::<T>::zeroes(2, 3).unwrap();
+    m[(1, 2)] = T::from(9);
+    m[(0, 1)] = T::from(7);
+    assert_eq!(m.as_slice(), &[T::zero(), T::from(7), T::zero(), T::zero(), T::zero(), T::from(9)]);
+}
+
+fn test_get<T>()
+where
+    T: Num + Copy + PartialEq + Debug + From<u8>,
+{
+    let m = matrix![[T::from(1), T::from(2)], [T::from(3), T::from(4)]];
+    assert_eq!(m.get(1, 0), Some(&T::from(3)));
+    assert_eq!(m.get(2, 0), None);
+    assert_eq!(m.get(0, 2), None);
+}
+
+fn test_get_mut<T>()
+where
+    T: Num + Copy + PartialEq + Debug + From<u8>,
+{
+    let mut m = matrix![[T::from(1), T::from(2)], [T::from(3), T::from(4)]];
+    *m.get_mut(0, 1).unwrap() = T::from(8);
+    assert_eq!(m[(0, 1)], T::from(8));
+    assert!(m.get_mut(0, 5).is_none());
+}
+
+fn test_get_unchecked<T>()
+where
+    T: Num + Copy + PartialEq + Debug + From<u8>,
+{
+    let mut m = matrix![[T::from(1), T::from(2)], [T::from(3), T::from(4)]];
+    unsafe {
+        assert_eq!(*m.get_unchecked(1, 1), T::from(4));
+        *m.get_unchecked_mut(1, 0) = T::from(6);
+    }
+    assert_eq!(m[(1, 0)], T::from(6));
+}
+
+// -------- f64 Tests --------
+
+#[test]
+fn test_index_read_f64() { test_index_read::<f64>(); }
+
+#[test]
+fn test_index_matches_slice_f64() { test_index_matches_slice::<f64>(); }
+
+#[test]
+fn test_index_write_f64() { test_index_write::<f64>(); }
+
+#[test]
+fn test_get_f64() { test_get::<f64>(); }
+
+#[test]
+fn test_get_mut_f64() { test_get_mut::<f64>(); }
+
+#[test]
+fn test_get_unchecked_f64() { test_get_unchecked::<f64>(); }
+
+// -------- i32 Tests --------
+
+#[test]
+fn test_index_read_i32() { test_index_read::<i32>(); }
+
+#[test]
+fn test_index_matches_slice_i32() { test_index_matches_slice::<i32>(); }
+
+#[test]
+fn test_index_write_i32() { test_index_write::<i32>(); }
+
+#[test]
+fn test_get_i32() { test_get::<i32>(); }
+
+#[test]
+fn test_get_mut_i32() { test_get_mut::<i32>(); }
+
+#[test]
+fn test_get_unchecked_i32() { test_get_unchecked::<i32>(); }
+
+// -------- Error Tests --------
+
+#[test]
+#[should_panic(expected = "Matrix::Index: row index 2 out of range for matrix of shape (2, 3)")]
+fn test_index_row_out_of_range() {
+    let m = Matrix::<f64>::zeroes(2, 3).unwrap();
+    let _ = m[(2, 0)];
+}
+
+#[test]
+#[should_panic(expected = "Matrix::Index: col index 3 out of range for matrix of shape (2, 3)")]
+fn test_index_col_out_of_range() {
+    let m = Matrix::<f64>::zeroes(2, 3).unwrap();
+    let _ = m[(1, 3)];
+}
+
+#[test]
+#[should_panic(expected = "Matrix::IndexMut: row index 5 out of range for matrix of shape (2, 2)")]
+fn test_index_mut_out_of_range() {
+    let mut m = Matrix::<i32>::zeroes(2, 2).unwrap();
+    m[(5, 0)] = 1;
+}
@@ -0,0 +1,179 @@
+use linalgae_rs::{matrix, core::{error::MatrixError, matrix::Matrix}};
+use num_traits::Num;
+use std::fmt::Debug;
+
+// -------- Generic Test Functions --------
+
+fn test_matmul_into<T>()
+where
+    T: Num + Copy + PartialEq + Debug + From<u8> + std::ops::AddAssign,
+{
+    let a = matrix![[T::from(1), T::from(2)], [T::from(3), T::from(4)]];
+    let b = matrix![[T::from(5), T::from(6)], [T::from(7), T::from(8)]];
+    let mut out = Matrix::<T>::full(2, 2, T::from(100)).unwrap();
+    let ptr = out.as_slice().as_ptr();
+
+    a.matmul_into(&b, &mut out);
+    assert_eq!(out, a.matmul(&b));
+
+    // buffer is reused, not reallocated
+    assert_eq!(out.as_slice().as_ptr(), ptr);
+}
+
+fn test_component_mul_into<T>()
+where
+    T: Num + Copy + PartialEq + Debug + From<u8>,
+{
+    let a = matrix![[T::from(1), T::from(2)], [T::from(3), T::from(4)]];
+    let b = matrix![[T::from(2), T::from(2)], [T::from(3), T::from(3)]];
+    let mut out = Matrix::<T>::zeroes(2, 2).unwrap();
+    a.component_mul_into(&b, &mut out);
+    assert_eq!(out, a.component_mul(&b));
+}
+
+fn test_apply_into<T>()
+where
+    T: Num + Copy + PartialEq + Debug + From<u8>,
+{
+    let a = matrix![[T::from(1), T::from(2), T::from(3)]];
+    let mut out = Matrix::<T>::zeroes(1, 3).unwrap();
+    a.apply_into(|x| x * x, &mut out);
+    assert_eq!(out.as_slice(), &[T::from(1), T::from(4), T::from(9)]);
+}
+
+fn test_add_sub_into<T>()
+where
+    T: Num + Copy + PartialEq + Debug + From<u8>,
+{
+    let a = matrix![[T::from(5), T::from(6)], [T::from(7), T::from(8)]];
+    let b = matrix![[T::from(1), T::from(2)], [T::from(3), T::from(4)]];
+    let mut out = Matrix::<T>::zeroes(2, 2).unwrap();
+
+    a.add_into(&b, &mut out);
+    assert_eq!(out, &a + &b);
+
+    a.sub_into(&b, &mut out);
+    assert_eq!(out, &a - &b);
+}
+
+fn test_scalar_into<T>()
+where
+    T: Num + Copy + PartialEq + Debug + From<u8>,
+{
+    let a = matrix![[T::from(2), T::from(4)], [T::from(6), T::from(8)]];
+    let mut out = Matrix::<T>::zeroes(2, 2).unwrap();
+
+    a.mul_scalar_into(T::from(3), &mut out);
+    assert_eq!(out, &a * T::from(3));
+
+    a.div_scalar_into(T::from(2), &mut out);
+    assert_eq!(out, &a / T::from(2));
+}
+
+fn test_into_reuses_buffer_in_loop<T>()
+where
+    T: Num + Copy + PartialEq + Debug + From<u8> + std::ops::AddAssign,
+{
+    let w = matrix![[T::from(1), T::from(0)], [T::from(0), T::from(1)]];
+    let mut x = matrix![[T::from(1)], [T::from(2)]];
+    let mut tmp = Matrix::<T>::zeroes(2, 1).unwrap();
+
+    for _ in 0..3
+    {
+        w.matmul_into(&x, &mut tmp);
+        x += &tmp;
+    }
+    assert_eq!(x.as_slice(), &[T::from(8), T::from(16)]);
+}
+
+// -------- f64 Tests --------
+
+#[test]
+fn test_matmul_into_f64() { test_matmul_into::<f64>(); }
+
+#[test]
+fn test_component_mul_into_f64() { test_component_mul_into::<f64>(); }
+
+#[test]
+fn test_apply_into_f64() { test_apply_into::<f64>(); }
+
+#[test]
+fn test_add_sub_into_f64() { test_add_sub_into::<f64>(); }
+
+#[test]
+fn test_scalar_into_f64() { test_scalar_into::<f64>(); }
+
+#[test]
+fn test_into_reuses_buffer_in_loop_f64() { test_into_reuses_buffer_in_loop::<f64>(); }
+
+// -------- i32 Tests --------
+
+#[test]
+fn test_matmul_into_i32() { test_matmul_into::<i32>(); }
+
+#[test]
+fn test_component_mul_into_i32() { test_component_mul_into::<i32>(); }
+
+#[test]
+fn test_apply_into_i32() { test_apply_into::<i32>(); }
+
+#[test]
+fn test_add_sub_into_i32() { test_add_sub_into::<i32>(); }
+
+#[test]
+fn test_scalar_into_i32() { test_scalar_into::<i32>(); }
+
+#[test]
+fn test_into_reuses_buffer_in_loop_i32() { test_into_reuses_buffer_in_loop::<i32>(); }
+
+// -------- Error Tests --------
+
+#[test]
+fn test_try_matmul_into_bad_output() {
+    let a = Matrix::<f64>::zeroes(2, 3).unwrap();
+    let b = Matrix::<f64>::zeroes(3, 4).unwrap();
+    let mut out = Matrix::<f64>::zeroes(4, 2).unwrap();
+    let err = a.try_matmul_into(&b, &mut out).unwrap_err();
+    assert_eq!(err, MatrixError::ShapeMismatch { op: "matmul", lhs: (2, 4), rhs: (4, 2) });
+}
+
+#[test]
+fn test_try_add_into_bad_operand() {
+    let a = Matrix::<f64>::zeroes(2, 2).unwrap();
+    let b = Matrix::<f64>::zeroes(2, 3).unwrap();
+    let mut out = Matrix::<f64>::zeroes(2, 2).unwrap();
+    let err = a.try_add_into(&b, &mut out).unwrap_err();
+    assert_eq!(err, MatrixError::ShapeMismatch { op: "add_into", lhs: (2, 2), rhs: (2, 3) });
+}
+
+#[test]
+fn test_try_apply_into_bad_output() {
+    let a = Matrix::<i32>::zeroes(2, 2).unwrap();
+    let mut out = Matrix::<i32>::zeroes(1, 4).unwrap();
+    let err = a.try_apply_into(|x| x, &mut out).unwrap_err();
+    assert_eq!(err, MatrixError::ShapeMismatch { op: "apply_into", lhs: (2, 2), rhs: (1, 4) });
+}
+
+#[test]
+fn test_try_div_scalar_into_zero() {
+    let a = Matrix::<f64>::ones(2, 2).unwrap();
+    let mut out = Matrix::<f64>::zeroes(2, 2).unwrap();
+    let err = a.try_div_scalar_into(0.0, &mut out).unwrap_err();
+    assert_eq!(err, MatrixError::DivisionByZero { op: "div_scalar_into" });
+}
+
+#[test]
+#[should_panic(expected = "Dimension mismatch")]
+fn test_component_mul_into_bad_output() {
+    let a = Matrix::<f64>::ones(2, 2).unwrap();
+    let mut out = Matrix::<f64>::zeroes(3, 3).unwrap();
+    a.component_mul_into(&a, &mut out);
+}
+
+#[test]
+#[should_panic(expected = "Dimension mismatch")]
+fn test_sub_into_bad_output() {
+    let a = Matrix::<i32>::ones(2, 2).unwrap();
+    let mut out = Matrix::<i32>::zeroes(2, 1).unwrap();
+    a.sub_into(&a, &mut out);
+}
@@ -0,0 +1,243 @@
+use linalgae_rs::{matrix, core::{error::MatrixError, matrix::Matrix}};
+use linalgae_rs::iterative::{bicgstab::bicgstab, cg::{cg, try_cg}, gmres::{gmres, try_gmres}};
+use linalgae_rs::iterative::solver::{IterativeOptions, Status};
+use linalgae_rs::sparse::{coo::CooMatrix, csr::CsrMatrix};
+use num_traits::Float;
+use std::fmt::Debug;
+use std::ops::AddAssign;
+
+mod common;
+use common::{assert_close, rhs, spd, v};
+
+// -------- Generic Test Helpers --------
+
+// nonsymmetric, strictly diagonally dominant
+fn nonsymmetric<T>(n: usize) -> Matrix<T>
+where
+    T: Float,
+{
+    let data = (0..n * n)
+        .map(|k| if k / n == k % n { v::<T>(n as f64) } else { v::<T>(((k * 3 + 2) % 5) as f64 * 0.2 - 0.3) })
+        .collect();
+    Matrix::new(n, n, data).unwrap()
+}
+
+// 1D Laplacian tridiag(-1, 2, -1), SPD
+fn laplacian_1d<T>(n: usize) -> CsrMatrix<T>
+where
+    T: Float,
+{
+    let mut coo = CooMatrix::new(n, n).unwrap();
+    for i in 0..n {
+        coo.push(i, i, v(2.0));
+        if i + 1 < n {
+            coo.push(i, i + 1, v(-1.0));
+            coo.push(i + 1, i, v(-1.0));
+        }
+    }
+    coo.to_csr()
+}
+
+fn test_cg_dense<T>(tol: T)
+where
+    T: Float + Debug + Default + AddAssign,
+{
+    let a = spd::<T>(6);
+    let b = rhs::<T>(6, 1);
+    let result = cg(&a, &b, IterativeOptions::new().tol(tol));
+
+    assert!(result.converged());
+    assert_close(&a.matmul(result.solution()), &b, tol * v(100.0));
+    assert_close(result.solution(), &a.solve(&b), tol * v(100.0));
+}
+
+fn test_cg_sparse<T>(tol: T)
+where
+    T: Float + Debug + Default + AddAssign,
+{
+    let a = laplacian_1d::<T>(30);
+    let b = rhs::<T>(30, 1);
+    let result = cg(&a, &b, IterativeOptions::new().tol(tol));
+
+    // exact arithmetic needs at most n steps
+    assert!(result.converged());
+    assert!(result.iterations() <= 30 + 5);
+    assert!(result.residual() <= tol);
+    assert_close(&a.matmul(result.solution()), &b, tol * v(100.0));
+}
+
+fn test_bicgstab<T>(tol: T)
+where
+    T: Float + Debug + Default + AddAssign,
+{
+    let a = nonsymmetric::<T>(8);
+    let b = rhs::<T>(8, 1);
+    let result = bicgstab(&a, &b, IterativeOptions::new().tol(tol));
+
+    assert!(result.converged());
+    assert_close(&a.matmul(result.solution()), &b, tol * v(100.0));
+}
+
+fn test_gmres<T>(tol: T)
+where
+    T: Float + Debug + Default + AddAssign,
+{
+    let a = nonsymmetric::<T>(10);
+    let b = rhs::<T>(10, 1);
+
+    // full GMRES, at most n iterations
+    let full = gmres(&a, &b, IterativeOptions::new().tol(tol).restart(10));
+    assert!(full.converged());
+    assert!(full.iterations() <= 10);
+    assert_close(&a.matmul(full.solution()), &b, tol * v(100.0));
+
+    // restarted
+    let restarted = gmres(&a, &b, IterativeOptions::new().tol(tol).restart(3));
+    assert!(restarted.converged());
+    assert_close(&a.matmul(restarted.solution()), &b, tol * v(100.0));
+}
+
+fn test_residual_history<T>(tol: T)
+where
+    T: Float + Debug + Default + AddAssign,
+{
+    let a = spd::<T>(5);
+    let b = rhs::<T>(5, 1);
+
+    for result in [cg(&a, &b, IterativeOptions::new().tol(tol)), bicgstab(&a, &b, IterativeOptions::new().tol(tol)), gmres(&a, &b, IterativeOptions::new().tol(tol))] {
+        let history = result.residuals();
+        assert_eq!(history.len(), result.iterations() + 1);
+        // x0 = 0, so the first relative residual is exactly 1
+        assert_eq!(history[0], T::one());
+        assert!(result.residual() <= tol);
+    }
+}
+
+// -------- f64 Tests --------
+
+#[test]
+fn test_cg_dense_f64() { test_cg_dense::<f64>(1e-10); }
+
+#[test]
+fn test_cg_sparse_f64() { test_cg_sparse::<f64>(1e-10); }
+
+#[test]
+fn test_bicgstab_f64() { test_bicgstab::<f64>(1e-10); }
+
+#[test]
+fn test_gmres_f64() { test_gmres::<f64>(1e-10); }
+
+#[test]
+fn test_residual_history_f64() { test_residual_history::<f64>(1e-10); }
+
+// -------- f32 Tests --------
+
+#[test]
+fn test_cg_dense_f32() { test_cg_dense::<f32>(1e-4); }
+
+#[test]
+fn test_cg_sparse_f32() { test_cg_sparse::<f32>(1e-4); }
+
+#[test]
+fn test_bicgstab_f32() { test_bicgstab::<f32>(1e-4); }
+
+#[test]
+fn test_gmres_f32() { test_gmres::<f32>(1e-4); }
+
+#[test]
+fn test_residual_history_f32() { test_residual_history::<f32>(1e-4); }
+
+// -------- Option Tests --------
+
+#[test]
+fn test_callback_sees_every_iteration() {
+    let a = laplacian_1d::<f64>(20);
+    let b = rhs::<f64>(20, 1);
+    let mut seen = Vec::new();
+
+    let result = cg(&a, &b, IterativeOptions::new().callback(|k, r| { seen.push((k, r)); true }));
+
+    assert_eq!(seen.len(), result.iterations());
+    assert_eq!(seen.last().unwrap().0, result.iterations());
+    assert_eq!(seen.iter().map(|&(_, r)| r).collect::<Vec<_>>(), result.residuals()[1..]);
+}
+
+#[test]
+fn test_callback_can_stop() {
+    let a = laplacian_1d::<f64>(20);
+    let b = rhs::<f64>(20, 1);
+    let result = gmres(&a, &b, IterativeOptions::new().callback(|k, _| k < 3));
+
+    assert_eq!(result.status(), Status::Stopped);
+    assert_eq!(result.iterations(), 3);
+}
+
+#[test]
+fn test_max_iter() {
+    let a = laplacian_1d::<f64>(50);
+    let b = rhs::<f64>(50, 1);
+    let result = bicgstab(&a, &b, IterativeOptions::new().max_iter(4));
+
+    assert_eq!(result.status(), Status::MaxIterations);
+    assert_eq!(result.iterations(), 4);
+    assert!(!result.converged());
+
+    let none = cg(&a, &b, IterativeOptions::new().max_iter(0));
+    assert_eq!(none.status(), Status::MaxIterations);
+    assert_eq!(none.solution(), &Matrix::zeroes(50, 1).unwrap());
+}
+
+#[test]
+fn test_exact_x0_needs_no_iterations() {
+    let a = spd::<f64>(4);
+    let x = matrix![[1.0], [-2.0], [0.5], [3.0]];
+    let b = a.matmul(&x);
+    let result = gmres(&a, &b, IterativeOptions::new().x0(x.clone()).tol(1e-8));
+
+    assert!(result.converged());
+    assert_eq!(result.iterations(), 0);
+    assert_eq!(result.solution(), &x);
+}
+
+#[test]
+fn test_zero_rhs() {
+    let a = spd::<f64>(3);
+    let result = bicgstab(&a, &Matrix::zeroes(3, 1).unwrap(), IterativeOptions::new());
+
+    assert!(result.converged());
+    assert_eq!(result.solution(), &Matrix::zeroes(3, 1).unwrap());
+}
+
+#[test]
+fn test_cg_indefinite_breaks_down() {
+    let a = matrix![[1.0, 0.0], [0.0, -1.0]];
+    let result = cg(&a, &matrix![[1.0], [1.0]], IterativeOptions::new());
+    assert_eq!(result.status(), Status::Breakdown);
+}
+
+// -------- Error Tests --------
+
+#[test]
+fn test_not_square() {
+    let a = Matrix::<f64>::ones(2, 3).unwrap();
+    let err = try_cg(&a, &Matrix::ones(2, 1).unwrap(), IterativeOptions::new()).unwrap_err();
+    assert_eq!(err, MatrixError::NotSquare { op: "cg", rows: 2, cols: 3 });
+}
+
+#[test]
+fn test_bad_rhs_and_x0() {
+    let a = spd::<f64>(3);
+
+    let err = try_gmres(&a, &Matrix::ones(3, 2).unwrap(), IterativeOptions::new()).unwrap_err();
+    assert_eq!(err, MatrixError::ShapeMismatch { op: "gmres", lhs: (3, 1), rhs: (3, 2) });
+
+    let err = try_cg(&a, &Matrix::ones(3, 1).unwrap(), IterativeOptions::new().x0(Matrix::ones(2, 1).unwrap())).unwrap_err();
+    assert_eq!(err, MatrixError::ShapeMismatch { op: "cg", lhs: (3, 1), rhs: (2, 1) });
+}
+
+#[test]
+#[should_panic(expected = "Dimension mismatch")]
+fn test_bicgstab_bad_rhs_panics() {
+    let a = spd::<f64>(3);
+    let _ = bicgstab(&a, &Matrix::ones(4, 1).unwrap(), IterativeOptions::new());
+}
@@ -0,0 +1,201 @@
+use linalgae_rs::{matrix, core::{error::MatrixError, matrix::Matrix}};
+use num_traits::Float;
+use std::fmt::Debug;
+
+mod common;
+use common::{assert_close, v};
+
+// -------- Generic Test Helpers --------
+
+// needs a row swap on the first step (zero leading entry)
+fn sample<T: Float>() -> Matrix<T> {
+    matrix![
+        [v(0.0), v(2.0), v(1.0)],
+        [v(4.0), v(-1.0), v(3.0)],
+        [v(2.0), v(5.0), v(-2.0)]
+    ]
+}
+
+fn test_lu_reconstructs<T>(tol: T)
+where
+    T: Float + Debug + std::ops::AddAssign,
+{
+    let a = sample::<T>();
+    let lu = a.lu();
+    assert_close(&lu.p().matmul(&a), &lu.l().matmul(&lu.u()), tol);
+
+    // partial pivoting keeps every multiplier at most 1 in magnitude
+    assert!(lu.l().as_slice().iter().all(|x| x.abs() <= T::one()));
+}
+
+fn test_lu_det<T>(tol: T)
+where
+    T: Float + Debug + std::ops::AddAssign,
+{
+    // det = 0*(2 - 15) - 2*(-8 - 6) + 1*(20 + 2) = 50
+    let lu = sample::<T>().lu();
+    assert!((lu.det() - v(50.0)).abs() <= tol);
+}
+
+fn test_lu_solve<T>(tol: T)
+where
+    T: Float + Debug + std::ops::AddAssign,
+{
+    let a = sample::<T>();
+    let x = matrix![[v(1.0), v(-2.0)], [v(2.0), v(0.5)], [v(3.0), v(4.0)]];
+    let b = a.matmul(&x);
+    assert_close(&a.lu().solve(&b), &x, tol);
+}
+
+fn test_lu_solve_in_place<T>(tol: T)
+where
+    T: Float + Debug + std::ops::AddAssign,
+{
+    let a = sample::<T>();
+    let x = matrix![[v(-1.0)], [v(3.0)], [v(0.25)]];
+    let mut b = a.matmul(&x);
+    let ptr = b.as_slice().as_ptr();
+
+    a.lu().solve_in_place(&mut b);
+    assert_close(&b, &x, tol);
+    assert_eq!(b.as_slice().as_ptr(), ptr);
+}
+
+fn test_lu_inverse<T>(tol: T)
+where
+    T: Float + Debug + std::ops::AddAssign,
+{
+    let a = sample::<T>();
+    let inv = a.lu().inverse();
+    assert_close(&a.matmul(&inv), &Matrix::identity(3).unwrap(), tol);
+    assert_close(&inv.matmul(&a), &Matrix::identity(3).unwrap(), tol);
+}
+
+// -------- f64 Tests --------
+
+#[test]
+fn test_lu_reconstructs_f64() { test_lu_reconstructs::<f64>(1e-12); }
+
+#[test]
+fn test_lu_det_f64() { test_lu_det::<f64>(1e-12); }
+
+#[test]
+fn test_lu_solve_f64() { test_lu_solve::<f64>(1e-12); }
+
+#[test]
+fn test_lu_solve_in_place_f64() { test_lu_solve_in_place::<f64>(1e-12); }
+
+#[test]
+fn test_lu_inverse_f64() { test_lu_inverse::<f64>(1e-12); }
+
+// -------- f32 Tests --------
+
+#[test]
+fn test_lu_reconstructs_f32() { test_lu_reconstructs::<f32>(1e-5); }
+
+#[test]
+fn test_lu_det_f32() { test_lu_det::<f32>(1e-4); }
+
+#[test]
+fn test_lu_solve_f32() { test_lu_solve::<f32>(1e-5); }
+
+#[test]
+fn test_lu_solve_in_place_f32() { test_lu_solve_in_place::<f32>(1e-5); }
+
+#[test]
+fn test_lu_inverse_f32() { test_lu_inverse::<f32>(1e-5); }
+
+// -------- Pivoting Tests --------
+
+#[test]
+fn test_lu_permutation_and_sign() {
+    let lu = sample::<f64>().lu();
+    let perm = lu.permutation();
+    let mut sorted = perm.clone();
+    sorted.sort();
+    assert_eq!(sorted, vec![0, 1, 2]);
+
+    // largest entry of column 0 (row 1) is moved to the top
+    assert_eq!(perm[0], 1);
+    assert_eq!(lu.pivots().len(), 3);
+    assert!(lu.sign() == 1.0 || lu.sign() == -1.0);
+}
+
+#[test]
+fn test_lu_packed_matches_factors() {
+    let lu = sample::<f64>().lu();
+    let packed = lu.packed();
+    let (l, u) = (lu.l(), lu.u());
+    for i in 0..3 {
+        for j in 0..3 {
+            let expected = if i > j { l[(i, j)] } else { u[(i, j)] };
+            assert_eq!(packed[(i, j)], expected);
+        }
+    }
+}
+
+#[test]
+fn test_lu_identity() {
+    let lu = Matrix::<f64>::identity(4).unwrap().lu();
+    assert_eq!(lu.det(), 1.0);
+    assert_eq!(lu.permutation(), vec![0, 1, 2, 3]);
+    assert_eq!(lu.inverse(), Matrix::identity(4).unwrap());
+}
+
+#[test]
+fn test_lu_singular_det_is_zero() {
+    let a = matrix![[1.0, 2.0, 3.0], [2.0, 4.0, 6.0], [1.0, 0.0, 1.0]];
+    let lu = a.lu();
+    assert!(lu.is_singular());
+    assert_eq!(lu.det(), 0.0);
+}
+
+// -------- Error Tests --------
+
+#[test]
+fn test_try_lu_not_square() {
+    let a = Matrix::<f64>::zeroes(2, 3).unwrap();
+    let err = a.try_lu().unwrap_err();
+    assert_eq!(err, MatrixError::NotSquare { op: "lu", rows: 2, cols: 3 });
+}
+
+#[test]
+fn test_try_solve_singular() {
+    let a = matrix![[1.0, 2.0], [2.0, 4.0]];
+    let b = matrix![[1.0], [1.0]];
+    let err = a.lu().try_solve(&b).unwrap_err();
+    assert_eq!(err, MatrixError::Singular { op: "solve" });
+}
+
+#[test]
+fn test_try_solve_rank_deficient() {
+    // rounding leaves a tiny nonzero last pivot, the relative test must still catch it
+    let a = matrix![[1.0, 2.0, 3.0], [4.0, 5.0, 6.0], [7.0, 8.0, 9.0]];
+    let lu = a.lu();
+    assert!(lu.is_singular());
+    let err = lu.try_solve(&matrix![[1.0], [1.0], [1.0]]).unwrap_err();
+    assert_eq!(err, MatrixError::Singular { op: "solve" });
+    assert_eq!(lu.try_inverse().unwrap_err(), MatrixError::Singular { op: "inverse" });
+}
+
+#[test]
+fn test_try_inverse_singular() {
+    let a = Matrix::<f32>::zeroes(3, 3).unwrap();
+    let err = a.lu().try_inverse().unwrap_err();
+    assert_eq!(err, MatrixError::Singular { op: "inverse" });
+}
+
+#[test]
+fn test_try_solve_bad_rhs() {
+    let a = sample::<f64>();
+    let mut b = Matrix::<f64>::zeroes(2, 1).unwrap();
+    let err = a.lu().try_solve_in_place(&mut b).unwrap_err();
+    assert_eq!(err, MatrixError::InnerDimMismatch { op: "solve", lhs: (3, 3), rhs: (2, 1) });
+}
+
+#[test]
+#[should_panic(expected = "Matrix is singular")]
+fn test_solve_singular_panics() {
+    let a = matrix![[0.0, 0.0], [0.0, 1.0]];
+    let _ = a.lu().solve(&matrix![[1.0], [1.0]]);
+}
@@ -138,6 +138,7 @@ fn test_macro_with_zero() {
 }
 
 #[test]
+#[allow(clippy::approx_constant)]
 fn test_macro_with_floats() {
     let m = matrix![
         [3.14159_f64, 2.71828_f64],
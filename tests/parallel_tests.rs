@@ -0,0 +1,221 @@
+#![cfg(feature = "parallel")]
+
+use linalgae_rs::{core::{error::MatrixError, matrix::Matrix}, ops::parallel};
+use num_complex::Complex64;
+use num_traits::Num;
+use std::fmt::Debug;
+use std::sync::{Mutex, MutexGuard};
+
+mod common;
+use common::filled_int;
+
+// -------- Generic Test Helpers --------
+
+// large enough to clear the default threshold, odd row count for ragged chunks
+const ROWS: usize = 257;
+const COLS: usize = 131;
+
+// the shared inputs must clear the default threshold to exercise the parallel path
+const _: () = assert!(parallel::DEFAULT_PARALLEL_THRESHOLD <= ROWS * COLS);
+
+// the thread pool and threshold are process-wide and the harness runs tests concurrently,
+// so every test holds this while it runs and the configuration tests can't leak into the others
+static LOCK: Mutex<()> = Mutex::new(());
+
+fn lock() -> MutexGuard<'static, ()> {
+    // a should_panic test poisons the lock, the guarded state is still fine
+    LOCK.lock().unwrap_or_else(|e| e.into_inner())
+}
+
+// serial reference, element by element over the raw slices
+fn zipped<T>(a: &Matrix<T>, b: &Matrix<T>, func: impl Fn(T, T) -> T) -> Matrix<T>
+where
+    T: Num + Copy,
+{
+    let data = a.as_slice().iter().zip(b.as_slice().iter()).map(|(&x, &y)| func(x, y)).collect();
+    Matrix::new(a.rows(), a.cols(), data).unwrap()
+}
+
+fn mapped<T>(a: &Matrix<T>, func: impl Fn(T) -> T) -> Matrix<T>
+where
+    T: Num + Copy,
+{
+    zipped(a, a, |x, _| func(x))
+}
+
+fn filled_complex(rows: usize, cols: usize, seed: usize) -> Matrix<Complex64> {
+    let data = filled_int::<f64>(rows, cols, seed).as_slice().iter().map(|&x| Complex64::new(x, 1.0 - x)).collect();
+    Matrix::new(rows, cols, data).unwrap()
+}
+
+fn reference_matmul<T>(a: &Matrix<T>, b: &Matrix<T>) -> Matrix<T>
+where
+    T: Num + Copy,
+{
+    let (m, k, n) = (a.rows(), a.cols(), b.cols());
+    let mut data = vec![T::zero(); m * n];
+    for i in 0..m {
+        for p in 0..k {
+            for j in 0..n {
+                data[i * n + j] = data[i * n + j] + a.as_slice()[i * k + p] * b.as_slice()[p * n + j];
+            }
+        }
+    }
+    Matrix::new(m, n, data).unwrap()
+}
+
+fn test_elementwise<T>()
+where
+    T: Num + Copy + PartialEq + Debug + From<u8>,
+{
+    let _guard = lock();
+    let a = filled_int::<T>(ROWS, COLS, 1);
+    let b = filled_int::<T>(ROWS, COLS, 2);
+    assert_eq!(&a + &b, zipped(&a, &b, |x, y| x + y));
+    assert_eq!(&a - &b, zipped(&a, &b, |x, y| x - y));
+    assert_eq!(a.component_mul(&b), zipped(&a, &b, |x, y| x * y));
+    assert_eq!(&a * T::from(3), mapped(&a, |x| x * T::from(3)));
+    assert_eq!(&a / T::from(2), mapped(&a, |x| x / T::from(2)));
+}
+
+fn test_apply<T>()
+where
+    T: Num + Copy + PartialEq + Debug + From<u8> + Send + Sync,
+{
+    let _guard = lock();
+    let a = filled_int::<T>(ROWS, COLS, 3);
+    let expected = mapped(&a, |x| x * x + T::one());
+    assert_eq!(a.par_apply(|x| x * x + T::one()), expected);
+    assert_eq!(a.apply(|x| x * x + T::one()), expected);
+    assert_eq!(a.clone().par_apply_inplace(|x| x * x + T::one()), expected);
+    assert_eq!(a.apply_inplace(|x| x * x + T::one()), expected);
+}
+
+fn test_sum<T>()
+where
+    T: Num + Copy + PartialEq + Debug + From<u8> + std::iter::Sum<T>,
+{
+    let _guard = lock();
+    // small integers, so the float sum is exact in any association
+    let a = filled_int::<T>(ROWS, COLS, 4);
+    assert_eq!(a.sum(), a.as_slice().iter().copied().sum());
+}
+
+fn test_matmul<T>()
+where
+    T: Num + Copy + PartialEq + Debug + From<u8> + std::ops::AddAssign,
+{
+    let _guard = lock();
+    let a = filled_int::<T>(ROWS, 90, 5);
+    let b = filled_int::<T>(90, COLS, 6);
+    let expected = reference_matmul(&a, &b);
+    assert_eq!(a.matmul(&b), expected);
+    assert_eq!(a.matmul(&b.t_view().t()), expected);
+    assert_eq!(&a * &b, expected);
+}
+
+// -------- f64 Tests --------
+
+#[test]
+fn test_elementwise_f64() { test_elementwise::<f64>(); }
+
+#[test]
+fn test_apply_f64() { test_apply::<f64>(); }
+
+#[test]
+fn test_sum_f64() { test_sum::<f64>(); }
+
+#[test]
+fn test_matmul_f64() { test_matmul::<f64>(); }
+
+// -------- i32 Tests --------
+
+#[test]
+fn test_elementwise_i32() { test_elementwise::<i32>(); }
+
+#[test]
+fn test_apply_i32() { test_apply::<i32>(); }
+
+#[test]
+fn test_sum_i32() { test_sum::<i32>(); }
+
+#[test]
+fn test_matmul_i32() { test_matmul::<i32>(); }
+
+// -------- Configuration Tests --------
+
+#[test]
+fn test_small_inputs_use_serial_path() {
+    let _guard = lock();
+    let a = filled_int::<f64>(3, 3, 7);
+    let b = filled_int::<f64>(3, 3, 8);
+    assert_eq!(a.matmul(&b), reference_matmul(&a, &b));
+    assert_eq!(&a + &b, zipped(&a, &b, |x, y| x + y));
+}
+
+#[test]
+fn test_non_primitive_elements_use_serial_path() {
+    let _guard = lock();
+    // only primitive element types go to the pool, everything else keeps the serial loops
+    let a = filled_complex(ROWS, COLS, 5);
+    let b = filled_complex(COLS, 40, 6);
+    assert_eq!(&a + &a, zipped(&a, &a, |x, y| x + y));
+    assert_eq!(a.component_mul(&a), zipped(&a, &a, |x, y| x * y));
+    assert_eq!(a.matmul(&b), reference_matmul(&a, &b));
+}
+
+#[test]
+fn test_thread_pool_configuration() {
+    let _guard = lock();
+    parallel::set_num_threads(2).unwrap();
+    assert_eq!(parallel::num_threads(), 2);
+
+    let a = filled_int::<f64>(ROWS, COLS, 9);
+    assert_eq!(a.par_apply(|x| x + 1.0), mapped(&a, |x| x + 1.0));
+
+    parallel::set_num_threads(0).unwrap();
+    assert!(parallel::num_threads() >= 1);
+}
+
+#[test]
+fn test_threshold_configuration() {
+    let _guard = lock();
+    // forcing the parallel path on a tiny input must not change results
+    parallel::set_parallel_threshold(0);
+    assert_eq!(parallel::parallel_threshold(), 0);
+
+    let a = filled_int::<i32>(5, 3, 2);
+    let b = filled_int::<i32>(3, 4, 3);
+    assert_eq!(a.matmul(&b), reference_matmul(&a, &b));
+    assert_eq!(a.sum(), a.as_slice().iter().sum());
+
+    parallel::set_parallel_threshold(parallel::DEFAULT_PARALLEL_THRESHOLD);
+    assert_eq!(parallel::parallel_threshold(), parallel::DEFAULT_PARALLEL_THRESHOLD);
+}
+
+// -------- Error Tests --------
+
+#[test]
+fn test_try_matmul_mismatch() {
+    let _guard = lock();
+    let a = filled_int::<f64>(ROWS, COLS, 1);
+    let err = a.try_matmul(&a).unwrap_err();
+    assert_eq!(err, MatrixError::InnerDimMismatch { op: "matmul", lhs: (ROWS, COLS), rhs: (ROWS, COLS) });
+}
+
+#[test]
+fn test_try_add_mismatch() {
+    let _guard = lock();
+    let a = filled_int::<f64>(ROWS, COLS, 1);
+    let b = filled_int::<f64>(COLS, ROWS, 1);
+    let err = a.try_add(&b).unwrap_err();
+    assert_eq!(err, MatrixError::ShapeMismatch { op: "Add", lhs: (ROWS, COLS), rhs: (COLS, ROWS) });
+}
+
+#[test]
+#[should_panic(expected = "Cannot divide by zero")]
+fn test_div_scalar_zero() {
+    let _guard = lock();
+    let a = filled_int::<f64>(ROWS, COLS, 1);
+    let _ = &a / 0.0;
+}
@@ -0,0 +1,171 @@
+use linalgae_rs::{matrix, core::{error::MatrixError, matrix::Matrix}};
+use num_traits::Float;
+use std::fmt::Debug;
+use std::ops::AddAssign;
+
+mod common;
+use common::{assert_close, filled, v};
+
+// -------- Generic Test Helpers --------
+
+// the four Penrose conditions
+fn assert_penrose<T>(a: &Matrix<T>, p: &Matrix<T>, tol: T)
+where
+    T: Float + Debug + Default + AddAssign,
+{
+    assert_eq!(p.shape(), (a.cols(), a.rows()));
+    assert_close(&a.matmul(p).matmul(a), a, tol);
+    assert_close(&p.matmul(a).matmul(p), p, tol);
+
+    let ap = a.matmul(p);
+    let pa = p.matmul(a);
+    assert_close(&ap.t(), &ap, tol);
+    assert_close(&pa.t(), &pa, tol);
+}
+
+fn test_pinv_penrose<T>(m: usize, n: usize, tol: T)
+where
+    T: Float + Debug + Default + AddAssign,
+{
+    let a = filled::<T>(m, n, 1);
+    assert_penrose(&a, &a.pinv(v(1e-6)), tol);
+}
+
+fn test_pinv_rank_deficient<T>(tol: T)
+where
+    T: Float + Debug + Default + AddAssign,
+{
+    // third column is the sum of the first two
+    let a: Matrix<T> = matrix![
+        [v(1.0), v(2.0), v(3.0)],
+        [v(0.0), v(1.0), v(1.0)],
+        [v(2.0), v(-1.0), v(1.0)],
+        [v(1.0), v(1.0), v(2.0)]
+    ];
+    assert_penrose(&a, &a.pinv(v(1e-5)), tol);
+}
+
+fn test_lstsq_overdetermined<T>(tol: T)
+where
+    T: Float + Debug + Default + AddAssign,
+{
+    // fit y = 1 + 2x through noisy samples, compare with the QR solution
+    let a: Matrix<T> = matrix![[v(1.0), v(0.0)], [v(1.0), v(1.0)], [v(1.0), v(2.0)], [v(1.0), v(3.0)]];
+    let b: Matrix<T> = matrix![[v(1.1)], [v(2.9)], [v(5.2)], [v(6.8)]];
+    let fit = a.lstsq(&b);
+
+    assert_eq!(fit.rank(), 2);
+    assert_close(fit.solution(), &a.qr().solve_least_squares(&b), tol);
+
+    let r = &b - &a.matmul(fit.solution());
+    let rss = r.as_slice().iter().fold(T::zero(), |acc, &x| acc + x * x);
+    assert_eq!(fit.residuals().len(), 1);
+    assert!((fit.residuals()[0] - rss).abs() <= tol);
+}
+
+// -------- f64 Tests --------
+
+#[test]
+fn test_pinv_penrose_tall_f64() { test_pinv_penrose::<f64>(6, 3, 1e-12); }
+
+#[test]
+fn test_pinv_penrose_wide_f64() { test_pinv_penrose::<f64>(3, 5, 1e-12); }
+
+#[test]
+fn test_pinv_rank_deficient_f64() { test_pinv_rank_deficient::<f64>(1e-12); }
+
+#[test]
+fn test_lstsq_overdetermined_f64() { test_lstsq_overdetermined::<f64>(1e-12); }
+
+// -------- f32 Tests --------
+
+#[test]
+fn test_pinv_penrose_tall_f32() { test_pinv_penrose::<f32>(6, 3, 1e-4); }
+
+#[test]
+fn test_pinv_rank_deficient_f32() { test_pinv_rank_deficient::<f32>(1e-4); }
+
+#[test]
+fn test_lstsq_overdetermined_f32() { test_lstsq_overdetermined::<f32>(1e-4); }
+
+// -------- Pseudo-inverse and Least Squares Tests --------
+
+#[test]
+fn test_pinv_of_invertible_is_inverse() {
+    let a = matrix![[4.0, 7.0], [2.0, 6.0]];
+    assert_close(&a.pinv(1e-15), &a.inverse(), 1e-12);
+}
+
+#[test]
+fn test_pinv_of_zero_matrix() {
+    let p = Matrix::<f64>::zeroes(2, 3).unwrap().pinv(1e-15);
+    assert_eq!(p, Matrix::zeroes(3, 2).unwrap());
+}
+
+#[test]
+fn test_pinv_rcond_drops_small_values() {
+    let a = matrix![[1.0, 0.0], [0.0, 1e-10]];
+    assert_close(&a.pinv(1e-12), &matrix![[1.0, 0.0], [0.0, 1e10]], 1e-3);
+    assert_close(&a.pinv(1e-8), &matrix![[1.0, 0.0], [0.0, 0.0]], 1e-15);
+}
+
+#[test]
+fn test_lstsq_underdetermined_minimum_norm() {
+    // x + y + z = 3 has minimum-norm solution (1, 1, 1)
+    let a = matrix![[1.0, 1.0, 1.0]];
+    let fit = a.lstsq(&matrix![[3.0]]);
+
+    assert_close(fit.solution(), &matrix![[1.0], [1.0], [1.0]], 1e-12);
+    assert_eq!(fit.rank(), 1);
+    assert!(fit.residuals().is_empty());
+    assert_eq!(fit.singular_values().len(), 1);
+}
+
+#[test]
+fn test_lstsq_rank_deficient_matches_pinv() {
+    let a = matrix![[1.0, 2.0], [2.0, 4.0], [3.0, 6.0]];
+    let b = matrix![[1.0, 0.0], [2.0, 1.0], [2.0, -1.0]];
+    let fit = a.lstsq(&b);
+
+    assert_eq!(fit.rank(), 1);
+    assert!(fit.residuals().is_empty());
+    assert_close(fit.solution(), &a.pinv(1e-12).matmul(&b), 1e-12);
+}
+
+#[test]
+fn test_lstsq_multiple_rhs_residuals() {
+    let a = filled::<f64>(7, 3, 2);
+    let b = filled::<f64>(7, 2, 5);
+    let fit = a.lstsq(&b);
+
+    assert_eq!(fit.residuals().len(), 2);
+    for j in 0..2 {
+        let col = Matrix::new(7, 1, (0..7).map(|i| b[(i, j)]).collect()).unwrap();
+        let single = a.lstsq(&col);
+        assert!((single.residuals()[0] - fit.residuals()[j]).abs() < 1e-10);
+    }
+}
+
+#[test]
+fn test_lstsq_with_rcond_controls_rank() {
+    let a = matrix![[1.0, 0.0], [0.0, 1e-9], [0.0, 0.0]];
+    let b = matrix![[1.0], [1.0], [0.0]];
+
+    assert_eq!(a.try_lstsq_with_rcond(&b, 1e-12).unwrap().rank(), 2);
+    assert_eq!(a.try_lstsq_with_rcond(&b, 1e-6).unwrap().rank(), 1);
+}
+
+// -------- Error Tests --------
+
+#[test]
+fn test_try_lstsq_bad_rhs() {
+    let a = Matrix::<f64>::ones(4, 2).unwrap();
+    let err = a.try_lstsq(&Matrix::ones(3, 1).unwrap()).unwrap_err();
+    assert_eq!(err, MatrixError::ShapeMismatch { op: "lstsq", lhs: (4, 2), rhs: (3, 1) });
+}
+
+#[test]
+#[should_panic(expected = "Dimension mismatch")]
+fn test_lstsq_bad_rhs_panics() {
+    let _ = Matrix::<f64>::ones(2, 2).unwrap().lstsq(&Matrix::ones(3, 3).unwrap());
+}
@@ -0,0 +1,213 @@
+use linalgae_rs::{matrix, core::{error::MatrixError, matrix::Matrix}};
+use linalgae_rs::iterative::{bicgstab::bicgstab, cg::{cg, try_cg}, gmres::gmres};
+use linalgae_rs::iterative::preconditioner::{IncompleteCholesky, IncompleteLU, Jacobi, Preconditioner, Ssor};
+use linalgae_rs::iterative::solver::IterativeOptions;
+use linalgae_rs::sparse::{coo::CooMatrix, csc::CscMatrix, csr::CsrMatrix};
+use num_traits::Float;
+use std::fmt::Debug;
+use std::ops::AddAssign;
+
+mod common;
+use common::{assert_close, rhs, v};
+
+// -------- Generic Test Helpers --------
+
+// D * L * D with L the 5-point grid Laplacian and D spread over two orders of magnitude, SPD and badly scaled
+fn scaled_laplacian<T>(k: usize) -> CsrMatrix<T>
+where
+    T: Float,
+{
+    let n = k * k;
+    let d = |i: usize| 1.0 + (i % 7) as f64 * 15.0;
+    let mut coo = CooMatrix::new(n, n).unwrap();
+
+    for i in 0..n {
+        coo.push(i, i, v(4.0 * d(i) * d(i)));
+        for j in [i + 1, i + k] {
+            if j < n && (j != i + 1 || (i + 1) % k != 0) {
+                coo.push(i, j, v(-d(i) * d(j)));
+                coo.push(j, i, v(-d(i) * d(j)));
+            }
+        }
+    }
+
+    coo.to_csr()
+}
+
+// convection-diffusion stencil, nonsymmetric
+fn convection<T>(n: usize) -> CsrMatrix<T>
+where
+    T: Float,
+{
+    let mut coo = CooMatrix::new(n, n).unwrap();
+    for i in 0..n {
+        coo.push(i, i, v(2.0 + (i % 3) as f64));
+        if i + 1 < n {
+            coo.push(i, i + 1, v(-1.6));
+            coo.push(i + 1, i, v(-0.4));
+        }
+    }
+    coo.to_csr()
+}
+
+fn test_pcg_beats_cg<T>(tol: T)
+where
+    T: Float + Debug + Default + AddAssign,
+{
+    let a = scaled_laplacian::<T>(8);
+    let b = rhs::<T>(64, 1);
+    let plain = cg(&a, &b, IterativeOptions::new().tol(tol));
+
+    let jacobi = Jacobi::new(&a).unwrap();
+    let ssor = Ssor::new(&a, v(1.2)).unwrap();
+    let ic0 = IncompleteCholesky::new(&a).unwrap();
+    let preconditioners: [&dyn Preconditioner<T>; 3] = [&jacobi, &ssor, &ic0];
+
+    for m in preconditioners {
+        let result = cg(&a, &b, IterativeOptions::new().tol(tol).preconditioner(m));
+        assert!(result.converged());
+        assert!(result.iterations() < plain.iterations(), "{} vs {}", result.iterations(), plain.iterations());
+        assert!(result.residual() <= tol);
+    }
+}
+
+fn test_ilu0_nonsymmetric<T>(tol: T)
+where
+    T: Float + Debug + Default + AddAssign,
+{
+    let a = convection::<T>(40);
+    let b = rhs::<T>(40, 1);
+    let ilu = IncompleteLU::new(&a).unwrap();
+
+    // no fill for a tridiagonal matrix, so ILU(0) is the exact LU and one step suffices
+    let result = gmres(&a, &b, IterativeOptions::new().tol(tol).preconditioner(&ilu));
+    assert!(result.converged());
+    assert_eq!(result.iterations(), 1);
+
+    let result = bicgstab(&a, &b, IterativeOptions::new().tol(tol).preconditioner(&ilu));
+    assert!(result.converged());
+    assert!(result.iterations() <= 2);
+    assert_close(&a.matmul(result.solution()), &b, tol * v(100.0));
+}
+
+fn test_ic0_tridiagonal_is_exact<T>(tol: T)
+where
+    T: Float + Debug + Default + AddAssign,
+{
+    let a = CsrMatrix::from_dense(&matrix![
+        [v::<T>(4.0), v(-1.0), v(0.0), v(0.0)],
+        [v(-1.0), v(4.0), v(-1.0), v(0.0)],
+        [v(0.0), v(-1.0), v(4.0), v(-1.0)],
+        [v(0.0), v(0.0), v(-1.0), v(4.0)]
+    ]);
+    let ic0 = IncompleteCholesky::new(&a).unwrap();
+    let l = ic0.l().to_dense();
+
+    assert_close(&l.matmul(&l.t()), &a.to_dense(), tol);
+    assert_close(&ic0.apply(&a.matmul(&rhs(4, 1))), &rhs(4, 1), tol);
+}
+
+// -------- f64 Tests --------
+
+#[test]
+fn test_pcg_beats_cg_f64() { test_pcg_beats_cg::<f64>(1e-10); }
+
+#[test]
+fn test_ilu0_nonsymmetric_f64() { test_ilu0_nonsymmetric::<f64>(1e-10); }
+
+#[test]
+fn test_ic0_tridiagonal_is_exact_f64() { test_ic0_tridiagonal_is_exact::<f64>(1e-10); }
+
+// -------- f32 Tests --------
+
+#[test]
+fn test_pcg_beats_cg_f32() { test_pcg_beats_cg::<f32>(1e-4); }
+
+#[test]
+fn test_ilu0_nonsymmetric_f32() { test_ilu0_nonsymmetric::<f32>(1e-4); }
+
+#[test]
+fn test_ic0_tridiagonal_is_exact_f32() { test_ic0_tridiagonal_is_exact::<f32>(1e-4); }
+
+// -------- Construction Tests --------
+
+#[test]
+fn test_jacobi_scales_by_diagonal() {
+    let a = matrix![[2.0, 1.0], [1.0, 4.0]];
+    let z = Jacobi::new(&a).unwrap().apply(&matrix![[1.0], [2.0]]);
+    assert_eq!(z, matrix![[0.5], [0.5]]);
+}
+
+#[test]
+fn test_ssor_of_diagonal_is_jacobi() {
+    let a = matrix![[2.0, 0.0, 0.0], [0.0, 5.0, 0.0], [0.0, 0.0, 0.5]];
+    let r = matrix![[1.0], [1.0], [1.0]];
+    assert_close(&Ssor::new(&a, 1.0).unwrap().apply(&r), &Jacobi::new(&a).unwrap().apply(&r), 1e-14);
+}
+
+#[test]
+fn test_ilu0_of_dense_is_lu() {
+    // no zeros, so nothing is dropped and L * U = A exactly
+    let a = matrix![[4.0, 1.0, 2.0], [2.0, 5.0, 1.0], [1.0, 3.0, 6.0]];
+    let lu = IncompleteLU::new(&a).unwrap().lu().to_dense();
+
+    let mut l = Matrix::identity(3).unwrap();
+    let mut u = Matrix::zeroes(3, 3).unwrap();
+    for i in 0..3 {
+        for j in 0..3 {
+            if j < i { l[(i, j)] = lu[(i, j)]; } else { u[(i, j)] = lu[(i, j)]; }
+        }
+    }
+
+    assert_close(&l.matmul(&u), &a, 1e-12);
+}
+
+#[test]
+fn test_sources_agree() {
+    let a = scaled_laplacian::<f64>(4);
+    let dense = a.to_dense();
+    let csc = a.to_csc();
+
+    assert_eq!(IncompleteCholesky::new(&dense).unwrap(), IncompleteCholesky::new(&a).unwrap());
+    assert_eq!(IncompleteLU::new(&csc).unwrap(), IncompleteLU::new(&a).unwrap());
+    assert_eq!(Ssor::new(&CscMatrix::from_dense(&dense), 1.5).unwrap(), Ssor::new(&a, 1.5).unwrap());
+}
+
+// -------- Error Tests --------
+
+#[test]
+fn test_zero_diagonal() {
+    let a = matrix![[0.0, 1.0], [1.0, 2.0]];
+    assert_eq!(Jacobi::new(&a).unwrap_err(), MatrixError::Singular { op: "jacobi" });
+    assert_eq!(Ssor::new(&a, 1.0).unwrap_err(), MatrixError::Singular { op: "ssor" });
+    assert_eq!(IncompleteLU::new(&a).unwrap_err(), MatrixError::Singular { op: "ilu0" });
+    assert_eq!(IncompleteCholesky::new(&a).unwrap_err(), MatrixError::NotPositiveDefinite { op: "ic0" });
+}
+
+#[test]
+fn test_ssor_omega_out_of_range() {
+    let a = Matrix::<f64>::identity(2).unwrap();
+    for omega in [0.0, 2.0, -1.0, f64::NAN] {
+        assert!(matches!(Ssor::new(&a, omega).unwrap_err(), MatrixError::InvalidParameter { op: "ssor", .. }));
+    }
+}
+
+#[test]
+fn test_ic0_not_positive_definite() {
+    let a = matrix![[1.0, 2.0], [2.0, 1.0]];
+    assert_eq!(IncompleteCholesky::new(&a).unwrap_err(), MatrixError::NotPositiveDefinite { op: "ic0" });
+}
+
+#[test]
+fn test_preconditioner_not_square() {
+    let a = Matrix::<f64>::ones(2, 3).unwrap();
+    assert_eq!(IncompleteLU::new(&a).unwrap_err(), MatrixError::NotSquare { op: "ilu0", rows: 2, cols: 3 });
+}
+
+#[test]
+fn test_preconditioner_size_mismatch() {
+    let a = Matrix::<f64>::identity(3).unwrap();
+    let m = Jacobi::new(&Matrix::<f64>::identity(2).unwrap()).unwrap();
+    let err = try_cg(&a, &Matrix::ones(3, 1).unwrap(), IterativeOptions::new().preconditioner(&m)).unwrap_err();
+    assert_eq!(err, MatrixError::ShapeMismatch { op: "cg", lhs: (3, 3), rhs: (2, 2) });
+}
@@ -0,0 +1,138 @@
+use linalgae_rs::{matrix, core::matrix::Matrix};
+
+mod common;
+use common::assert_close;
+
+// -------- Test Helpers --------
+
+fn assert_orthonormal_cols(q: &Matrix<f64>) {
+    assert_close(&q.t().matmul(q), &Matrix::identity(q.cols()).unwrap(), 1e-12);
+}
+
+// 7 x 4 design matrix whose last column is col0 + 2 * col1
+fn collinear() -> Matrix<f64> {
+    let mut data = Vec::new();
+    for i in 0..7 {
+        let x = i as f64;
+        let (c0, c1, c2) = (1.0, x, (x * 0.7).sin());
+        data.extend_from_slice(&[c0, c1, c2, c0 + 2.0 * c1]);
+    }
+    Matrix::new(7, 4, data).unwrap()
+}
+
+// -------- Factorisation Tests --------
+
+#[test]
+fn test_pivoted_reconstructs() {
+    let a = collinear();
+    let qr = a.qr_pivoted();
+    assert_close(&a.matmul(&qr.p()), &qr.thin_q().matmul(&qr.thin_r()), 1e-12);
+    assert_close(&a.matmul(&qr.p()), &qr.full_q().matmul(&qr.full_r()), 1e-12);
+    assert_orthonormal_cols(&qr.thin_q());
+}
+
+#[test]
+fn test_pivoted_diagonal_non_increasing() {
+    let a = matrix![[1.0, 10.0, 0.1], [2.0, 20.0, 0.3], [0.0, 5.0, 0.2], [1.0, 1.0, 1.0]];
+    let r = a.qr_pivoted().thin_r();
+    for j in 1..3 {
+        assert!(r[(j, j)].abs() <= r[(j - 1, j - 1)].abs() + 1e-12);
+    }
+}
+
+#[test]
+fn test_pivoted_permutation() {
+    // the heaviest column is picked first
+    let a = matrix![[1.0, 0.0, 9.0], [0.0, 1.0, 9.0]];
+    let qr = a.qr_pivoted();
+    assert_eq!(qr.permutation()[0], 2);
+
+    let mut sorted = qr.permutation().to_vec();
+    sorted.sort();
+    assert_eq!(sorted, vec![0, 1, 2]);
+}
+
+// -------- Rank Tests --------
+
+#[test]
+fn test_rank_collinear() {
+    let qr = collinear().qr_pivoted();
+    assert_eq!(qr.rank(), 3);
+}
+
+#[test]
+fn test_rank_full_and_zero() {
+    assert_eq!(Matrix::<f64>::identity(5).unwrap().qr_pivoted().rank(), 5);
+    assert_eq!(Matrix::<f64>::zeroes(4, 3).unwrap().qr_pivoted().rank(), 0);
+    assert_eq!(Matrix::<f64>::ones(3, 6).unwrap().qr_pivoted().rank(), 1);
+}
+
+#[test]
+fn test_rank_tolerance() {
+    // the third singular direction is ~1e-10 relative to the rest
+    let a = matrix![[1.0, 0.0, 0.0], [0.0, 1.0, 0.0], [0.0, 0.0, 1e-10], [1.0, 1.0, 0.0]];
+    let qr = a.qr_pivoted();
+    assert_eq!(qr.rank(), 3);
+    assert_eq!(qr.rank_with_tol(1e-8), 2);
+    assert_eq!(qr.rank_with_tol(1e-12), 3);
+}
+
+// -------- Basis Tests --------
+
+#[test]
+fn test_column_space() {
+    let a = collinear();
+    let basis = a.qr_pivoted().column_space().unwrap();
+    assert_eq!(basis.shape(), (7, 3));
+    assert_orthonormal_cols(&basis);
+
+    // projecting A onto the basis leaves it unchanged
+    let projected = basis.matmul(&basis.t().matmul(&a));
+    assert_close(&projected, &a, 1e-10);
+}
+
+#[test]
+fn test_null_space() {
+    let a = collinear();
+    let null = a.qr_pivoted().null_space().unwrap();
+    assert_eq!(null.shape(), (4, 1));
+    assert_orthonormal_cols(&null);
+    assert_close(&a.matmul(&null), &Matrix::zeroes(7, 1).unwrap(), 1e-10);
+
+    // proportional to (1, 2, 0, -1)
+    let ratio = null[(1, 0)] / null[(0, 0)];
+    assert!((ratio - 2.0).abs() < 1e-10);
+    assert!((null[(3, 0)] / null[(0, 0)] + 1.0).abs() < 1e-10);
+}
+
+#[test]
+fn test_null_space_wide() {
+    // 2 x 5 of rank 2 has a 3-dimensional null space
+    let a = matrix![[1.0, 2.0, 3.0, 4.0, 5.0], [0.0, 1.0, 0.0, 1.0, 0.0]];
+    let null = a.qr_pivoted().null_space().unwrap();
+    assert_eq!(null.shape(), (5, 3));
+    assert_orthonormal_cols(&null);
+    assert_close(&a.matmul(&null), &Matrix::zeroes(2, 3).unwrap(), 1e-12);
+}
+
+#[test]
+fn test_null_space_with_tol() {
+    let a = matrix![[1.0, 0.0], [0.0, 1e-10], [0.0, 0.0]];
+    let qr = a.qr_pivoted();
+    assert!(qr.null_space().is_none());
+
+    let null = qr.null_space_with_tol(1e-8).unwrap();
+    assert_eq!(null.shape(), (2, 1));
+    assert!((null[(1, 0)].abs() - 1.0).abs() < 1e-12);
+}
+
+#[test]
+fn test_bases_degenerate() {
+    let full = Matrix::<f64>::identity(3).unwrap().qr_pivoted();
+    assert!(full.null_space().is_none());
+    assert_eq!(full.column_space().unwrap().shape(), (3, 3));
+
+    let zero = Matrix::<f64>::zeroes(3, 2).unwrap().qr_pivoted();
+    assert!(zero.column_space().is_none());
+    assert_orthonormal_cols(&zero.null_space().unwrap());
+}
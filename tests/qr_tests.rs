@@ -0,0 +1,162 @@
+use linalgae_rs::{matrix, core::{error::MatrixError, matrix::Matrix}};
+use num_traits::Float;
+use std::fmt::Debug;
+use std::ops::AddAssign;
+
+mod common;
+use common::{assert_close, filled, v};
+
+// -------- Generic Test Helpers --------
+
+fn assert_upper<T: Float + Debug>(r: &Matrix<T>) {
+    for i in 0..r.rows() {
+        for j in 0..i.min(r.cols()) {
+            assert_eq!(r[(i, j)], T::zero());
+        }
+    }
+}
+
+fn test_qr_thin<T>(m: usize, n: usize, tol: T)
+where
+    T: Float + Debug + Default + AddAssign,
+{
+    let a = filled::<T>(m, n, 1);
+    let qr = a.qr();
+    let (q, r) = (qr.thin_q(), qr.thin_r());
+    let k = m.min(n);
+
+    assert_eq!(q.shape(), (m, k));
+    assert_eq!(r.shape(), (k, n));
+    assert_upper(&r);
+    assert_close(&q.matmul(&r), &a, tol);
+    assert_close(&q.t().matmul(&q), &Matrix::identity(k).unwrap(), tol);
+}
+
+fn test_qr_full<T>(m: usize, n: usize, tol: T)
+where
+    T: Float + Debug + Default + AddAssign,
+{
+    let a = filled::<T>(m, n, 2);
+    let qr = a.qr();
+    let (q, r) = (qr.full_q(), qr.full_r());
+
+    assert_eq!(q.shape(), (m, m));
+    assert_eq!(r.shape(), (m, n));
+    assert_upper(&r);
+    assert_close(&q.matmul(&r), &a, tol);
+    assert_close(&q.t().matmul(&q), &Matrix::identity(m).unwrap(), tol);
+    assert_close(&q.matmul(&q.t()), &Matrix::identity(m).unwrap(), tol);
+}
+
+fn test_least_squares_exact_fit<T>(tol: T)
+where
+    T: Float + Debug,
+{
+    // y = 2 + 3x sampled without noise, two right-hand sides (second is y = -1 + 0.5x)
+    let xs = [0.0, 1.0, 2.0, 3.0, 4.0, 5.0];
+    let design = Matrix::new(6, 2, xs.iter().flat_map(|&x| [v(1.0), v(x)]).collect()).unwrap();
+    let y = Matrix::new(6, 2, xs.iter().flat_map(|&x| [v(2.0 + 3.0 * x), v(-1.0 + 0.5 * x)]).collect()).unwrap();
+
+    let coef = design.qr().solve_least_squares(&y);
+    assert_close(&coef, &matrix![[v(2.0), v(-1.0)], [v(3.0), v(0.5)]], tol);
+}
+
+fn test_least_squares_matches_normal_equations<T>(tol: T)
+where
+    T: Float + Debug + Default + AddAssign,
+{
+    // inconsistent tall system, the residual is orthogonal to the column space
+    let a = filled::<T>(12, 4, 3);
+    let b = filled::<T>(12, 1, 4);
+    let x = a.qr().solve_least_squares(&b);
+
+    let at = a.t();
+    let expected = at.matmul(&a).solve(&at.matmul(&b));
+    assert_close(&x, &expected, tol);
+
+    let residual = &a.matmul(&x) - &b;
+    assert_close(&at.matmul(&residual), &Matrix::zeroes(4, 1).unwrap(), tol);
+}
+
+// -------- f64 Tests --------
+
+#[test]
+fn test_qr_thin_tall_f64() { test_qr_thin::<f64>(9, 4, 1e-12); }
+
+#[test]
+fn test_qr_thin_square_f64() { test_qr_thin::<f64>(6, 6, 1e-12); }
+
+#[test]
+fn test_qr_thin_wide_f64() { test_qr_thin::<f64>(3, 7, 1e-12); }
+
+#[test]
+fn test_qr_full_tall_f64() { test_qr_full::<f64>(8, 3, 1e-12); }
+
+#[test]
+fn test_qr_full_wide_f64() { test_qr_full::<f64>(4, 6, 1e-12); }
+
+#[test]
+fn test_least_squares_exact_fit_f64() { test_least_squares_exact_fit::<f64>(1e-12); }
+
+#[test]
+fn test_least_squares_matches_normal_equations_f64() { test_least_squares_matches_normal_equations::<f64>(1e-10); }
+
+// -------- f32 Tests --------
+
+#[test]
+fn test_qr_thin_tall_f32() { test_qr_thin::<f32>(9, 4, 1e-5); }
+
+#[test]
+fn test_qr_full_tall_f32() { test_qr_full::<f32>(8, 3, 1e-5); }
+
+#[test]
+fn test_qr_full_wide_f32() { test_qr_full::<f32>(4, 6, 1e-5); }
+
+#[test]
+fn test_least_squares_exact_fit_f32() { test_least_squares_exact_fit::<f32>(1e-4); }
+
+#[test]
+fn test_least_squares_matches_normal_equations_f32() { test_least_squares_matches_normal_equations::<f32>(1e-3); }
+
+// -------- Edge Case Tests --------
+
+#[test]
+fn test_qr_already_upper() {
+    // reflectors are skipped (tau == 0) and Q stays the identity
+    let a = matrix![[2.0, 1.0], [0.0, 3.0]];
+    let qr = a.qr();
+    assert_eq!(qr.tau(), &[0.0, 0.0]);
+    assert_eq!(qr.full_q(), Matrix::identity(2).unwrap());
+    assert_eq!(qr.full_r(), a);
+}
+
+#[test]
+fn test_qr_zero_column() {
+    let a = matrix![[0.0, 1.0], [0.0, 2.0], [0.0, 3.0]];
+    let qr = a.qr();
+    assert_close(&qr.thin_q().matmul(&qr.thin_r()), &a, 1e-12);
+}
+
+// -------- Error Tests --------
+
+#[test]
+fn test_try_least_squares_underdetermined() {
+    let a = filled::<f64>(2, 3, 1);
+    let err = a.qr().try_solve_least_squares(&filled(2, 1, 2)).unwrap_err();
+    assert_eq!(err, MatrixError::Underdetermined { op: "solve_least_squares", rows: 2, cols: 3 });
+}
+
+#[test]
+fn test_try_least_squares_bad_rhs() {
+    let a = filled::<f64>(5, 2, 1);
+    let err = a.qr().try_solve_least_squares(&filled(4, 1, 2)).unwrap_err();
+    assert_eq!(err, MatrixError::ShapeMismatch { op: "solve_least_squares", lhs: (5, 2), rhs: (4, 1) });
+}
+
+#[test]
+fn test_try_least_squares_rank_deficient() {
+    // second column is twice the first
+    let a = matrix![[1.0, 2.0], [2.0, 4.0], [3.0, 6.0]];
+    let err = a.qr().try_solve_least_squares(&matrix![[1.0], [1.0], [1.0]]).unwrap_err();
+    assert_eq!(err, MatrixError::Singular { op: "solve_least_squares" });
+}
@@ -0,0 +1,207 @@
+#![cfg(feature = "simd")]
+
+use linalgae_rs::{core::{error::MatrixError, matrix::Matrix}, ops::simd::SimdScalar};
+use num_traits::Float;
+use std::fmt::Debug;
+
+mod common;
+use common::filled;
+
+// -------- Generic Test Helpers --------
+
+// odd sizes so every kernel runs both the vector body and the scalar tail
+const ROWS: usize = 37;
+const COLS: usize = 29;
+
+fn assert_close<T>(a: &[T], b: &[T], tol: T)
+where
+    T: Float + Debug,
+{
+    assert_eq!(a.len(), b.len());
+    for (x, y) in a.iter().zip(b.iter()) {
+        assert!((*x - *y).abs() <= tol * (T::one() + y.abs()), "{:?} vs {:?}", x, y);
+    }
+}
+
+// serial references over the raw slices
+fn zipped<T>(a: &Matrix<T>, b: &Matrix<T>, func: impl Fn(T, T) -> T) -> Vec<T>
+where
+    T: Float,
+{
+    a.as_slice().iter().zip(b.as_slice().iter()).map(|(&x, &y)| func(x, y)).collect()
+}
+
+fn reference_matmul<T>(a: &Matrix<T>, b: &Matrix<T>) -> Vec<T>
+where
+    T: Float,
+{
+    let (m, k, n) = (a.rows(), a.cols(), b.cols());
+    let mut data = vec![T::zero(); m * n];
+    for i in 0..m {
+        for p in 0..k {
+            for j in 0..n {
+                data[i * n + j] = data[i * n + j] + a.as_slice()[i * k + p] * b.as_slice()[p * n + j];
+            }
+        }
+    }
+    data
+}
+
+fn test_simd_elementwise_exact<T>()
+where
+    T: SimdScalar + Float + Debug + From<u16>,
+{
+    // a single IEEE op per element, so results must match bit for bit
+    let a = filled::<T>(ROWS, COLS, 1);
+    let b = filled::<T>(ROWS, COLS, 2);
+    assert_eq!(a.simd_add(&b).as_slice(), zipped(&a, &b, |x, y| x + y));
+    assert_eq!(a.simd_sub(&b).as_slice(), zipped(&a, &b, |x, y| x - y));
+    assert_eq!(a.simd_component_mul(&b).as_slice(), zipped(&a, &b, |x, y| x * y));
+}
+
+fn test_ops_route_to_kernels<T>(tol: T)
+where
+    T: SimdScalar + Float + Debug + Default + From<u16>,
+{
+    // the plain operators take the kernels for f32 / f64 and must agree with scalar loops
+    let a = filled::<T>(ROWS, COLS, 1);
+    let b = filled::<T>(ROWS, COLS, 2);
+    let alpha = <T as From<u16>>::from(3);
+    assert_eq!((&a + &b).as_slice(), zipped(&a, &b, |x, y| x + y));
+    assert_eq!((&a - &b).as_slice(), zipped(&a, &b, |x, y| x - y));
+    assert_eq!(a.component_mul(&b).as_slice(), zipped(&a, &b, |x, y| x * y));
+    assert_eq!((&a * alpha).as_slice(), zipped(&a, &a, |x, _| x * alpha));
+
+    // unit-stride rhs takes the axpy rows, a lazy transpose keeps the generic loop
+    let c = filled::<T>(COLS, 23, 3);
+    let expected = reference_matmul(&a, &c);
+    assert_close(a.matmul(&c).as_slice(), &expected, tol);
+    assert_close(a.matmul(&c.t().t_view()).as_slice(), &expected, tol);
+}
+
+fn test_simd_axpy<T>(tol: T)
+where
+    T: SimdScalar + Float + Debug + From<u16>,
+{
+    let x = filled::<T>(ROWS, COLS, 3);
+    let mut y = filled::<T>(ROWS, COLS, 4);
+    let alpha = <T as From<u16>>::from(3);
+    let expected = &y + &(&x * alpha);
+
+    y.simd_axpy(alpha, &x);
+    assert_close(y.as_slice(), expected.as_slice(), tol);
+}
+
+fn test_simd_dot<T>(tol: T)
+where
+    T: SimdScalar + Float + Debug + From<u16> + std::iter::Sum<T>,
+{
+    let a = filled::<T>(ROWS, COLS, 5);
+    let b = filled::<T>(ROWS, COLS, 6);
+    let expected = a.component_mul(&b).sum();
+    assert_close(&[a.simd_dot(&b)], &[expected], tol);
+}
+
+fn test_simd_matmul<T>(m: usize, k: usize, n: usize, tol: T)
+where
+    T: SimdScalar + Float + Debug + From<u16>,
+{
+    let a = filled::<T>(m, k, 7);
+    let b = filled::<T>(k, n, 8);
+    let c = a.simd_matmul(&b);
+    assert_eq!(c.shape(), (m, n));
+    assert_close(c.as_slice(), a.matmul(&b).as_slice(), tol);
+}
+
+// -------- f64 Tests --------
+
+#[test]
+fn test_simd_elementwise_exact_f64() { test_simd_elementwise_exact::<f64>(); }
+
+#[test]
+fn test_ops_route_to_kernels_f64() { test_ops_route_to_kernels::<f64>(1e-12); }
+
+#[test]
+fn test_simd_axpy_f64() { test_simd_axpy::<f64>(1e-12); }
+
+#[test]
+fn test_simd_dot_f64() { test_simd_dot::<f64>(1e-12); }
+
+#[test]
+fn test_simd_matmul_f64() { test_simd_matmul::<f64>(ROWS, 41, COLS, 1e-12); }
+
+#[test]
+fn test_simd_matmul_short_rows_f64() { test_simd_matmul::<f64>(5, 3, 2, 1e-12); }
+
+// -------- f32 Tests --------
+
+#[test]
+fn test_simd_elementwise_exact_f32() { test_simd_elementwise_exact::<f32>(); }
+
+#[test]
+fn test_ops_route_to_kernels_f32() { test_ops_route_to_kernels::<f32>(1e-5); }
+
+#[test]
+fn test_simd_axpy_f32() { test_simd_axpy::<f32>(1e-5); }
+
+#[test]
+fn test_simd_dot_f32() { test_simd_dot::<f32>(1e-5); }
+
+#[test]
+fn test_simd_matmul_f32() { test_simd_matmul::<f32>(ROWS, 41, COLS, 1e-5); }
+
+#[test]
+fn test_simd_matmul_short_rows_f32() { test_simd_matmul::<f32>(3, 7, 6, 1e-5); }
+
+// -------- Slice Kernel Tests --------
+
+#[test]
+fn test_slice_kernels_every_tail_length() {
+    // every remainder modulo the vector width, including the empty slice
+    for len in 0..20 {
+        let a: Vec<f32> = (0..len).map(|i| i as f32 * 0.5).collect();
+        let b: Vec<f32> = (0..len).map(|i| 3.0 - i as f32).collect();
+        let mut out = vec![0.0; len];
+
+        f32::simd_add(&a, &b, &mut out);
+        assert_eq!(out, a.iter().zip(&b).map(|(x, y)| x + y).collect::<Vec<_>>());
+
+        f32::simd_scale(1.5, &a, &mut out);
+        assert_eq!(out, a.iter().map(|x| x * 1.5).collect::<Vec<_>>());
+
+        let dot: f32 = a.iter().zip(&b).map(|(x, y)| x * y).sum();
+        assert_close(&[f32::simd_dot(&a, &b)], &[dot], 1e-5);
+    }
+}
+
+#[test]
+#[should_panic(expected = "slice length mismatch")]
+fn test_slice_kernel_length_mismatch() {
+    let mut out = [0.0f64; 3];
+    f64::simd_add(&[1.0, 2.0, 3.0], &[1.0, 2.0], &mut out);
+}
+
+// -------- Error Tests --------
+
+#[test]
+fn test_try_simd_matmul_mismatch() {
+    let a = filled::<f64>(ROWS, COLS, 1);
+    let err = a.try_simd_matmul(&a).unwrap_err();
+    assert_eq!(err, MatrixError::InnerDimMismatch { op: "matmul", lhs: (ROWS, COLS), rhs: (ROWS, COLS) });
+}
+
+#[test]
+fn test_try_simd_add_mismatch() {
+    let a = filled::<f32>(ROWS, COLS, 1);
+    let b = filled::<f32>(COLS, ROWS, 1);
+    let err = a.try_simd_add(&b).unwrap_err();
+    assert_eq!(err, MatrixError::ShapeMismatch { op: "Add", lhs: (ROWS, COLS), rhs: (COLS, ROWS) });
+}
+
+#[test]
+#[should_panic(expected = "Dimension mismatch")]
+fn test_simd_dot_mismatch() {
+    let a = filled::<f64>(2, 3, 1);
+    let b = filled::<f64>(3, 2, 1);
+    let _ = a.simd_dot(&b);
+}
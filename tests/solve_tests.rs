@@ -0,0 +1,169 @@
+use linalgae_rs::{matrix, core::{error::MatrixError, matrix::Matrix}};
+use num_traits::{Float, Num};
+use std::fmt::Debug;
+
+mod common;
+use common::{assert_close, v};
+
+// -------- Generic Test Helpers --------
+
+fn system<T: Float>() -> Matrix<T> {
+    matrix![
+        [v(2.0), v(1.0), v(-1.0), v(0.0)],
+        [v(-3.0), v(-1.0), v(2.0), v(1.0)],
+        [v(-2.0), v(1.0), v(2.0), v(0.0)],
+        [v(1.0), v(0.0), v(0.0), v(4.0)]
+    ]
+}
+
+fn test_solve_multiple_rhs<T>(tol: T)
+where
+    T: Float + Debug + std::ops::AddAssign,
+{
+    let a = system::<T>();
+    let x = matrix![
+        [v(1.0), v(0.0), v(2.0)],
+        [v(2.0), v(-1.0), v(0.5)],
+        [v(-1.0), v(1.0), v(0.0)],
+        [v(0.5), v(3.0), v(-2.0)]
+    ];
+    assert_close(&a.solve(&a.matmul(&x)), &x, tol);
+}
+
+fn test_inverse<T>(tol: T)
+where
+    T: Float + Debug + std::ops::AddAssign,
+{
+    let a = system::<T>();
+    let id = Matrix::identity(4).unwrap();
+    assert_close(&a.matmul(&a.inverse()), &id, tol);
+    assert_close(&a.inverse().inverse(), &a, tol);
+}
+
+fn test_determinant<T>(tol: T)
+where
+    T: Float + Debug + Default,
+{
+    let a: Matrix<T> = matrix![[v(3.0), v(8.0)], [v(4.0), v(6.0)]];
+    assert!((a.determinant() - v(-14.0)).abs() <= tol);
+
+    let b: Matrix<T> = matrix![[v(6.0), v(1.0), v(1.0)], [v(4.0), v(-2.0), v(5.0)], [v(2.0), v(8.0), v(7.0)]];
+    assert!((b.determinant() - v(-306.0)).abs() <= tol);
+
+    // det(A^T) == det(A)
+    assert!((b.t().determinant() - b.determinant()).abs() <= tol);
+}
+
+fn test_trace<T>()
+where
+    T: Num + Copy + PartialEq + Debug + From<u8>,
+{
+    let a = matrix![[T::from(1), T::from(2)], [T::from(3), T::from(4)]];
+    assert_eq!(a.trace(), T::from(5));
+    assert_eq!(Matrix::<T>::identity(7).unwrap().trace(), T::from(7));
+}
+
+// -------- f64 Tests --------
+
+#[test]
+fn test_solve_multiple_rhs_f64() { test_solve_multiple_rhs::<f64>(1e-12); }
+
+#[test]
+fn test_inverse_f64() { test_inverse::<f64>(1e-12); }
+
+#[test]
+fn test_determinant_f64() { test_determinant::<f64>(1e-10); }
+
+#[test]
+fn test_trace_f64() { test_trace::<f64>(); }
+
+// -------- f32 Tests --------
+
+#[test]
+fn test_solve_multiple_rhs_f32() { test_solve_multiple_rhs::<f32>(1e-4); }
+
+#[test]
+fn test_inverse_f32() { test_inverse::<f32>(1e-4); }
+
+#[test]
+fn test_determinant_f32() { test_determinant::<f32>(1e-3); }
+
+// -------- i32 Tests --------
+
+#[test]
+fn test_trace_i32() { test_trace::<i32>(); }
+
+// -------- Tolerance Tests --------
+
+#[test]
+fn test_nearly_singular_rejected_by_default() {
+    // second row differs from a multiple of the first only at rounding level
+    let a = matrix![[1.0, 2.0], [2.0, 4.0 + 1e-15]];
+    let err = a.try_solve(&matrix![[1.0], [2.0]]).unwrap_err();
+    assert_eq!(err, MatrixError::Singular { op: "solve" });
+}
+
+#[test]
+fn test_rtol_controls_singularity() {
+    let a = matrix![[1.0, 0.0], [0.0, 1e-8]];
+    let b = matrix![[1.0], [1e-8]];
+
+    // well above the default n * eps, so accepted
+    let x = a.try_solve_with_tol(&b, 0.0).unwrap();
+    assert_close(&x, &matrix![[1.0], [1.0]], 1e-12);
+    assert!(a.try_inverse().is_ok());
+
+    // a looser tolerance flags the tiny pivot
+    let err = a.try_solve_with_tol(&b, 1e-6).unwrap_err();
+    assert_eq!(err, MatrixError::Singular { op: "solve" });
+    let err = a.try_inverse_with_tol(1e-6).unwrap_err();
+    assert_eq!(err, MatrixError::Singular { op: "inverse" });
+}
+
+#[test]
+fn test_rtol_is_relative_to_scale() {
+    // uniformly scaling A must not change the verdict
+    let a = matrix![[1e-20, 0.0], [0.0, 2e-20]];
+    assert!(a.try_inverse().is_ok());
+    assert_close(&a.inverse(), &matrix![[1e20, 0.0], [0.0, 5e19]], 1e6);
+}
+
+#[test]
+fn test_determinant_singular_is_zero() {
+    let a = matrix![[1.0, 2.0, 3.0], [4.0, 5.0, 6.0], [0.0, 0.0, 0.0]];
+    assert_eq!(a.determinant(), 0.0);
+}
+
+// -------- Error Tests --------
+
+#[test]
+fn test_try_solve_not_square() {
+    let a = Matrix::<f64>::ones(2, 3).unwrap();
+    let err = a.try_solve(&Matrix::ones(2, 1).unwrap()).unwrap_err();
+    assert_eq!(err, MatrixError::NotSquare { op: "solve", rows: 2, cols: 3 });
+}
+
+#[test]
+fn test_try_solve_bad_rhs() {
+    let a = system::<f64>();
+    let err = a.try_solve(&Matrix::ones(3, 2).unwrap()).unwrap_err();
+    assert_eq!(err, MatrixError::InnerDimMismatch { op: "solve", lhs: (4, 4), rhs: (3, 2) });
+}
+
+#[test]
+fn test_try_determinant_not_square() {
+    let err = Matrix::<f32>::ones(3, 1).unwrap().try_determinant().unwrap_err();
+    assert_eq!(err, MatrixError::NotSquare { op: "determinant", rows: 3, cols: 1 });
+}
+
+#[test]
+fn test_try_trace_not_square() {
+    let err = Matrix::<i32>::ones(1, 2).unwrap().try_trace().unwrap_err();
+    assert_eq!(err, MatrixError::NotSquare { op: "trace", rows: 1, cols: 2 });
+}
+
+#[test]
+#[should_panic(expected = "Matrix is singular")]
+fn test_inverse_singular_panics() {
+    let _ = Matrix::<f64>::ones(3, 3).unwrap().inverse();
+}
@@ -0,0 +1,230 @@
+use linalgae_rs::{matrix, core::{error::MatrixError, matrix::Matrix}};
+use linalgae_rs::sparse::{coo::CooMatrix, csc::CscMatrix};
+use num_traits::Float;
+use std::fmt::Debug;
+use std::ops::AddAssign;
+
+mod common;
+use common::{assert_close, rhs, v};
+
+// -------- Generic Test Helpers --------
+
+// 5-point Laplacian on a (k x k) grid plus shift * I, symmetric positive definite for shift >= 0
+fn grid_laplacian<T>(k: usize, shift: f64) -> CscMatrix<T>
+where
+    T: Float,
+{
+    let n = k * k;
+    let mut coo = CooMatrix::new(n, n).unwrap();
+
+    for r in 0..k {
+        for c in 0..k {
+            let i = r * k + c;
+            coo.push(i, i, v(4.0 + shift));
+            if c + 1 < k {
+                coo.push(i, i + 1, v(-1.0));
+                coo.push(i + 1, i, v(-1.0));
+            }
+            if r + 1 < k {
+                coo.push(i, i + k, v(-1.0));
+                coo.push(i + k, i, v(-1.0));
+            }
+        }
+    }
+
+    coo.to_csc()
+}
+
+// dense first row and column, natural order fills all of L
+fn arrow(n: usize) -> CscMatrix<f64> {
+    let mut coo = CooMatrix::new(n, n).unwrap();
+    coo.push(0, 0, n as f64);
+    for i in 1..n {
+        coo.push(i, i, 2.0);
+        coo.push(0, i, 1.0);
+        coo.push(i, 0, 1.0);
+    }
+    coo.to_csc()
+}
+
+fn test_sparse_cholesky_solve<T>(tol: T)
+where
+    T: Float + Debug + Default + AddAssign,
+{
+    let a = grid_laplacian::<T>(6, 0.5);
+    let b = rhs::<T>(36, 2);
+    let x = a.cholesky().solve(&b);
+
+    assert_close(&a.matmul(&x), &b, tol);
+}
+
+fn test_sparse_cholesky_reconstructs<T>(tol: T)
+where
+    T: Float + Debug + Default + AddAssign,
+{
+    let a = grid_laplacian::<T>(4, 0.0);
+    let chol = a.cholesky();
+    let l = chol.l().to_dense();
+    let p = chol.perm();
+
+    // P * A * P^T, entry (i, j) is A[p[i], p[j]]
+    let dense = a.to_dense();
+    let data = (0..16 * 16).map(|k| dense[(p[k / 16], p[k % 16])]).collect();
+    let pap = Matrix::new(16, 16, data).unwrap();
+
+    assert_close(&l.matmul(&l.t()), &pap, tol);
+    assert_eq!(chol.l().nnz(), chol.symbolic().nnz_l());
+}
+
+fn test_sparse_cholesky_matches_dense<T>(tol: T)
+where
+    T: Float + Debug + Default + AddAssign,
+{
+    let a = grid_laplacian::<T>(5, 1.0);
+    let sparse = a.cholesky();
+    let dense = a.to_dense().cholesky();
+
+    assert!((sparse.log_det() - dense.log_det()).abs() <= tol * dense.log_det().abs());
+    assert_close(&sparse.solve(&rhs(25, 1)), &dense.solve(&rhs(25, 1)), tol);
+}
+
+fn test_refactor_reuses_symbolic<T>(tol: T)
+where
+    T: Float + Debug + Default + AddAssign,
+{
+    let a = grid_laplacian::<T>(5, 0.0);
+    let symbolic = a.symbolic_cholesky();
+    let mut chol = symbolic.factor(&a);
+
+    // same pattern, different values
+    let shifted = grid_laplacian::<T>(5, 3.0);
+    chol.refactor(&shifted);
+
+    let b = rhs::<T>(25, 3);
+    assert_eq!(chol.symbolic(), &symbolic);
+    assert_close(&shifted.matmul(&chol.solve(&b)), &b, tol);
+    assert_close(&symbolic.factor(&shifted).solve(&b), &chol.solve(&b), tol);
+}
+
+// -------- f64 Tests --------
+
+#[test]
+fn test_sparse_cholesky_solve_f64() { test_sparse_cholesky_solve::<f64>(1e-10); }
+
+#[test]
+fn test_sparse_cholesky_reconstructs_f64() { test_sparse_cholesky_reconstructs::<f64>(1e-10); }
+
+#[test]
+fn test_sparse_cholesky_matches_dense_f64() { test_sparse_cholesky_matches_dense::<f64>(1e-10); }
+
+#[test]
+fn test_refactor_reuses_symbolic_f64() { test_refactor_reuses_symbolic::<f64>(1e-10); }
+
+// -------- f32 Tests --------
+
+#[test]
+fn test_sparse_cholesky_solve_f32() { test_sparse_cholesky_solve::<f32>(1e-3); }
+
+#[test]
+fn test_sparse_cholesky_reconstructs_f32() { test_sparse_cholesky_reconstructs::<f32>(1e-3); }
+
+#[test]
+fn test_sparse_cholesky_matches_dense_f32() { test_sparse_cholesky_matches_dense::<f32>(1e-3); }
+
+#[test]
+fn test_refactor_reuses_symbolic_f32() { test_refactor_reuses_symbolic::<f32>(1e-3); }
+
+// -------- Ordering Tests --------
+
+#[test]
+fn test_amd_is_permutation() {
+    let mut perm = grid_laplacian::<f64>(7, 0.0).amd();
+    perm.sort_unstable();
+    assert_eq!(perm, (0..49).collect::<Vec<_>>());
+}
+
+#[test]
+fn test_amd_orders_arrow_hub_last() {
+    let a = arrow(10);
+    // the hub ties with the last leaf once everything else is gone
+    let perm = a.amd();
+    assert!(perm[8..].contains(&0));
+
+    // no fill at all, versus a full triangle in natural order
+    assert_eq!(a.symbolic_cholesky().nnz_l(), 19);
+    assert_eq!(a.symbolic_cholesky_with_perm(&(0..10).collect::<Vec<_>>()).nnz_l(), 55);
+}
+
+#[test]
+fn test_amd_reduces_grid_fill() {
+    let a = grid_laplacian::<f64>(12, 0.0);
+    let natural = a.symbolic_cholesky_with_perm(&(0..144).collect::<Vec<_>>());
+    let amd = a.symbolic_cholesky();
+
+    assert!(amd.nnz_l() < natural.nnz_l(), "{} vs {}", amd.nnz_l(), natural.nnz_l());
+}
+
+#[test]
+fn test_etree_of_tridiagonal_is_a_path() {
+    let a = CscMatrix::from_dense(&matrix![[2.0, -1.0, 0.0], [-1.0, 2.0, -1.0], [0.0, -1.0, 2.0]]);
+    let symbolic = a.symbolic_cholesky_with_perm(&[0, 1, 2]);
+
+    assert_eq!(symbolic.etree(), &[Some(1), Some(2), None]);
+    assert_eq!(symbolic.nnz_l(), 5);
+}
+
+#[test]
+fn test_sparse_cholesky_det() {
+    let a = CscMatrix::from_dense(&matrix![[4.0, 2.0, 0.0], [2.0, 5.0, 1.0], [0.0, 1.0, 3.0]]);
+    let expected = a.to_dense().determinant();
+    assert!((a.cholesky().det() - expected).abs() < 1e-10);
+}
+
+// -------- Error Tests --------
+
+#[test]
+fn test_sparse_cholesky_not_square() {
+    let a = CscMatrix::from_dense(&Matrix::<f64>::ones(2, 3).unwrap());
+    let err = a.try_cholesky().unwrap_err();
+    assert_eq!(err, MatrixError::NotSquare { op: "cholesky", rows: 2, cols: 3 });
+}
+
+#[test]
+fn test_sparse_cholesky_not_positive_definite() {
+    let a = CscMatrix::from_dense(&matrix![[1.0, 2.0], [2.0, 1.0]]);
+    let err = a.try_cholesky().unwrap_err();
+    assert_eq!(err, MatrixError::NotPositiveDefinite { op: "cholesky" });
+}
+
+#[test]
+fn test_sparse_cholesky_not_symmetric() {
+    let a = CscMatrix::from_dense(&matrix![[4.0, 1.0], [0.0, 4.0]]);
+    let err = a.try_cholesky().unwrap_err();
+    assert_eq!(err, MatrixError::NotSymmetric { op: "cholesky" });
+}
+
+#[test]
+fn test_refactor_pattern_mismatch_keeps_factor() {
+    let a = grid_laplacian::<f64>(3, 0.0);
+    let mut chol = a.cholesky();
+    let before = chol.clone();
+
+    let err = chol.try_refactor(&CscMatrix::from_dense(&Matrix::identity(9).unwrap())).unwrap_err();
+    assert!(matches!(err, MatrixError::InvalidStructure { op: "refactor", .. }));
+    assert_eq!(chol, before);
+}
+
+#[test]
+fn test_invalid_perm() {
+    let a = grid_laplacian::<f64>(2, 0.0);
+    let err = a.try_symbolic_cholesky_with_perm(&[0, 1, 1, 3]).unwrap_err();
+    assert!(matches!(err, MatrixError::InvalidStructure { op: "symbolic_cholesky", .. }));
+    assert!(a.try_symbolic_cholesky_with_perm(&[0, 1]).is_err());
+}
+
+#[test]
+#[should_panic(expected = "lhs_cols (4) != rhs_rows (3)")]
+fn test_sparse_cholesky_solve_bad_rhs_panics() {
+    let chol = grid_laplacian::<f64>(2, 0.0).cholesky();
+    let _ = chol.solve(&Matrix::ones(3, 1).unwrap());
+}
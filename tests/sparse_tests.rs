@@ -0,0 +1,269 @@
+use linalgae_rs::{matrix, core::{error::MatrixError, matrix::Matrix}};
+use linalgae_rs::sparse::{coo::CooMatrix, csc::CscMatrix, csr::CsrMatrix};
+use num_traits::Num;
+use std::fmt::Debug;
+use std::ops::AddAssign;
+
+// -------- Generic Test Helpers --------
+
+fn v<T: Num + Copy + From<u8>>(x: u8) -> T {
+    T::from(x)
+}
+
+// mostly zero, every row and col has at least one entry
+fn sparse_dense<T>(rows: usize, cols: usize, seed: usize) -> Matrix<T>
+where
+    T: Num + Copy + From<u8>,
+{
+    let data = (0..rows * cols)
+        .map(|k| if (k * 7 + seed).is_multiple_of(5) || k.is_multiple_of(cols + 1) { v::<T>(((k + seed) % 9 + 1) as u8) } else { T::zero() })
+        .collect();
+    Matrix::new(rows, cols, data).unwrap()
+}
+
+fn test_round_trips<T>()
+where
+    T: Num + Copy + From<u8> + Debug,
+{
+    let a = sparse_dense::<T>(5, 7, 1);
+
+    assert_eq!(CsrMatrix::from_dense(&a).to_dense(), a);
+    assert_eq!(CscMatrix::from_dense(&a).to_dense(), a);
+    assert_eq!(CooMatrix::from_dense(&a).to_dense(), a);
+
+    let csr = CsrMatrix::from_dense(&a);
+    assert_eq!(csr.to_csc().to_dense(), a);
+    assert_eq!(csr.to_coo().to_csr(), csr);
+    assert_eq!(csr.to_csc().to_csr(), csr);
+    assert_eq!(CscMatrix::from_dense(&a).to_coo().to_csc(), csr.to_csc());
+}
+
+fn test_sparse_dense_matmul<T>()
+where
+    T: Num + Copy + From<u8> + Debug + AddAssign,
+{
+    let a = sparse_dense::<T>(6, 4, 2);
+    let b = sparse_dense::<T>(4, 3, 3);
+    let expected = a.matmul(&b);
+
+    assert_eq!(CsrMatrix::from_dense(&a).matmul(&b), expected);
+    assert_eq!(CscMatrix::from_dense(&a).matmul(&b), expected);
+}
+
+fn test_sparse_sparse_matmul<T>()
+where
+    T: Num + Copy + From<u8> + Debug + AddAssign,
+{
+    let a = sparse_dense::<T>(5, 6, 4);
+    let b = sparse_dense::<T>(6, 4, 5);
+    let expected = a.matmul(&b);
+
+    let csr = CsrMatrix::from_dense(&a).matmul_sparse(&CsrMatrix::from_dense(&b));
+    let csc = CscMatrix::from_dense(&a).matmul_sparse(&CscMatrix::from_dense(&b));
+
+    assert_eq!(csr.to_dense(), expected);
+    assert_eq!(csc.to_dense(), expected);
+    assert_eq!(csr, CsrMatrix::from_dense(&expected));
+}
+
+fn test_sparse_add_and_transpose<T>()
+where
+    T: Num + Copy + From<u8> + Debug + Default,
+{
+    let a = sparse_dense::<T>(4, 5, 6);
+    let b = sparse_dense::<T>(4, 5, 7);
+    let sum = &a + &b;
+
+    assert_eq!((&CsrMatrix::from_dense(&a) + &CsrMatrix::from_dense(&b)).to_dense(), sum);
+    assert_eq!((&CscMatrix::from_dense(&a) + &CscMatrix::from_dense(&b)).to_dense(), sum);
+
+    assert_eq!(CsrMatrix::from_dense(&a).t().to_dense(), a.t());
+    assert_eq!(CscMatrix::from_dense(&a).t().to_dense(), a.t());
+    assert_eq!(CooMatrix::from_dense(&a).t().to_dense(), a.t());
+}
+
+// -------- f64 Tests --------
+
+#[test]
+fn test_round_trips_f64() { test_round_trips::<f64>(); }
+
+#[test]
+fn test_sparse_dense_matmul_f64() { test_sparse_dense_matmul::<f64>(); }
+
+#[test]
+fn test_sparse_sparse_matmul_f64() { test_sparse_sparse_matmul::<f64>(); }
+
+#[test]
+fn test_sparse_add_and_transpose_f64() { test_sparse_add_and_transpose::<f64>(); }
+
+// -------- i32 Tests --------
+
+#[test]
+fn test_round_trips_i32() { test_round_trips::<i32>(); }
+
+#[test]
+fn test_sparse_dense_matmul_i32() { test_sparse_dense_matmul::<i32>(); }
+
+#[test]
+fn test_sparse_sparse_matmul_i32() { test_sparse_sparse_matmul::<i32>(); }
+
+#[test]
+fn test_sparse_add_and_transpose_i32() { test_sparse_add_and_transpose::<i32>(); }
+
+// -------- Structure Tests --------
+
+#[test]
+fn test_csr_layout() {
+    let a = matrix![[1, 0, 2], [0, 0, 0], [0, 3, 0]];
+    let csr = CsrMatrix::from_dense(&a);
+
+    assert_eq!(csr.indptr(), &[0, 2, 2, 3]);
+    assert_eq!(csr.indices(), &[0, 2, 1]);
+    assert_eq!(csr.values(), &[1, 2, 3]);
+    assert_eq!(csr.nnz(), 3);
+    assert_eq!(csr.row(0).collect::<Vec<_>>(), vec![(0, 1), (2, 2)]);
+
+    let csc = CscMatrix::from_dense(&a);
+    assert_eq!(csc.indptr(), &[0, 1, 2, 3]);
+    assert_eq!(csc.indices(), &[0, 2, 0]);
+    assert_eq!(csc.values(), &[1, 3, 2]);
+    assert_eq!(csc.col(2).collect::<Vec<_>>(), vec![(0, 2)]);
+}
+
+#[test]
+fn test_get() {
+    let csr = CsrMatrix::from_dense(&matrix![[1.0, 0.0], [0.0, 2.5]]);
+    assert_eq!(csr.get(1, 1), Some(2.5));
+    assert_eq!(csr.get(0, 1), Some(0.0));
+    assert_eq!(csr.get(2, 0), None);
+
+    let csc = csr.to_csc();
+    assert_eq!(csc.get(1, 1), Some(2.5));
+    assert_eq!(csc.get(1, 0), Some(0.0));
+    assert_eq!(csc.get(0, 2), None);
+}
+
+#[test]
+fn test_coo_duplicates_are_summed() {
+    let mut coo = CooMatrix::new(2, 3).unwrap();
+    coo.push(1, 2, 4.0);
+    coo.push(0, 0, 1.0);
+    coo.push(1, 2, -1.5);
+
+    assert_eq!(coo.nnz(), 3);
+    assert_eq!(coo.to_dense(), matrix![[1.0, 0.0, 0.0], [0.0, 0.0, 2.5]]);
+
+    let csr = coo.to_csr();
+    assert_eq!(csr.nnz(), 2);
+    assert_eq!(csr.get(1, 2), Some(2.5));
+    assert_eq!(coo.to_csc().to_dense(), coo.to_dense());
+}
+
+#[test]
+fn test_coo_from_triplets() {
+    let coo = CooMatrix::from_triplets(3, 3, vec![2, 0], vec![1, 2], vec![5, 7]).unwrap();
+    assert_eq!(coo.to_dense(), matrix![[0, 0, 7], [0, 0, 0], [0, 5, 0]]);
+    assert_eq!(coo.triplets().collect::<Vec<_>>(), vec![(2, 1, 5), (0, 2, 7)]);
+}
+
+#[test]
+fn test_csr_from_parts() {
+    let csr = CsrMatrix::from_parts(2, 3, vec![0, 1, 3], vec![1, 0, 2], vec![1.0, 2.0, 3.0]).unwrap();
+    assert_eq!(csr.to_dense(), matrix![[0.0, 1.0, 0.0], [2.0, 0.0, 3.0]]);
+
+    let csc = CscMatrix::from_parts(2, 3, vec![0, 1, 2, 3], vec![1, 0, 1], vec![2.0, 1.0, 3.0]).unwrap();
+    assert_eq!(csc.to_csr(), csr);
+}
+
+#[test]
+fn test_add_drops_cancelled_entries() {
+    let a = CsrMatrix::from_dense(&matrix![[1, 2], [0, 3]]);
+    let b = CsrMatrix::from_dense(&matrix![[-1, 0], [4, 0]]);
+    let sum = &a + &b;
+
+    assert_eq!(sum.to_dense(), matrix![[0, 2], [4, 3]]);
+    assert_eq!(sum.nnz(), 3);
+}
+
+#[test]
+fn test_graph_adjacency_paths() {
+    // directed 4-cycle, A^2 counts the length-2 walks
+    let mut coo = CooMatrix::new(4, 4).unwrap();
+    for i in 0..4 {
+        coo.push(i, (i + 1) % 4, 1);
+    }
+
+    let adj = coo.to_csr();
+    let two = adj.matmul_sparse(&adj);
+    for i in 0..4 {
+        assert_eq!(two.row(i).collect::<Vec<_>>(), vec![((i + 2) % 4, 1)]);
+    }
+}
+
+#[test]
+fn test_display_mirrors_dense() {
+    let a = matrix![[1.5, 0.0, 0.0], [0.0, 0.0, -2.0]];
+    let dense = format!("{}", a);
+
+    assert_eq!(format!("{}", CsrMatrix::from_dense(&a)), dense);
+    assert_eq!(format!("{}", CscMatrix::from_dense(&a)), dense);
+    assert_eq!(format!("{}", CooMatrix::from_dense(&a)), dense);
+}
+
+// -------- Error Tests --------
+
+#[test]
+fn test_try_push_out_of_bounds() {
+    let mut coo = CooMatrix::<f64>::new(2, 2).unwrap();
+    let err = coo.try_push(2, 0, 1.0).unwrap_err();
+    assert_eq!(err, MatrixError::IndexOutOfBounds { op: "push", index: (2, 0), shape: (2, 2) });
+}
+
+#[test]
+fn test_coo_zero_dimension() {
+    let err = CooMatrix::<f64>::new(0, 3).unwrap_err();
+    assert_eq!(err, MatrixError::ZeroDimension { rows: 0, cols: 3 });
+}
+
+#[test]
+fn test_from_triplets_bad_lengths() {
+    let err = CooMatrix::from_triplets(2, 2, vec![0, 1], vec![0], vec![1.0, 2.0]).unwrap_err();
+    assert!(matches!(err, MatrixError::InvalidStructure { op: "from_triplets", .. }));
+}
+
+#[test]
+fn test_from_triplets_out_of_bounds() {
+    let err = CooMatrix::from_triplets(2, 2, vec![0, 1], vec![0, 5], vec![1.0, 2.0]).unwrap_err();
+    assert_eq!(err, MatrixError::IndexOutOfBounds { op: "from_triplets", index: (1, 5), shape: (2, 2) });
+}
+
+#[test]
+fn test_from_parts_invalid() {
+    // indptr too short
+    assert!(CsrMatrix::from_parts(2, 2, vec![0, 1], vec![0], vec![1.0]).is_err());
+    // unsorted indices within a row
+    assert!(CsrMatrix::from_parts(1, 3, vec![0, 2], vec![2, 0], vec![1.0, 2.0]).is_err());
+    // index past the minor dim
+    assert!(CscMatrix::from_parts(2, 1, vec![0, 1], vec![2], vec![1.0]).is_err());
+    // decreasing indptr
+    let err = CsrMatrix::from_parts(2, 2, vec![0, 2, 1], vec![0], vec![1.0]).unwrap_err();
+    assert!(matches!(err, MatrixError::InvalidStructure { op: "from_parts", .. }));
+}
+
+#[test]
+fn test_try_matmul_bad_shape() {
+    let csr = CsrMatrix::from_dense(&Matrix::<f64>::identity(3).unwrap());
+    let err = csr.try_matmul(&Matrix::ones(2, 2).unwrap()).unwrap_err();
+    assert_eq!(err, MatrixError::InnerDimMismatch { op: "matmul", lhs: (3, 3), rhs: (2, 2) });
+
+    let err = csr.try_matmul_sparse(&CsrMatrix::from_dense(&Matrix::ones(2, 3).unwrap())).unwrap_err();
+    assert_eq!(err, MatrixError::InnerDimMismatch { op: "matmul_sparse", lhs: (3, 3), rhs: (2, 3) });
+}
+
+#[test]
+#[should_panic(expected = "Dimension mismatch")]
+fn test_add_shape_mismatch_panics() {
+    let a = CscMatrix::from_dense(&Matrix::<f64>::ones(2, 2).unwrap());
+    let b = CscMatrix::from_dense(&Matrix::<f64>::ones(2, 3).unwrap());
+    let _ = &a + &b;
+}
@@ -0,0 +1,161 @@
+use linalgae_rs::{matrix, core::{error::MatrixError, matrix::Matrix}};
+use num_traits::Float;
+use std::fmt::Debug;
+use std::ops::AddAssign;
+
+mod common;
+use common::{assert_close, filled, v};
+
+// -------- Generic Test Helpers --------
+
+fn assert_orthonormal_cols<T>(q: &Matrix<T>, tol: T)
+where
+    T: Float + Debug + Default + AddAssign,
+{
+    assert_close(&q.t().matmul(q), &Matrix::identity(q.cols()).unwrap(), tol);
+}
+
+fn test_svd_full<T>(m: usize, n: usize, tol: T)
+where
+    T: Float + Debug + Default + AddAssign,
+{
+    let a = filled::<T>(m, n, 1);
+    let svd = a.svd();
+
+    assert_eq!(svd.u().shape(), (m, m));
+    assert_eq!(svd.vt().shape(), (n, n));
+    assert_eq!(svd.sigma().shape(), (m, n));
+    assert_orthonormal_cols(svd.u(), tol);
+    assert_orthonormal_cols(&svd.vt().t(), tol);
+    assert_close(&svd.u().matmul(&svd.sigma()).matmul(svd.vt()), &a, tol);
+}
+
+fn test_svd_thin<T>(m: usize, n: usize, tol: T)
+where
+    T: Float + Debug + Default + AddAssign,
+{
+    let a = filled::<T>(m, n, 2);
+    let svd = a.svd_thin();
+    let k = m.min(n);
+
+    assert_eq!(svd.u().shape(), (m, k));
+    assert_eq!(svd.vt().shape(), (k, n));
+    assert_orthonormal_cols(svd.u(), tol);
+    assert_close(&svd.u().matmul(&svd.sigma()).matmul(svd.vt()), &a, tol);
+}
+
+fn test_singular_values_sorted<T>(tol: T)
+where
+    T: Float + Debug + Default + AddAssign,
+{
+    let a = filled::<T>(6, 4, 3);
+    let s = a.singular_values();
+    assert_eq!(s.len(), 4);
+    assert!(s.windows(2).all(|w| w[0] >= w[1]));
+    assert!(s.iter().all(|&x| x >= T::zero()));
+
+    // values-only path agrees with the full decomposition
+    for (x, y) in s.iter().zip(a.svd().singular_values()) {
+        assert!((*x - *y).abs() <= tol);
+    }
+
+    // squared singular values are the eigenvalues of A^T A, so their sum is its trace
+    let sum_sq = s.iter().fold(T::zero(), |acc, &x| acc + x * x);
+    assert!((sum_sq - a.t().matmul(&a).trace()).abs() <= tol * v(100.0));
+}
+
+// -------- f64 Tests --------
+
+#[test]
+fn test_svd_full_tall_f64() { test_svd_full::<f64>(7, 4, 1e-12); }
+
+#[test]
+fn test_svd_full_wide_f64() { test_svd_full::<f64>(3, 6, 1e-12); }
+
+#[test]
+fn test_svd_full_square_f64() { test_svd_full::<f64>(5, 5, 1e-12); }
+
+#[test]
+fn test_svd_thin_tall_f64() { test_svd_thin::<f64>(9, 3, 1e-12); }
+
+#[test]
+fn test_svd_thin_wide_f64() { test_svd_thin::<f64>(2, 5, 1e-12); }
+
+#[test]
+fn test_singular_values_sorted_f64() { test_singular_values_sorted::<f64>(1e-12); }
+
+// -------- f32 Tests --------
+
+#[test]
+fn test_svd_full_tall_f32() { test_svd_full::<f32>(7, 4, 1e-4); }
+
+#[test]
+fn test_svd_thin_wide_f32() { test_svd_thin::<f32>(2, 5, 1e-4); }
+
+#[test]
+fn test_singular_values_sorted_f32() { test_singular_values_sorted::<f32>(1e-4); }
+
+// -------- Known Value Tests --------
+
+#[test]
+fn test_svd_known_values() {
+    // singular values of [[3, 0], [4, 5]] are sqrt(45) and sqrt(5)
+    let s = matrix![[3.0, 0.0], [4.0, 5.0]].singular_values();
+    assert!((s[0] - 45f64.sqrt()).abs() < 1e-12);
+    assert!((s[1] - 5f64.sqrt()).abs() < 1e-12);
+}
+
+#[test]
+fn test_svd_rank_deficient() {
+    // rank 1, the missing U columns are still completed to an orthonormal basis
+    let a = matrix![[1.0, 2.0], [2.0, 4.0], [3.0, 6.0]];
+    let svd = a.svd();
+    assert!(svd.singular_values()[1].abs() < 1e-12);
+    assert_eq!(svd.rank_with_tol(1e-10), 1);
+    assert!(svd.cond() > 1e12);
+    assert_orthonormal_cols(svd.u(), 1e-12);
+    assert_close(&svd.u().matmul(&svd.sigma()).matmul(svd.vt()), &a, 1e-12);
+}
+
+#[test]
+fn test_svd_zero_matrix() {
+    let svd = Matrix::<f64>::zeroes(3, 2).unwrap().svd();
+    assert_eq!(svd.singular_values(), &[0.0, 0.0]);
+    assert_orthonormal_cols(svd.u(), 1e-15);
+}
+
+#[test]
+fn test_svd_condition_number() {
+    let a = matrix![[10.0, 0.0], [0.0, 0.1]];
+    assert!((a.svd().cond() - 100.0).abs() < 1e-10);
+}
+
+#[test]
+fn test_low_rank_approximation() {
+    let a = filled::<f64>(6, 5, 4);
+    let svd = a.svd_thin();
+    let s = svd.singular_values();
+
+    // Eckart-Young: the 2-norm error of the rank-r truncation is sigma_r
+    let approx = svd.low_rank(2);
+    let err = (&a - &approx).singular_values();
+    assert!((err[0] - s[2]).abs() < 1e-10);
+
+    // keeping every term gives A back
+    assert_close(&svd.low_rank(5), &a, 1e-12);
+}
+
+// -------- Error Tests --------
+
+#[test]
+fn test_not_converged_display_for_svd() {
+    let err = MatrixError::NotConverged { op: "svd", iterations: 60 };
+    assert!(err.to_string().contains("Failed to converge"));
+}
+
+#[test]
+fn test_try_singular_values_nan_does_not_panic() {
+    let a = matrix![[f64::NAN, 0.0], [0.0, 1.0]];
+    assert!(matches!(a.try_singular_values(), Err(MatrixError::NotConverged { op: "svd", .. })));
+    assert!(matches!(a.try_svd(), Err(MatrixError::NotConverged { op: "svd", .. })));
+}
@@ -0,0 +1,161 @@
+use linalgae_rs::{matrix, core::{error::MatrixError, matrix::Matrix}};
+use num_traits::Float;
+use std::fmt::Debug;
+use std::ops::AddAssign;
+
+mod common;
+use common::{assert_close, v};
+
+// -------- Generic Test Helpers --------
+
+// B + B^T, symmetric and indefinite
+fn symmetric<T>(n: usize) -> Matrix<T>
+where
+    T: Float + Default,
+{
+    let data = (0..n * n).map(|i| v::<T>(((i * 5 + 2) % 11) as f64 - 5.0) / v(2.0)).collect();
+    let b = Matrix::new(n, n, data).unwrap();
+    &b + &b.t()
+}
+
+fn test_eigen_decomposes<T>(n: usize, tol: T)
+where
+    T: Float + Debug + Default + AddAssign,
+{
+    let a = symmetric::<T>(n);
+    let eig = a.symmetric_eigen();
+    let q = eig.vectors();
+
+    // A * V = V * Lambda
+    assert_close(&a.matmul(q), &q.matmul(&eig.lambda()), tol);
+
+    // V^T * V = I
+    assert_close(&q.t().matmul(q), &Matrix::identity(n).unwrap(), tol);
+
+    assert!(eig.values().windows(2).all(|w| w[0] <= w[1]));
+}
+
+fn test_eigen_gram_matrix<T>(tol: T)
+where
+    T: Float + Debug + Default + AddAssign,
+{
+    // A^T A has the squared singular values of A as eigenvalues
+    let data = (0..15).map(|i| v::<T>(((i * 7 + 1) % 13) as f64 - 6.0)).collect();
+    let a = Matrix::new(5, 3, data).unwrap();
+    let gram = a.t().matmul(&a);
+    let eig = gram.symmetric_eigen();
+
+    let mut s: Vec<T> = a.singular_values().iter().map(|&x| x * x).collect();
+    s.reverse();
+
+    for (x, y) in eig.values().iter().zip(s.iter()) {
+        assert!((*x - *y).abs() <= tol * y.max(T::one()), "{:?} vs {:?}", x, y);
+    }
+}
+
+fn test_map_values<T>(tol: T)
+where
+    T: Float + Debug + Default + AddAssign,
+{
+    // matrix square root of an SPD matrix squares back to it
+    let a: Matrix<T> = matrix![[v(4.0), v(1.0), v(0.0)], [v(1.0), v(3.0), v(1.0)], [v(0.0), v(1.0), v(2.0)]];
+    let root = a.symmetric_eigen().map_values(|x| x.sqrt());
+    assert_close(&root.matmul(&root), &a, tol);
+}
+
+// -------- f64 Tests --------
+
+#[test]
+fn test_eigen_decomposes_f64() { test_eigen_decomposes::<f64>(6, 1e-12); }
+
+#[test]
+fn test_eigen_decomposes_large_f64() { test_eigen_decomposes::<f64>(25, 1e-11); }
+
+#[test]
+fn test_eigen_gram_matrix_f64() { test_eigen_gram_matrix::<f64>(1e-12); }
+
+#[test]
+fn test_map_values_f64() { test_map_values::<f64>(1e-12); }
+
+// -------- f32 Tests --------
+
+#[test]
+fn test_eigen_decomposes_f32() { test_eigen_decomposes::<f32>(6, 1e-4); }
+
+#[test]
+fn test_eigen_gram_matrix_f32() { test_eigen_gram_matrix::<f32>(1e-4); }
+
+#[test]
+fn test_map_values_f32() { test_map_values::<f32>(1e-4); }
+
+// -------- Known Value Tests --------
+
+#[test]
+fn test_eigen_known_values() {
+    // eigenvalues of the 1D Laplacian tridiag(-1, 2, -1) are 2 - 2 cos(k pi / (n + 1))
+    let n = 5;
+    let mut a = Matrix::<f64>::zeroes(n, n).unwrap();
+    for i in 0..n {
+        a[(i, i)] = 2.0;
+        if i + 1 < n {
+            a[(i, i + 1)] = -1.0;
+            a[(i + 1, i)] = -1.0;
+        }
+    }
+
+    let eig = a.symmetric_eigen();
+    for (k, &x) in eig.values().iter().enumerate() {
+        let expected = 2.0 - 2.0 * ((k + 1) as f64 * std::f64::consts::PI / (n + 1) as f64).cos();
+        assert!((x - expected).abs() < 1e-12);
+    }
+}
+
+#[test]
+fn test_eigen_repeated_values() {
+    // identity plus a rank-1 term, eigenvalue 1 has multiplicity 2
+    let a = matrix![[2.0, 1.0, 1.0], [1.0, 2.0, 1.0], [1.0, 1.0, 2.0]];
+    let eig = a.symmetric_eigen();
+    let q = eig.vectors();
+
+    assert!((eig.values()[0] - 1.0).abs() < 1e-12);
+    assert!((eig.values()[1] - 1.0).abs() < 1e-12);
+    assert!((eig.values()[2] - 4.0).abs() < 1e-12);
+    assert_close(&q.t().matmul(q), &Matrix::identity(3).unwrap(), 1e-12);
+}
+
+#[test]
+fn test_eigen_diagonal_input() {
+    let a = matrix![[3.0, 0.0, 0.0], [0.0, -1.0, 0.0], [0.0, 0.0, 2.0]];
+    let eig = a.symmetric_eigen();
+    assert_eq!(eig.values(), &[-1.0, 2.0, 3.0]);
+    assert_close(&a.matmul(eig.vectors()), &eig.vectors().matmul(&eig.lambda()), 1e-15);
+}
+
+#[test]
+fn test_eigen_one_by_one() {
+    let eig = matrix![[-7.5]].symmetric_eigen();
+    assert_eq!(eig.values(), &[-7.5]);
+    assert_eq!(eig.vectors().as_slice(), &[1.0]);
+}
+
+// -------- Error Tests --------
+
+#[test]
+fn test_try_symmetric_eigen_not_symmetric() {
+    let err = matrix![[1.0, 2.0], [0.0, 1.0]].try_symmetric_eigen().unwrap_err();
+    assert_eq!(err, MatrixError::NotSymmetric { op: "symmetric_eigen" });
+}
+
+#[test]
+fn test_try_symmetric_eigen_not_square() {
+    let err = Matrix::<f64>::ones(3, 2).unwrap().try_symmetric_eigen().unwrap_err();
+    assert_eq!(err, MatrixError::NotSquare { op: "symmetric_eigen", rows: 3, cols: 2 });
+}
+
+#[test]
+fn test_try_symmetric_eigen_nan_diagonal() {
+    // only off-diagonals are compared by the symmetry check, so this reaches the eigenvalue sort
+    let eig = matrix![[f64::NAN, 0.0], [0.0, 1.0]].try_symmetric_eigen().unwrap();
+    assert_eq!(eig.values()[0], 1.0);
+    assert!(eig.values()[1].is_nan());
+}
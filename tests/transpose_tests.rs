@@ -0,0 +1,167 @@
+use linalgae_rs::{matrix, core::{error::MatrixError, matrix::Matrix}};
+use num_traits::Num;
+use std::fmt::Debug;
+
+// -------- Generic Test Functions --------
+
+fn test_t_view_shape_and_strides<T>()
+where
+    T: Num + Copy + PartialEq + Debug + From<u8>,
+{
+    let m = matrix![[T::from(1), T::from(2), T::from(3)], [T::from(4), T::from(5), T::from(6)]];
+    let v = m.t_view();
+    assert_eq!(v.shape(), (3, 2));
+    assert_eq!(v.row_stride(), 1);
+    assert_eq!(v.col_stride(), 3);
+    assert_eq!(v[(2, 1)], T::from(6));
+}
+
+fn test_t_view_matches_t<T>()
+where
+    T: Num + Copy + PartialEq + Debug + Default + From<u8>,
+{
+    let m = matrix![
+        [T::from(1), T::from(2), T::from(3), T::from(4)],
+        [T::from(5), T::from(6), T::from(7), T::from(8)]
+    ];
+    assert_eq!(m.t_view().to_owned(), m.t());
+}
+
+fn test_double_transpose<T>()
+where
+    T: Num + Copy + PartialEq + Debug + From<u8>,
+{
+    let m = matrix![[T::from(1), T::from(2)], [T::from(3), T::from(4)], [T::from(5), T::from(6)]];
+    assert_eq!(m.t_view().t().to_owned(), m);
+}
+
+fn test_transposed_slice<T>()
+where
+    T: Num + Copy + PartialEq + Debug + From<u8>,
+{
+    let m = matrix![
+        [T::from(1), T::from(2), T::from(3)],
+        [T::from(4), T::from(5), T::from(6)],
+        [T::from(7), T::from(8), T::from(9)]
+    ];
+    // rows 1..3 of the transpose are cols 1..3 of m
+    let v = m.t_view().slice(1..3, 0..2);
+    assert_eq!(v.to_owned().as_slice(), &[T::from(2), T::from(5), T::from(3), T::from(6)]);
+}
+
+fn test_matmul_with_t_view<T>()
+where
+    T: Num + Copy + PartialEq + Debug + Default + From<u8> + std::ops::AddAssign,
+{
+    let a = matrix![[T::from(1), T::from(2), T::from(3)], [T::from(4), T::from(5), T::from(6)]];
+    let b = matrix![[T::from(1), T::from(0), T::from(2)], [T::from(0), T::from(1), T::from(1)]];
+
+    // A^T * B and A * B^T without materialising the transpose
+    assert_eq!(a.t_view().matmul(&b), a.t().matmul(&b));
+    assert_eq!(a.matmul(&b.t_view()), a.matmul(&b.t()));
+    assert_eq!(&a.t_view() * &b, a.t().matmul(&b));
+    assert_eq!(&a * &b.t_view(), a.matmul(&b.t()));
+}
+
+fn test_mixed_add_sub<T>()
+where
+    T: Num + Copy + PartialEq + Debug + Default + From<u8>,
+{
+    let a = matrix![[T::from(1), T::from(2)], [T::from(3), T::from(4)]];
+    let b = matrix![[T::from(5), T::from(6)], [T::from(7), T::from(8)]];
+    assert_eq!(&a.t_view() + &b, &a.t() + &b);
+    assert_eq!(&b + &a.t_view(), &b + &a.t());
+    assert_eq!(&b - &a.t_view(), &b - &a.t());
+    assert_eq!(&a.t_view() - &b, &a.t() - &b);
+}
+
+fn test_transpose_inplace<T>()
+where
+    T: Num + Copy + PartialEq + Debug + Default + From<u8>,
+{
+    let m = matrix![
+        [T::from(1), T::from(2), T::from(3)],
+        [T::from(4), T::from(5), T::from(6)],
+        [T::from(7), T::from(8), T::from(9)]
+    ];
+    let mut n = m.clone();
+    n.transpose_inplace();
+    assert_eq!(n, m.t());
+}
+
+// -------- f64 Tests --------
+
+#[test]
+fn test_t_view_shape_and_strides_f64() { test_t_view_shape_and_strides::<f64>(); }
+
+#[test]
+fn test_t_view_matches_t_f64() { test_t_view_matches_t::<f64>(); }
+
+#[test]
+fn test_double_transpose_f64() { test_double_transpose::<f64>(); }
+
+#[test]
+fn test_transposed_slice_f64() { test_transposed_slice::<f64>(); }
+
+#[test]
+fn test_matmul_with_t_view_f64() { test_matmul_with_t_view::<f64>(); }
+
+#[test]
+fn test_mixed_add_sub_f64() { test_mixed_add_sub::<f64>(); }
+
+#[test]
+fn test_transpose_inplace_f64() { test_transpose_inplace::<f64>(); }
+
+// -------- i32 Tests --------
+
+#[test]
+fn test_t_view_shape_and_strides_i32() { test_t_view_shape_and_strides::<i32>(); }
+
+#[test]
+fn test_t_view_matches_t_i32() { test_t_view_matches_t::<i32>(); }
+
+#[test]
+fn test_double_transpose_i32() { test_double_transpose::<i32>(); }
+
+#[test]
+fn test_transposed_slice_i32() { test_transposed_slice::<i32>(); }
+
+#[test]
+fn test_matmul_with_t_view_i32() { test_matmul_with_t_view::<i32>(); }
+
+#[test]
+fn test_mixed_add_sub_i32() { test_mixed_add_sub::<i32>(); }
+
+#[test]
+fn test_transpose_inplace_i32() { test_transpose_inplace::<i32>(); }
+
+// -------- Large Matrix Tests --------
+
+#[test]
+fn test_transpose_inplace_spans_tiles() {
+    // 70 is not a multiple of the tile size, covers partial edge tiles
+    let n = 70;
+    let data: Vec<f64> = (0..n * n).map(|x| x as f64).collect();
+    let m = Matrix::new(n, n, data).unwrap();
+    let mut t = m.clone();
+    t.transpose_inplace();
+    assert_eq!(t, m.t());
+    t.transpose_inplace();
+    assert_eq!(t, m);
+}
+
+// -------- Error Tests --------
+
+#[test]
+fn test_try_transpose_inplace_not_square() {
+    let mut m = Matrix::<f64>::zeroes(2, 3).unwrap();
+    let err = m.try_transpose_inplace().unwrap_err();
+    assert_eq!(err, MatrixError::NotSquare { op: "transpose_inplace", rows: 2, cols: 3 });
+}
+
+#[test]
+#[should_panic(expected = "lhs_cols")]
+fn test_matmul_t_view_mismatch() {
+    let a = Matrix::<f64>::zeroes(2, 3).unwrap();
+    let _ = a.matmul(&a.t_view().t());
+}
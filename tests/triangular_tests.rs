@@ -0,0 +1,217 @@
+use linalgae_rs::{matrix, core::{error::MatrixError, matrix::Matrix}};
+use num_traits::Float;
+use std::fmt::Debug;
+use std::ops::AddAssign;
+
+mod common;
+use common::{assert_close, v};
+
+// -------- Generic Test Helpers --------
+
+// dense matrix with a dominant diagonal, both triangles filled
+fn dense<T: Float>(n: usize) -> Matrix<T> {
+    let data = (0..n * n).map(|i| if i % (n + 1) == 0 { v::<T>(4.0 + (i % 3) as f64) } else { v::<T>(((i * 7) % 5) as f64 - 2.0) / v(2.0) }).collect();
+    Matrix::new(n, n, data).unwrap()
+}
+
+// explicit triangles of a, for checking the solves against plain matmul
+fn lower<T: Float + Default>(a: &Matrix<T>, unit: bool) -> Matrix<T> {
+    let mut l = a.to_lower_triangular().to_matrix();
+    if unit {
+        for i in 0..l.rows() { l[(i, i)] = T::one(); }
+    }
+    l
+}
+
+fn upper<T: Float + Default>(a: &Matrix<T>, unit: bool) -> Matrix<T> {
+    let mut u = a.to_upper_triangular().to_matrix();
+    if unit {
+        for i in 0..u.rows() { u[(i, i)] = T::one(); }
+    }
+    u
+}
+
+fn test_triangular_solves<T>(tol: T)
+where
+    T: Float + Debug + Default + AddAssign,
+{
+    let a = dense::<T>(5);
+    let x: Matrix<T> = matrix![[v(1.0), v(0.5)], [v(-2.0), v(1.0)], [v(0.0), v(3.0)], [v(1.5), v(-1.0)], [v(2.0), v(0.0)]];
+
+    let (l, u) = (lower(&a, false), upper(&a, false));
+    let (l1, u1) = (lower(&a, true), upper(&a, true));
+
+    assert_close(&a.solve_lower_triangular(&l.matmul(&x)), &x, tol);
+    assert_close(&a.solve_upper_triangular(&u.matmul(&x)), &x, tol);
+    assert_close(&a.solve_lower_triangular_unit(&l1.matmul(&x)), &x, tol);
+    assert_close(&a.solve_upper_triangular_unit(&u1.matmul(&x)), &x, tol);
+    assert_close(&a.solve_lower_triangular_transpose(&l.t().matmul(&x)), &x, tol);
+    assert_close(&a.solve_upper_triangular_transpose(&u.t().matmul(&x)), &x, tol);
+}
+
+fn test_packed_matmul<T>(tol: T)
+where
+    T: Float + Debug + Default + AddAssign,
+{
+    let a = dense::<T>(6);
+    let b = dense::<T>(6).t();
+    let (l, u) = (a.to_lower_triangular(), a.to_upper_triangular());
+
+    assert_close(&l.matmul(&b), &l.to_matrix().matmul(&b), tol);
+    assert_close(&u.matmul(&b), &u.to_matrix().matmul(&b), tol);
+    assert_close(&l.matmul_lower(&b.to_lower_triangular()).to_matrix(), &l.to_matrix().matmul(&b.to_lower_triangular().to_matrix()), tol);
+    assert_close(&u.matmul_upper(&b.to_upper_triangular()).to_matrix(), &u.to_matrix().matmul(&b.to_upper_triangular().to_matrix()), tol);
+}
+
+fn test_packed_solves<T>(tol: T)
+where
+    T: Float + Debug + Default + AddAssign,
+{
+    let a = dense::<T>(4);
+    let x: Matrix<T> = matrix![[v(1.0)], [v(-1.0)], [v(2.0)], [v(0.5)]];
+    let (l, u) = (a.to_lower_triangular(), a.to_upper_triangular());
+
+    assert_close(&l.solve(&l.matmul(&x)), &x, tol);
+    assert_close(&u.solve(&u.matmul(&x)), &x, tol);
+    assert_close(&l.solve_transpose(&l.t().matmul(&x)), &x, tol);
+    assert_close(&u.solve_transpose(&u.t().matmul(&x)), &x, tol);
+
+    // packed solves agree with the dense ones
+    assert_close(&l.solve(&x), &a.solve_lower_triangular(&x), tol);
+    assert_close(&u.solve_transpose(&x), &a.solve_upper_triangular_transpose(&x), tol);
+}
+
+// -------- f64 Tests --------
+
+#[test]
+fn test_triangular_solves_f64() { test_triangular_solves::<f64>(1e-12); }
+
+#[test]
+fn test_packed_matmul_f64() { test_packed_matmul::<f64>(1e-12); }
+
+#[test]
+fn test_packed_solves_f64() { test_packed_solves::<f64>(1e-12); }
+
+// -------- f32 Tests --------
+
+#[test]
+fn test_triangular_solves_f32() { test_triangular_solves::<f32>(1e-4); }
+
+#[test]
+fn test_packed_matmul_f32() { test_packed_matmul::<f32>(1e-4); }
+
+#[test]
+fn test_packed_solves_f32() { test_packed_solves::<f32>(1e-4); }
+
+// -------- Packed Storage Tests --------
+
+#[test]
+fn test_packed_storage_layout() {
+    let a = matrix![[1, 2, 3], [4, 5, 6], [7, 8, 9]];
+    let l = a.to_lower_triangular();
+    let u = a.to_upper_triangular();
+
+    assert_eq!(l.as_packed(), &[1, 4, 5, 7, 8, 9]);
+    assert_eq!(u.as_packed(), &[1, 2, 3, 5, 6, 9]);
+    assert_eq!(l.to_matrix(), matrix![[1, 0, 0], [4, 5, 0], [7, 8, 9]]);
+    assert_eq!(u.to_matrix(), matrix![[1, 2, 3], [0, 5, 6], [0, 0, 9]]);
+    assert_eq!(l.diagonal(), vec![1, 5, 9]);
+    assert_eq!(u.size(), 3);
+}
+
+#[test]
+fn test_packed_get() {
+    let l = matrix![[1, 2], [3, 4]].to_lower_triangular();
+    assert_eq!(l.get(1, 0), Some(3));
+    assert_eq!(l.get(0, 1), Some(0));
+    assert_eq!(l.get(2, 0), None);
+
+    let u = matrix![[1, 2], [3, 4]].to_upper_triangular();
+    assert_eq!(u.get(0, 1), Some(2));
+    assert_eq!(u.get(1, 0), Some(0));
+    assert_eq!(u.get(0, 2), None);
+}
+
+#[test]
+fn test_packed_transpose() {
+    let a = matrix![[1, 2, 3], [4, 5, 6], [7, 8, 9]];
+    let l = a.to_lower_triangular();
+    let u = a.to_upper_triangular();
+
+    assert_eq!(l.t().to_matrix(), l.to_matrix().t());
+    assert_eq!(u.t().to_matrix(), u.to_matrix().t());
+    assert_eq!(l.t().t(), l);
+}
+
+#[test]
+fn test_packed_det() {
+    let a = matrix![[2.0, 9.0, 9.0], [1.0, 3.0, 9.0], [1.0, 1.0, -0.5]];
+    assert_eq!(a.to_lower_triangular().det(), -3.0);
+    assert_eq!(a.to_upper_triangular().det(), -3.0);
+}
+
+#[test]
+fn test_solve_reads_only_one_triangle() {
+    // the upper half is garbage, the lower solve must not see it
+    let a = matrix![[2.0, f64::NAN], [1.0, 4.0]];
+    let x = a.solve_lower_triangular(&matrix![[2.0], [9.0]]);
+    assert_eq!(x, matrix![[1.0], [2.0]]);
+
+    // unit solve ignores the diagonal, even a zero one
+    let b = matrix![[0.0, 0.0], [3.0, 0.0]];
+    assert_eq!(b.solve_lower_triangular_unit(&matrix![[1.0], [5.0]]), matrix![[1.0], [2.0]]);
+}
+
+#[test]
+fn test_cholesky_via_triangular_solves() {
+    let a = matrix![[4.0, 2.0, 0.0], [2.0, 5.0, 1.0], [0.0, 1.0, 3.0]];
+    let b = matrix![[1.0], [2.0], [3.0]];
+    let l = a.cholesky().l().clone();
+
+    let y = l.solve_lower_triangular(&b);
+    let x = l.solve_lower_triangular_transpose(&y);
+    assert_close(&x, &a.cholesky().solve(&b), 1e-14);
+}
+
+// -------- Error Tests --------
+
+#[test]
+fn test_try_solve_lower_triangular_singular() {
+    let a = matrix![[1.0, 0.0], [2.0, 0.0]];
+    let err = a.try_solve_lower_triangular(&matrix![[1.0], [1.0]]).unwrap_err();
+    assert_eq!(err, MatrixError::Singular { op: "solve_lower_triangular" });
+}
+
+#[test]
+fn test_try_solve_upper_triangular_bad_rhs() {
+    let a = Matrix::<f64>::identity(3).unwrap();
+    let err = a.try_solve_upper_triangular(&Matrix::ones(2, 1).unwrap()).unwrap_err();
+    assert_eq!(err, MatrixError::InnerDimMismatch { op: "solve_upper_triangular", lhs: (3, 3), rhs: (2, 1) });
+}
+
+#[test]
+fn test_try_solve_triangular_not_square() {
+    let a = Matrix::<f64>::ones(2, 3).unwrap();
+    let err = a.try_solve_lower_triangular_transpose(&Matrix::ones(2, 1).unwrap()).unwrap_err();
+    assert_eq!(err, MatrixError::NotSquare { op: "solve_lower_triangular_transpose", rows: 2, cols: 3 });
+}
+
+#[test]
+fn test_try_to_lower_triangular_not_square() {
+    let err = Matrix::<i32>::ones(3, 2).unwrap().try_to_lower_triangular().unwrap_err();
+    assert_eq!(err, MatrixError::NotSquare { op: "to_lower_triangular", rows: 3, cols: 2 });
+}
+
+#[test]
+fn test_try_packed_matmul_bad_shape() {
+    let u = Matrix::<f64>::identity(3).unwrap().to_upper_triangular();
+    let err = u.try_matmul(&Matrix::ones(4, 2).unwrap()).unwrap_err();
+    assert_eq!(err, MatrixError::InnerDimMismatch { op: "matmul", lhs: (3, 3), rhs: (4, 2) });
+}
+
+#[test]
+#[should_panic(expected = "singular")]
+fn test_packed_solve_singular_panics() {
+    let l = matrix![[0.0, 0.0], [1.0, 1.0]].to_lower_triangular();
+    let _ = l.solve(&matrix![[1.0], [1.0]]);
+}
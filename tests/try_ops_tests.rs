@@ -0,0 +1,192 @@
+use linalgae_rs::{matrix, core::{error::MatrixError, matrix::Matrix}};
+use num_traits::Num;
+use std::fmt::Debug;
+
+// -------- Generic Test Functions --------
+
+fn test_try_add_ok<T>()
+where
+    T: Num + Copy + PartialEq + Debug + From<u8>,
+{
+    let a = matrix![[T::from(1), T::from(2)], [T::from(3), T::from(4)]];
+    let b = matrix![[T::from(5), T::from(6)], [T::from(7), T::from(8)]];
+    let c = a.try_add(&b).unwrap();
+    assert_eq!(c, &a + &b);
+}
+
+fn test_try_add_mismatch<T>()
+where
+    T: Num + Copy + PartialEq + Debug + From<u8>,
+{
+    let a = matrix![[T::from(1), T::from(2)], [T::from(3), T::from(4)]];
+    let b = matrix![[T::from(1), T::from(2), T::from(3)]];
+    assert_eq!(a.try_add(&b).unwrap_err(), MatrixError::ShapeMismatch { op: "Add", lhs: (2, 2), rhs: (1, 3) });
+}
+
+fn test_try_sub_ok<T>()
+where
+    T: Num + Copy + PartialEq + Debug + From<u8>,
+{
+    let a = matrix![[T::from(5), T::from(6)], [T::from(7), T::from(8)]];
+    let b = matrix![[T::from(1), T::from(2)], [T::from(3), T::from(4)]];
+    let c = a.try_sub(&b).unwrap();
+    assert_eq!(c.as_slice(), &[T::from(4), T::from(4), T::from(4), T::from(4)]);
+}
+
+fn test_try_sub_mismatch<T>()
+where
+    T: Num + Copy + PartialEq + Debug + From<u8>,
+{
+    let a = matrix![[T::from(1)], [T::from(2)]];
+    let b = matrix![[T::from(1), T::from(2)]];
+    assert_eq!(a.try_sub(&b).unwrap_err(), MatrixError::ShapeMismatch { op: "Sub", lhs: (2, 1), rhs: (1, 2) });
+}
+
+fn test_try_div_scalar_ok<T>()
+where
+    T: Num + Copy + PartialEq + Debug + From<u8>,
+{
+    let a = matrix![[T::from(2), T::from(4)], [T::from(6), T::from(8)]];
+    let c = a.try_div_scalar(T::from(2)).unwrap();
+    assert_eq!(c.as_slice(), &[T::from(1), T::from(2), T::from(3), T::from(4)]);
+}
+
+fn test_try_div_scalar_zero<T>()
+where
+    T: Num + Copy + PartialEq + Debug + From<u8>,
+{
+    let a = matrix![[T::from(2), T::from(4)]];
+    assert_eq!(a.try_div_scalar(T::zero()).unwrap_err(), MatrixError::DivisionByZero { op: "Div" });
+}
+
+fn test_try_matmul_ok<T>()
+where
+    T: Num + Copy + PartialEq + Debug + From<u8> + std::ops::AddAssign,
+{
+    let a = matrix![[T::from(1), T::from(2)], [T::from(3), T::from(4)]];
+    let b = matrix![[T::from(5)], [T::from(6)]];
+    let c = a.try_matmul(&b).unwrap();
+    assert_eq!(c.shape(), (2, 1));
+    assert_eq!(c.as_slice(), &[T::from(17), T::from(39)]);
+}
+
+fn test_try_matmul_mismatch<T>()
+where
+    T: Num + Copy + PartialEq + Debug + From<u8> + std::ops::AddAssign,
+{
+    let a = matrix![[T::from(1), T::from(2), T::from(3)]];
+    let b = matrix![[T::from(1), T::from(2)]];
+    assert_eq!(a.try_matmul(&b).unwrap_err(), MatrixError::InnerDimMismatch { op: "matmul", lhs: (1, 3), rhs: (1, 2) });
+}
+
+fn test_try_component_mul_mismatch<T>()
+where
+    T: Num + Copy + PartialEq + Debug + From<u8> + std::ops::MulAssign,
+{
+    let a = matrix![[T::from(1), T::from(2)]];
+    let b = matrix![[T::from(1)], [T::from(2)]];
+    assert_eq!(a.try_component_mul(&b).unwrap_err(), MatrixError::ShapeMismatch { op: "component_mul", lhs: (1, 2), rhs: (2, 1) });
+
+    // inplace variant leaves lhs untouched on error
+    let mut c = a.clone();
+    assert!(c.try_component_mul_inplace(&b).is_err());
+    assert_eq!(c, a);
+}
+
+fn test_try_inplace_ops<T>()
+where
+    T: Num + Copy + PartialEq + Debug + From<u8> + std::ops::AddAssign + std::ops::SubAssign,
+{
+    let mut a = matrix![[T::from(1), T::from(2)]];
+    let b = matrix![[T::from(3), T::from(4)]];
+    a.try_add_assign(&b).unwrap();
+    assert_eq!(a.as_slice(), &[T::from(4), T::from(6)]);
+    a.try_sub_assign(&b).unwrap();
+    assert_eq!(a.as_slice(), &[T::from(1), T::from(2)]);
+    a.try_sub_assign_scaled(&b, T::from(0)).unwrap();
+    assert_eq!(a.as_slice(), &[T::from(1), T::from(2)]);
+
+    let wrong = matrix![[T::from(1)]];
+    assert_eq!(a.try_add_assign(&wrong).unwrap_err(), MatrixError::ShapeMismatch { op: "AddAssign", lhs: (1, 2), rhs: (1, 1) });
+    assert_eq!(a.try_sub_assign(&wrong).unwrap_err(), MatrixError::ShapeMismatch { op: "SubAssign", lhs: (1, 2), rhs: (1, 1) });
+    assert_eq!(a.try_sub_assign_scaled(&wrong, T::from(1)).unwrap_err(), MatrixError::ShapeMismatch { op: "sub_assign_scaled", lhs: (1, 2), rhs: (1, 1) });
+    assert_eq!(a.as_slice(), &[T::from(1), T::from(2)]);
+}
+
+// -------- f64 Tests --------
+
+#[test]
+fn test_try_add_ok_f64() { test_try_add_ok::<f64>(); }
+
+#[test]
+fn test_try_add_mismatch_f64() { test_try_add_mismatch::<f64>(); }
+
+#[test]
+fn test_try_sub_ok_f64() { test_try_sub_ok::<f64>(); }
+
+#[test]
+fn test_try_sub_mismatch_f64() { test_try_sub_mismatch::<f64>(); }
+
+#[test]
+fn test_try_div_scalar_ok_f64() { test_try_div_scalar_ok::<f64>(); }
+
+#[test]
+fn test_try_div_scalar_zero_f64() { test_try_div_scalar_zero::<f64>(); }
+
+#[test]
+fn test_try_matmul_ok_f64() { test_try_matmul_ok::<f64>(); }
+
+#[test]
+fn test_try_matmul_mismatch_f64() { test_try_matmul_mismatch::<f64>(); }
+
+#[test]
+fn test_try_component_mul_mismatch_f64() { test_try_component_mul_mismatch::<f64>(); }
+
+#[test]
+fn test_try_inplace_ops_f64() { test_try_inplace_ops::<f64>(); }
+
+// -------- i32 Tests --------
+
+#[test]
+fn test_try_add_ok_i32() { test_try_add_ok::<i32>(); }
+
+#[test]
+fn test_try_add_mismatch_i32() { test_try_add_mismatch::<i32>(); }
+
+#[test]
+fn test_try_sub_ok_i32() { test_try_sub_ok::<i32>(); }
+
+#[test]
+fn test_try_sub_mismatch_i32() { test_try_sub_mismatch::<i32>(); }
+
+#[test]
+fn test_try_div_scalar_ok_i32() { test_try_div_scalar_ok::<i32>(); }
+
+#[test]
+fn test_try_div_scalar_zero_i32() { test_try_div_scalar_zero::<i32>(); }
+
+#[test]
+fn test_try_matmul_ok_i32() { test_try_matmul_ok::<i32>(); }
+
+#[test]
+fn test_try_matmul_mismatch_i32() { test_try_matmul_mismatch::<i32>(); }
+
+#[test]
+fn test_try_component_mul_mismatch_i32() { test_try_component_mul_mismatch::<i32>(); }
+
+#[test]
+fn test_try_inplace_ops_i32() { test_try_inplace_ops::<i32>(); }
+
+// -------- rand_init Tests --------
+
+#[test]
+fn test_try_rand_init_ok() {
+    let m = Matrix::<f64>::try_rand_init(3, 4).unwrap();
+    assert_eq!(m.shape(), (3, 4));
+}
+
+#[test]
+fn test_try_rand_init_zero_dimension() {
+    let err = Matrix::<f32>::try_rand_init(0, 4).unwrap_err();
+    assert_eq!(err, MatrixError::ZeroDimension { rows: 0, cols: 4 });
+}
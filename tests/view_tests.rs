@@ -0,0 +1,280 @@
+use linalgae_rs::{matrix, core::{error::MatrixError, matrix::Matrix}};
+use num_traits::Num;
+use std::fmt::Debug;
+
+// -------- Generic Test Helpers --------
+
+// 3 x 4 matrix holding 1..=12
+fn sample<T>() -> Matrix<T>
+where
+    T: Num + Copy + From<u8>,
+{
+    matrix![
+        [T::from(1), T::from(2), T::from(3), T::from(4)],
+        [T::from(5), T::from(6), T::from(7), T::from(8)],
+        [T::from(9), T::from(10), T::from(11), T::from(12)]
+    ]
+}
+
+fn test_full_view<T>()
+where
+    T: Num + Copy + PartialEq + Debug + From<u8>,
+{
+    let m = sample::<T>();
+    let v = m.view();
+    assert_eq!(v.shape(), (3, 4));
+    assert_eq!(v.row_stride(), 4);
+    assert_eq!(v.col_stride(), 1);
+    assert_eq!(v.to_owned(), m);
+}
+
+fn test_slice_block<T>()
+where
+    T: Num + Copy + PartialEq + Debug + From<u8>,
+{
+    let m = sample::<T>();
+    let v = m.slice(1..3, 1..3);
+    assert_eq!(v.shape(), (2, 2));
+    assert_eq!(v[(0, 0)], T::from(6));
+    assert_eq!(v[(1, 1)], T::from(11));
+    assert_eq!(v.to_owned().as_slice(), &[T::from(6), T::from(7), T::from(10), T::from(11)]);
+}
+
+fn test_nested_slice<T>()
+where
+    T: Num + Copy + PartialEq + Debug + From<u8>,
+{
+    let m = sample::<T>();
+    let v = m.slice(0..3, 1..4).slice(1..3, 0..2);
+    assert_eq!(v.to_owned().as_slice(), &[T::from(6), T::from(7), T::from(10), T::from(11)]);
+}
+
+fn test_row_and_col<T>()
+where
+    T: Num + Copy + PartialEq + Debug + From<u8>,
+{
+    let m = sample::<T>();
+    let r = m.row(1);
+    assert_eq!(r.shape(), (1, 4));
+    assert_eq!(r.to_owned().as_slice(), &[T::from(5), T::from(6), T::from(7), T::from(8)]);
+
+    let c = m.col(2);
+    assert_eq!(c.shape(), (3, 1));
+    assert_eq!(c.to_owned().as_slice(), &[T::from(3), T::from(7), T::from(11)]);
+}
+
+fn test_view_sum<T>()
+where
+    T: Num + Copy + PartialEq + Debug + From<u8> + std::iter::Sum<T>,
+{
+    let m = sample::<T>();
+    assert_eq!(m.view().sum(), m.sum());
+    assert_eq!(m.col(0).sum(), T::from(15));
+    assert_eq!(m.slice(0..2, 2..4).sum(), T::from(22));
+}
+
+fn test_view_apply<T>()
+where
+    T: Num + Copy + PartialEq + Debug + From<u8>,
+{
+    let m = sample::<T>();
+    let r = m.row(0).apply(|x| x * x);
+    assert_eq!(r.as_slice(), &[T::from(1), T::from(4), T::from(9), T::from(16)]);
+}
+
+fn test_view_matmul<T>()
+where
+    T: Num + Copy + PartialEq + Debug + From<u8> + std::ops::AddAssign,
+{
+    let m = sample::<T>();
+    let a = m.slice(0..2, 0..2);
+    let b = m.slice(1..3, 2..4);
+    let expected = a.to_owned().matmul(&b.to_owned());
+    assert_eq!(a.matmul(&b), expected);
+    assert_eq!(&a * &b, expected);
+}
+
+fn test_view_arithmetic<T>()
+where
+    T: Num + Copy + PartialEq + Debug + From<u8>,
+{
+    let m = sample::<T>();
+    let a = m.row(2);
+    let b = m.row(0);
+    assert_eq!((&a - &b).as_slice(), &[T::from(8), T::from(8), T::from(8), T::from(8)]);
+    assert_eq!((&a + &b).as_slice(), &[T::from(10), T::from(12), T::from(14), T::from(16)]);
+    assert_eq!((&b * T::from(2)).as_slice(), &[T::from(2), T::from(4), T::from(6), T::from(8)]);
+    assert_eq!((&a.col(0) / T::from(3)).as_slice(), &[T::from(3)]);
+    assert_eq!(a.component_mul(&b).as_slice(), &[T::from(9), T::from(20), T::from(33), T::from(48)]);
+}
+
+fn test_view_mut_block<T>()
+where
+    T: Num + Copy + PartialEq + Debug + From<u8>,
+{
+    let mut m = sample::<T>();
+    {
+        let mut block = m.slice_mut(0..2, 2..4);
+        block.fill(T::zero());
+        block[(1, 1)] = T::from(99);
+    }
+    assert_eq!(m.row(0).to_owned().as_slice(), &[T::from(1), T::from(2), T::zero(), T::zero()]);
+    assert_eq!(m.row(1).to_owned().as_slice(), &[T::from(5), T::from(6), T::zero(), T::from(99)]);
+    assert_eq!(m[(2, 3)], T::from(12));
+}
+
+fn test_view_mut_col<T>()
+where
+    T: Num + Copy + PartialEq + Debug + From<u8>,
+{
+    let mut m = sample::<T>();
+    m.col_mut(1).apply_inplace(|x| x + T::from(100));
+    assert_eq!(m.col(1).to_owned().as_slice(), &[T::from(102), T::from(106), T::from(110)]);
+    assert_eq!(m[(0, 0)], T::from(1));
+}
+
+fn test_view_mut_assign_ops<T>()
+where
+    T: Num + Copy + PartialEq + Debug + From<u8> + std::iter::Sum<T>,
+{
+    let src = sample::<T>();
+    let mut m = Matrix::<T>::zeroes(3, 4).unwrap();
+    m.row_mut(0).copy_from(&src.row(2));
+    let mut r = m.row_mut(0);
+    r += &src.row(0);
+    r -= &src.row(1);
+    assert_eq!(r.to_owned().as_slice(), &[T::from(5), T::from(6), T::from(7), T::from(8)]);
+    assert_eq!(r.sum(), T::from(26));
+}
+
+// -------- f64 Tests --------
+
+#[test]
+fn test_full_view_f64() { test_full_view::<f64>(); }
+
+#[test]
+fn test_slice_block_f64() { test_slice_block::<f64>(); }
+
+#[test]
+fn test_nested_slice_f64() { test_nested_slice::<f64>(); }
+
+#[test]
+fn test_row_and_col_f64() { test_row_and_col::<f64>(); }
+
+#[test]
+fn test_view_sum_f64() { test_view_sum::<f64>(); }
+
+#[test]
+fn test_view_apply_f64() { test_view_apply::<f64>(); }
+
+#[test]
+fn test_view_matmul_f64() { test_view_matmul::<f64>(); }
+
+#[test]
+fn test_view_arithmetic_f64() { test_view_arithmetic::<f64>(); }
+
+#[test]
+fn test_view_mut_block_f64() { test_view_mut_block::<f64>(); }
+
+#[test]
+fn test_view_mut_col_f64() { test_view_mut_col::<f64>(); }
+
+#[test]
+fn test_view_mut_assign_ops_f64() { test_view_mut_assign_ops::<f64>(); }
+
+// -------- i32 Tests --------
+
+#[test]
+fn test_full_view_i32() { test_full_view::<i32>(); }
+
+#[test]
+fn test_slice_block_i32() { test_slice_block::<i32>(); }
+
+#[test]
+fn test_nested_slice_i32() { test_nested_slice::<i32>(); }
+
+#[test]
+fn test_row_and_col_i32() { test_row_and_col::<i32>(); }
+
+#[test]
+fn test_view_sum_i32() { test_view_sum::<i32>(); }
+
+#[test]
+fn test_view_apply_i32() { test_view_apply::<i32>(); }
+
+#[test]
+fn test_view_matmul_i32() { test_view_matmul::<i32>(); }
+
+#[test]
+fn test_view_arithmetic_i32() { test_view_arithmetic::<i32>(); }
+
+#[test]
+fn test_view_mut_block_i32() { test_view_mut_block::<i32>(); }
+
+#[test]
+fn test_view_mut_col_i32() { test_view_mut_col::<i32>(); }
+
+#[test]
+fn test_view_mut_assign_ops_i32() { test_view_mut_assign_ops::<i32>(); }
+
+// -------- Display Tests --------
+
+#[test]
+fn test_view_display_matches_matrix() {
+    let m = sample::<f64>();
+    let v = m.slice(0..2, 1..3);
+    assert_eq!(format!("{}", v), format!("{}", v.to_owned()));
+}
+
+// -------- Error Tests --------
+
+#[test]
+fn test_try_slice_out_of_bounds() {
+    let m = sample::<f64>();
+    let err = m.try_slice(0..4, 0..2).unwrap_err();
+    assert_eq!(err, MatrixError::SliceOutOfBounds { rows: (0, 4), cols: (0, 2), shape: (3, 4) });
+}
+
+#[test]
+fn test_try_slice_empty() {
+    let m = sample::<f64>();
+    let err = m.try_slice(1..1, 0..2).unwrap_err();
+    assert_eq!(err, MatrixError::ZeroDimension { rows: 0, cols: 2 });
+}
+
+#[test]
+fn test_view_try_matmul_mismatch() {
+    let m = sample::<f64>();
+    let err = m.row(0).try_matmul(&m.row(1)).unwrap_err();
+    assert_eq!(err, MatrixError::InnerDimMismatch { op: "matmul", lhs: (1, 4), rhs: (1, 4) });
+}
+
+#[test]
+fn test_view_get_out_of_range() {
+    let m = sample::<i32>();
+    let v = m.slice(0..2, 0..2);
+    assert_eq!(v.get(1, 1), Some(&6));
+    assert_eq!(v.get(2, 0), None);
+}
+
+#[test]
+#[should_panic(expected = "MatrixView::row: row index 3 out of range for view of shape (3, 4)")]
+fn test_row_out_of_range() {
+    let m = sample::<f64>();
+    let _ = m.row(3);
+}
+
+#[test]
+#[should_panic(expected = "MatrixView::Index: col index 2 out of range for view of shape (2, 2)")]
+fn test_view_index_out_of_range() {
+    let m = sample::<f64>();
+    let v = m.slice(0..2, 0..2);
+    let _ = v[(0, 2)];
+}
+
+#[test]
+#[should_panic(expected = "Dimension mismatch")]
+fn test_view_add_mismatch() {
+    let m = sample::<f64>();
+    let _ = &m.row(0) + &m.col(0);
+}